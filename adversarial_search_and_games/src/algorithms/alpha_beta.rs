@@ -1,30 +1,30 @@
-/// function Alpha-Beta-Search(game, state) returns an action
-///     player <- game.To-Move(state)
-///     value,move <- Max-Value(game, state, -infty, +infty)
-///     return move
-/// 
-/// function Max-Value(game, state, alpha, beta) returns a (utlity, move) pair
-///     if game.is_terminal(state) then return game.utility(state, player), null
-///     v <- -infty
-///     for each a in game.actions(state) do
-///         v2, a2 <- Min-Value(game, game.result(state, a), alpha, beta)
-///         if v2 > v then
-///             v, move <- v2, a
-///             alpha <- Max(alpha, v)
-///         if v >= beta then return v, move
-///     return v, move
-///
-/// function Min-Value(game, state, alpha, beta) returns a (utility, move) pair
-///     if game.Is-Terminal(state) then return game.Utlity(state, player), null
-///     v <- +infty
-///     for each a in game.actions(state) do
-///         v2, a2 <- Max-Value(game, game.result(state, a), alpha, beta)
-///         if v2 < v then
-///             v, move <- v2, a
-///             beta <- Min(beta, v)
-///         if v <= alpha then return v, mvoe
-///     return v, move
-///
+//! function Alpha-Beta-Search(game, state) returns an action
+//!     player <- game.To-Move(state)
+//!     value,move <- Max-Value(game, state, -infty, +infty)
+//!     return move
+//! 
+//! function Max-Value(game, state, alpha, beta) returns a (utlity, move) pair
+//!     if game.is_terminal(state) then return game.utility(state, player), null
+//!     v <- -infty
+//!     for each a in game.actions(state) do
+//!         v2, a2 <- Min-Value(game, game.result(state, a), alpha, beta)
+//!         if v2 > v then
+//!             v, move <- v2, a
+//!             alpha <- Max(alpha, v)
+//!         if v >= beta then return v, move
+//!     return v, move
+//!
+//! function Min-Value(game, state, alpha, beta) returns a (utility, move) pair
+//!     if game.Is-Terminal(state) then return game.Utlity(state, player), null
+//!     v <- +infty
+//!     for each a in game.actions(state) do
+//!         v2, a2 <- Max-Value(game, game.result(state, a), alpha, beta)
+//!         if v2 < v then
+//!             v, move <- v2, a
+//!             beta <- Min(beta, v)
+//!         if v <= alpha then return v, mvoe
+//!     return v, move
+//!
 
 use crate::algorithms::game::*;
 
@@ -39,7 +39,7 @@ where
     let mut game_copy = game.clone();
     let _player = G::to_move(state);
     let (_value, move_) = max_value(&mut game_copy, state, f64::NEG_INFINITY, f64::INFINITY, &mut search_depth);
-    return move_;
+    move_
 }
 
 fn max_value<G, S, A, P>(game: &mut G, state: &S, alpha: f64, beta: f64, depth: &mut usize) -> (f64, Option<A>)
@@ -73,7 +73,7 @@ where
     }
 
     *depth += 1;
-    return (v, move_);
+    (v, move_)
 }
 
 fn min_value<G, S, A, P>(game: &mut G, state: &S, alpha: f64, beta: f64, depth: &mut usize) -> (f64, Option<A>)
@@ -105,5 +105,5 @@ where
         }
     }
     *depth += 1;
-    return (v, move_);
+    (v, move_)
 }
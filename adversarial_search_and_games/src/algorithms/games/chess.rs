@@ -149,18 +149,10 @@ impl ChessGame {
         }
         let piece = &state.0[cur_pos.1][cur_pos.0];
         let square_value = &state.0[new_pos.1][new_pos.0];
-        match square_value {
-            Some(other_piece) => {
-                // Compare color of piece at destination and color of moving piece
-                if piece.is_some() {
-                    piece.as_ref().unwrap().get_color() != other_piece.get_color()
-                } else {
-                    false 
-                }
-            },
-            None => {
-                false
-            }
+        match (piece, square_value) {
+            // Compare color of piece at destination and color of moving piece
+            (Some(piece), Some(other_piece)) => piece.get_color() != other_piece.get_color(),
+            _ => false
         }
     }
 
@@ -172,18 +164,10 @@ impl ChessGame {
         }
         let piece = &state.0[cur_pos.1][cur_pos.0];
         let square_value = &state.0[new_pos.1][new_pos.0];
-        match square_value {
-            Some(other_piece) => {
-                // Compare color of piece at destination and color of moving piece
-                if piece.is_some() {
-                    piece.as_ref().unwrap().get_color() == other_piece.get_color()
-                } else {
-                    false
-                }
-            },
-            None => {
-                false
-            }
+        match (piece, square_value) {
+            // Compare color of piece at destination and color of moving piece
+            (Some(piece), Some(other_piece)) => piece.get_color() == other_piece.get_color(),
+            _ => false
         }
     }
 
@@ -202,15 +186,15 @@ impl ChessGame {
     fn hypothetical_move(&self, state: &ChessState, action: &ChessAction) -> ChessState {
         let mut new_state = state.clone();
         
-        let piece_taken = Self::perform_move(&mut new_state, &action);
+        let piece_taken = Self::perform_move(&mut new_state, action);
         // update players piece
-        if let Some(promoted_pawn) = Self::get_current_player_as_mut(&mut new_state).update_piece(&action) {
-            let pos = promoted_pawn.get_position().clone();
+        if let Some(promoted_pawn) = Self::get_current_player_as_mut(&mut new_state).update_piece(action) {
+            let pos = promoted_pawn.get_position();
             new_state.0[pos.1][pos.0] = Some(promoted_pawn);
             //new_state.0[pos.1][pos.0] = Some(Box::new(promoted_pawn));
         }
         if piece_taken.is_some() {
-            Self::get_other_player_as_mut(&mut new_state).remove_piece(&action);
+            Self::get_other_player_as_mut(&mut new_state).remove_piece(action);
         }
         let state_copy = new_state.clone();
         Self::get_other_player_as_mut(&mut new_state).check_if_checked(&state_copy, Self::get_current_player(&state_copy));
@@ -227,7 +211,7 @@ impl ChessGame {
         let cur_pos = action.0;
         let new_pos = action.1;
         let piece_taken = if state.0[new_pos.1][new_pos.0].is_some() { Some(state.0[new_pos.1][new_pos.0].as_ref().unwrap().get_type()) } else { None };
-        let mut piece = state.0[cur_pos.1][cur_pos.0].as_mut().unwrap();
+        let piece = state.0[cur_pos.1][cur_pos.0].as_mut().unwrap();
         // update piece's own state
         piece.position = action.1;
 
@@ -280,7 +264,7 @@ impl ChessGame {
 
     fn get_repetition_penalty(state: &ChessState) -> f64 {
         let mut occurrences = 0;
-        let _ = state.3.as_ref().into_iter().map(|a| 
+        let _ = state.3.as_ref().iter().map(|a| 
             if &state.0 == a { occurrences += 1; } 
         );
         (occurrences * 300) as f64
@@ -350,14 +334,8 @@ impl Game<ChessState, ChessAction, ChessPlayer> for ChessGame {
 
     fn is_terminal(&self, state: &ChessState) -> bool {
         // If we can't perform any moves, the game must be over
-        if  Self::get_current_player(state).king == None || 
-            Self::get_current_player(state).get_moves(state).len() == 0 || 
-            (Self::get_current_player(state).get_num_pieces() == 1 && Self::get_other_player(state).get_num_pieces() == 1)
-        {
-            true
-        } else {
-            false
-        }
+        Self::get_current_player(state).king.is_none() || 
+            Self::get_current_player(state).get_moves(state).is_empty() || (Self::get_current_player(state).get_num_pieces() == 1 && Self::get_other_player(state).get_num_pieces() == 1)
     }
 
     fn utility(&self, state: &ChessState, player: &ChessPlayer) -> f64 {
@@ -408,7 +386,7 @@ impl Game<ChessState, ChessAction, ChessPlayer> for ChessGame {
     }
 
     fn take_action(&mut self, _state: &ChessState, action: &ChessAction) -> &ChessState {
-        self.move_piece(action.clone());
+        self.move_piece(*action);
         &self.board
     }
 }
@@ -477,14 +455,14 @@ impl ChessPlayer {
                     pawns.push(Box::new(ChessPiece { piece_type: ChessPieceType::Pawn, position: (i, 7 - 1), color, can_perform: true }));
                 }
                 */
-                king = ChessPiece { piece_type: ChessPieceType::King, position: (4, 7 - 0), color, can_perform: true };
-                queen = ChessPiece { piece_type: ChessPieceType::Queen, position: (3, 7 - 0), color, can_perform: false };
-                rooks = vec![ChessPiece { piece_type: ChessPieceType::Rook, position: (0, 7 - 0), color, can_perform: true }, ChessPiece { piece_type: ChessPieceType::Rook, position: (7, 7 - 0), color, can_perform: true }];
-                knights = vec![ChessPiece { piece_type: ChessPieceType::Knight, position: (1, 7 - 0), color, can_perform: false }, ChessPiece { piece_type: ChessPieceType::Knight, position: (6, 7 - 0), color, can_perform: false }];
-                bishops = vec![ChessPiece { piece_type: ChessPieceType::Bishop, position: (2, 7 - 0), color, can_perform: false }, ChessPiece { piece_type: ChessPieceType::Bishop, position: (5, 7 - 0), color, can_perform: false }];
+                king = ChessPiece { piece_type: ChessPieceType::King, position: (4, 7), color, can_perform: true };
+                queen = ChessPiece { piece_type: ChessPieceType::Queen, position: (3, 7), color, can_perform: false };
+                rooks = vec![ChessPiece { piece_type: ChessPieceType::Rook, position: (0, 7), color, can_perform: true }, ChessPiece { piece_type: ChessPieceType::Rook, position: (7, 7), color, can_perform: true }];
+                knights = vec![ChessPiece { piece_type: ChessPieceType::Knight, position: (1, 7), color, can_perform: false }, ChessPiece { piece_type: ChessPieceType::Knight, position: (6, 7), color, can_perform: false }];
+                bishops = vec![ChessPiece { piece_type: ChessPieceType::Bishop, position: (2, 7), color, can_perform: false }, ChessPiece { piece_type: ChessPieceType::Bishop, position: (5, 7), color, can_perform: false }];
                 pawns = Vec::new();
                 for i in 0..BOARD_COLS {
-                    pawns.push(ChessPiece { piece_type: ChessPieceType::Pawn, position: (i, 7 - 1), color, can_perform: true });
+                    pawns.push(ChessPiece { piece_type: ChessPieceType::Pawn, position: (i, 6), color, can_perform: true });
                 }
             },
             PlayerColor::Black => {
@@ -499,14 +477,14 @@ impl ChessPlayer {
                     pawns.push(Box::new(ChessPiece { piece_type: ChessPieceType::Pawn, position: (i, 7 - 6), color, can_perform: true }));
                 }
                 */
-                king = ChessPiece { piece_type: ChessPieceType::King, position: (4, 7 - 7), color, can_perform: true };
-                queen = ChessPiece { piece_type: ChessPieceType::Queen, position: (3, 7 - 7), color, can_perform: false };
-                rooks = vec![ChessPiece { piece_type: ChessPieceType::Rook, position: (0, 7 - 7), color, can_perform: true }, ChessPiece { piece_type: ChessPieceType::Rook, position: (7, 7 - 7), color, can_perform: true }];
-                knights = vec![ChessPiece { piece_type: ChessPieceType::Knight, position: (1, 7 - 7), color, can_perform: false }, ChessPiece { piece_type: ChessPieceType::Knight, position: (6, 7 - 7), color, can_perform: false }];
-                bishops = vec![ChessPiece { piece_type: ChessPieceType::Bishop, position: (2, 7 - 7), color, can_perform: false }, ChessPiece { piece_type: ChessPieceType::Bishop, position: (5, 7 - 7), color, can_perform: false }];
+                king = ChessPiece { piece_type: ChessPieceType::King, position: (4, 0), color, can_perform: true };
+                queen = ChessPiece { piece_type: ChessPieceType::Queen, position: (3, 0), color, can_perform: false };
+                rooks = vec![ChessPiece { piece_type: ChessPieceType::Rook, position: (0, 0), color, can_perform: true }, ChessPiece { piece_type: ChessPieceType::Rook, position: (7, 0), color, can_perform: true }];
+                knights = vec![ChessPiece { piece_type: ChessPieceType::Knight, position: (1, 0), color, can_perform: false }, ChessPiece { piece_type: ChessPieceType::Knight, position: (6, 0), color, can_perform: false }];
+                bishops = vec![ChessPiece { piece_type: ChessPieceType::Bishop, position: (2, 0), color, can_perform: false }, ChessPiece { piece_type: ChessPieceType::Bishop, position: (5, 0), color, can_perform: false }];
                 pawns = Vec::new();
                 for i in 0..BOARD_COLS {
-                    pawns.push(ChessPiece { piece_type: ChessPieceType::Pawn, position: (i, 7 - 6), color, can_perform: true });
+                    pawns.push(ChessPiece { piece_type: ChessPieceType::Pawn, position: (i, 1), color, can_perform: true });
                 }
             }
         }
@@ -562,12 +540,12 @@ impl ChessPlayer {
         if self.color == ChessGame::get_current_player(state).color {
             other_players_moves.append(&mut other_player.get_moves(state));
         }
-        if self.checked_by.is_some() {
-            let other_pos = self.checked_by.as_ref().unwrap().get_position();
+        if let Some(checked_by) = &self.checked_by {
+            let other_pos = checked_by.get_position();
             let king_pos = self.king.as_ref().unwrap().get_position();
             let max_length = (other_pos.0 as isize - king_pos.0 as isize).max(other_pos.1 as isize - king_pos.1 as isize);
             let diff = (other_pos.0 as isize - king_pos.0 as isize, other_pos.1 as isize - king_pos.1 as isize);
-            match self.checked_by.as_ref().unwrap().get_type() {
+            match checked_by.get_type() {
                 ChessPieceType::Knight => {
                     /* We handle knights differently, as they aren't stopped by LOS */
                     checked_squares.push(other_pos);
@@ -648,10 +626,9 @@ impl ChessPlayer {
     }
 
     fn update_piece(&mut self, action: &ChessAction) -> Option<ChessPiece> {
-        let piece_info = self.pieces.get(&action.0).clone();
+        let piece_info = self.pieces.get(&action.0).copied();
         let mut promoted_pawn = None;
-        if piece_info.is_some() {
-            let mut piece_info = *piece_info.unwrap();
+        if let Some(mut piece_info) = piece_info {
             match piece_info.0 {
                 ChessPieceType::King => {
                     self.king.as_mut().unwrap().position = action.1;
@@ -662,7 +639,7 @@ impl ChessPlayer {
                         println!("Queens: {:?}", self.queens);
                         println!("Piece_info: {:?}", piece_info);
                         println!("Pieces: {:?}", self.pieces);
-                        loop {}
+                        panic!("Moved a queen that does not exist");
                     }
                     self.queens[piece_info.1].position = action.1;
                 },
@@ -727,13 +704,11 @@ impl ChessPlayer {
         }
         pieces += self.queens.len() + self.rooks.len() + self.knights.len() + self.bishops.len() + self.pawns.len();
 
-        return pieces;
+        pieces
     }
 
     fn remove_piece(&mut self, action: &ChessAction) {
-        let piece_info = self.pieces.get(&action.1).clone();
-        if piece_info.is_some() {
-            let piece_info = *piece_info.unwrap();
+        if let Some(&piece_info) = self.pieces.get(&action.1) {
             match piece_info.0 {
                 ChessPieceType::King => {
                     self.king = None;
@@ -942,7 +917,7 @@ impl ChessPiece {
                 }
                 let action: ChessAction = (self.position, new_pos);
                 
-                if self.piece_type == ChessPieceType::Pawn && (new_pos.0 ) < 7 && (new_pos.1 ) < 7 {
+                if self.piece_type == ChessPieceType::Pawn && new_pos.0 < 8 && new_pos.1 < 8 {
                     // Pawns are weird. Need to check if any opponent piece is in squares
                     // cross ahead. Don't even get me started on "en passant"
 
@@ -956,8 +931,8 @@ impl ChessPiece {
                     }
 
                     // Lastly, check if we can even move forward
-                    else if vector == &vectors[0] {
-                        if state.0[new_pos.1][new_pos.0].is_none() {
+                    else if vector == &vectors[0]
+                        && state.0[new_pos.1][new_pos.0].is_none() {
                             moves.push(new_pos);
 
                             /* Check if we can move two squares ahead */
@@ -973,7 +948,6 @@ impl ChessPiece {
                                 }
                             }
                         }
-                    }
                 } else if ChessGame::is_legal_move(state, action) {
                     moves.push(new_pos);
                     if ChessGame::contains_opponent_piece(state, &action) {
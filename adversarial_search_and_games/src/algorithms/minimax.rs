@@ -1,25 +1,25 @@
-/// function Minimax-Search(game, state) returns an action
-///     player <- game.To-Move(state)
-///     value, move <- Max-Value(game, state)
-///     return move
-///
-/// function Max-Value(game, state) returns a (utility, move) pair
-///     if game.Is-Terminal(state) then return game.utility(state, player), null
-///     v <- -infty
-///     for each a in game.Actions(state) do
-///         v2, a2 <- Min-Value(game, game.Result(state, a))
-///     if v2 > v then
-///         v, move <- v2, a
-///     return v, move
-///
-/// function Min-Value(game, state) returns a (utility, move) pair
-///     if game.Is-Terminal(state) then return game.Utility(state, player), null
-///     v <- +infty
-///     for each a in game.Actions(state) do
-///         v2, a2 <- Max-Value(game, game.Result(state, a))
-///         if v2 < v then
-///             v, move <- v2, a
-///     return v, move
+//! function Minimax-Search(game, state) returns an action
+//!     player <- game.To-Move(state)
+//!     value, move <- Max-Value(game, state)
+//!     return move
+//!
+//! function Max-Value(game, state) returns a (utility, move) pair
+//!     if game.Is-Terminal(state) then return game.utility(state, player), null
+//!     v <- -infty
+//!     for each a in game.Actions(state) do
+//!         v2, a2 <- Min-Value(game, game.Result(state, a))
+//!     if v2 > v then
+//!         v, move <- v2, a
+//!     return v, move
+//!
+//! function Min-Value(game, state) returns a (utility, move) pair
+//!     if game.Is-Terminal(state) then return game.Utility(state, player), null
+//!     v <- +infty
+//!     for each a in game.Actions(state) do
+//!         v2, a2 <- Max-Value(game, game.Result(state, a))
+//!         if v2 < v then
+//!             v, move <- v2, a
+//!     return v, move
 
 use std::fmt::Debug;
 
@@ -36,7 +36,7 @@ where
     let loc_state = state.clone();
     let mut cur_depth = depth; 
     let (_value, move_) = max_value(&mut loc_game, &loc_state, &mut cur_depth);
-    return move_;
+    move_
 }
 
 fn max_value<G, S, A, P>(game: &mut G, state: &S, cur_depth: &mut usize) -> (f64, Option<A>)
@@ -65,7 +65,7 @@ where
     
     *cur_depth += 1;
 
-    return (v, move_);
+    (v, move_)
 }
 
 fn min_value<G, S, A, P>(game: &mut G, state: &S, cur_depth: &mut usize) -> (f64, Option<A>)
@@ -94,5 +94,5 @@ where
 
     *cur_depth += 1;
 
-    return (v, move_);
+    (v, move_)
 }
@@ -103,10 +103,9 @@ where
             let tmp = tmp.lock().unwrap();
             let best_candidate = tmp.children.first().unwrap();
             child = Arc::clone(best_candidate.as_ref().unwrap());
-            drop(best_candidate);
             drop(tmp);
         }
-        return Some(child);
+        Some(child)
     }
 
     ///
@@ -135,11 +134,11 @@ where
 
         let new_child = Arc::new(Mutex::new(MonteCarloNode::new(
             game.result(&leaf.state, &possible_states[choice]),
-            Some(Arc::clone(&state.as_ref().unwrap())),
+            Some(Arc::clone(state.as_ref().unwrap())),
             Some(possible_states[choice].clone())
         )));
         
-        return Some(new_child);
+        Some(new_child)
     }
 
     ///
@@ -155,11 +154,7 @@ where
         let mut rng = rand::thread_rng();
         let state_copy = state.as_ref().unwrap().lock().unwrap().state.clone();
         let eval = game.utility(&state_copy, G::to_move(&state_copy));
-        if rng.gen_ratio((50. + eval * 100.).clamp(0.,100.) as u32, 100) {
-            true
-        } else {
-            false 
-        }
+        rng.gen_ratio((50. + eval * 100.).clamp(0.,100.) as u32, 100)
     }
 
     ///
@@ -175,15 +170,16 @@ where
         loop {
             let mut n = node.lock().unwrap();           
             n.playouts += 1;
-            if result == true {
+            if result {
                 n.wins += 1;
             }
-            if n.parent.is_none() {
-                break;
-            } else {
-                let tmp = Arc::clone(n.parent.as_ref().unwrap());
-                drop(n);
-                node = tmp;
+            match &n.parent {
+                None => break,
+                Some(parent) => {
+                    let tmp = Arc::clone(parent);
+                    drop(n);
+                    node = tmp;
+                }
             }
         }
     }
@@ -0,0 +1,26 @@
+//!
+//! Adversarial search and games from chapter 5 of AIMA.
+//!
+//! A game is described by implementing [`Game`] for its states, actions and [`Player`]s, and
+//! played by asking [`minimax_search`], [`alpha_beta_search`] or a [`MonteCarloTree`] for the
+//! next move. Chess is included as an example game.
+//!
+
+pub mod algorithms;
+
+pub use algorithms::{
+    game::{Game, Player},
+    node::{Link, MonteCarloNode},
+    games::chess::{
+        ChessAction,
+        ChessGame,
+        ChessPiece,
+        ChessPieceType,
+        ChessPlayer,
+        ChessState,
+        PlayerColor,
+    },
+    minimax::minimax_search,
+    alpha_beta::alpha_beta_search,
+    monte_carlo_tree_search::MonteCarloTree,
+};
@@ -1,5 +1,5 @@
 #![allow(dead_code)]
-#![allow(unused_imports)]
+
 use std::{
     fmt::Debug,
     thread::sleep
@@ -8,15 +8,11 @@ use std::{
 use console::Term;
 use rand::prelude::*;
 
-pub mod algorithms;
-
-use crate::algorithms::{
-    node,
-    game::*,
-    games::chess,
-    minimax,
-    alpha_beta,
-    monte_carlo_tree_search,
+use adversarial_search_and_games::{
+    algorithms::games::chess,
+    Game,
+    MonteCarloTree,
+    Player,
 };
 
 type Algorithm<G, S, A> = fn(game: &G, state: &S, depth: usize) -> Option<A>;
@@ -58,7 +54,7 @@ fn play_chess() {
         draw_board(&term, &state);
     }
 
-    let mut mcts = monte_carlo_tree_search::MonteCarloTree::<_, chess::ChessAction>::new(&state);    
+    let mut mcts = MonteCarloTree::<_, chess::ChessAction>::new(&state);    
 
     while !game.is_terminal(&state) {
         let current_player = chess::ChessGame::to_move(&state).clone();
@@ -81,8 +77,8 @@ fn play_chess() {
                 term.write_line(&format!("Current utility of state: {}", game.utility(&state, chess::ChessGame::to_move(&state)))).expect("");
                 term.read_key().expect("");
             }
-            if choice.is_some() {
-                state = game.result(&state, &choice.unwrap()).clone();
+            if let Some(choice) = choice {
+                state = game.result(&state, &choice).clone();
             } else {
                 println!("Game over, {:?} wins!", chess::ChessGame::to_move(&state).get_color());
                 break;
@@ -114,90 +110,86 @@ fn play_chess() {
 
 
 
-fn display_actions(term: &Term, actions: &Vec<chess::ChessAction>) {
+fn display_actions(term: &Term, actions: &[chess::ChessAction]) {
     let pos = (10, 0);
-    let mut x = 0;
     term.move_cursor_to(pos.0, pos.1).expect("");
-    for action in actions {
+    for (x, action) in actions.iter().enumerate() {
         let line = format!("[{:0>2}] {:?}->{:?}", x, action.0, action.1);
         term.write_line(&line).expect("");
         term.move_cursor_right(pos.0).expect("");
-        x += 1;
     }
 }
 
 fn draw_board(term: &Term, state: &chess::ChessState) {
     term.clear_screen().expect("");
     for row in &state.0 {
-        for piece in row {
-            if let Some(p) = piece {
-                let pos = p.get_position();
-                let icon: &'static str;
-                term.move_cursor_to(pos.0 as usize, pos.1 as usize).expect("");
-                match p.get_type() {
-                    chess::ChessPieceType::King => {
-                        match p.get_color() {
-                            chess::PlayerColor::White => {
-                                icon = "\x1b[38;5;11mK\x1b[38;5;0m";
-                            },
-                            chess::PlayerColor::Black => {
-                                icon = "\x1b[38;5;5mK\x1b[38;5;0m";
-                            }
+        for p in row.iter().flatten() {
+            let pos = p.get_position();
+            let icon: &'static str;
+            term.move_cursor_to(pos.0, pos.1).expect("");
+            match p.get_type() {
+                chess::ChessPieceType::King => {
+                    match p.get_color() {
+                        chess::PlayerColor::White => {
+                            icon = "\x1b[38;5;11mK\x1b[38;5;0m";
+                        },
+                        chess::PlayerColor::Black => {
+                            icon = "\x1b[38;5;5mK\x1b[38;5;0m";
                         }
-                    },
-                    chess::ChessPieceType::Queen => {
-                        match p.get_color() {
-                            chess::PlayerColor::White => {
-                                icon = "\x1b[38;5;11mQ\x1b[38;5;0m";
-                            },
-                            chess::PlayerColor::Black => {
-                                icon = "\x1b[38;5;5mQ\x1b[38;5;0m";
-                            }
+                    }
+                },
+                chess::ChessPieceType::Queen => {
+                    match p.get_color() {
+                        chess::PlayerColor::White => {
+                            icon = "\x1b[38;5;11mQ\x1b[38;5;0m";
+                        },
+                        chess::PlayerColor::Black => {
+                            icon = "\x1b[38;5;5mQ\x1b[38;5;0m";
                         }
-                    },
-                    chess::ChessPieceType::Rook => {
-                        match p.get_color() {
-                            chess::PlayerColor::White => {
-                                icon = "\x1b[38;5;11mR\x1b[38;5;0m";
-                            },
-                            chess::PlayerColor::Black => {
-                                icon = "\x1b[38;5;5mR\x1b[38;5;0m";
-                            }
+                    }
+                },
+                chess::ChessPieceType::Rook => {
+                    match p.get_color() {
+                        chess::PlayerColor::White => {
+                            icon = "\x1b[38;5;11mR\x1b[38;5;0m";
+                        },
+                        chess::PlayerColor::Black => {
+                            icon = "\x1b[38;5;5mR\x1b[38;5;0m";
                         }
-                    },
-                    chess::ChessPieceType::Knight => {
-                        match p.get_color() {
-                            chess::PlayerColor::White => {
-                                icon = "\x1b[38;5;11mN\x1b[38;5;0m";
-                            },
-                            chess::PlayerColor::Black => {
-                                icon = "\x1b[38;5;5mN\x1b[38;5;0m";
-                            }
+                    }
+                },
+                chess::ChessPieceType::Knight => {
+                    match p.get_color() {
+                        chess::PlayerColor::White => {
+                            icon = "\x1b[38;5;11mN\x1b[38;5;0m";
+                        },
+                        chess::PlayerColor::Black => {
+                            icon = "\x1b[38;5;5mN\x1b[38;5;0m";
                         }
-                    },
-                    chess::ChessPieceType::Bishop => {
-                        match p.get_color() {
-                            chess::PlayerColor::White => {
-                                icon = "\x1b[38;5;11mB\x1b[38;5;0m";
-                            },
-                            chess::PlayerColor::Black => {
-                                icon = "\x1b[38;5;5mB\x1b[38;5;0m";
-                            }
+                    }
+                },
+                chess::ChessPieceType::Bishop => {
+                    match p.get_color() {
+                        chess::PlayerColor::White => {
+                            icon = "\x1b[38;5;11mB\x1b[38;5;0m";
+                        },
+                        chess::PlayerColor::Black => {
+                            icon = "\x1b[38;5;5mB\x1b[38;5;0m";
                         }
-                    },
-                    chess::ChessPieceType::Pawn => {
-                        match p.get_color() {
-                            chess::PlayerColor::White => {
-                                icon = "\x1b[38;5;11mP\x1b[38;5;0m";
-                            },
-                            chess::PlayerColor::Black => {
-                                icon = "\x1b[38;5;5mP\x1b[38;5;0m";
-                            }
+                    }
+                },
+                chess::ChessPieceType::Pawn => {
+                    match p.get_color() {
+                        chess::PlayerColor::White => {
+                            icon = "\x1b[38;5;11mP\x1b[38;5;0m";
+                        },
+                        chess::PlayerColor::Black => {
+                            icon = "\x1b[38;5;5mP\x1b[38;5;0m";
                         }
                     }
                 }
-                term.write_line(icon).expect("");
             }
+            term.write_line(icon).expect("");
         }
     }
 }
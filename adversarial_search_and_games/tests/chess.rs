@@ -0,0 +1,20 @@
+use adversarial_search_and_games::{ChessGame, Game, PlayerColor};
+
+#[test]
+fn white_has_twenty_opening_moves() {
+    let game = ChessGame::create_game();
+    let state = game.get_initial_state();
+    assert_eq!(ChessGame::to_move(state).get_color(), PlayerColor::White);
+    assert_eq!(game.actions(state).len(), 20);
+    assert!(!game.is_terminal(state));
+}
+
+#[test]
+fn turns_alternate() {
+    let mut game = ChessGame::create_game();
+    let state = game.get_initial_state().clone();
+    let action = game.actions(&state)[0];
+    let next = game.result(&state, &action);
+    assert_eq!(ChessGame::to_move(&next).get_color(), PlayerColor::Black);
+    assert_eq!(game.actions(&next).len(), 20);
+}
@@ -0,0 +1,80 @@
+use adversarial_search_and_games::{alpha_beta_search, minimax_search, Game, Player};
+
+/// The two-ply game of AIMA figure 5.2: MAX picks one of A's moves, then MIN picks a leaf
+#[derive(Clone)]
+struct TwoPlyGame {
+    initial: &'static str,
+}
+
+#[derive(PartialEq, Debug)]
+enum Side {
+    Max,
+    Min,
+}
+
+static MAX: Side = Side::Max;
+static MIN: Side = Side::Min;
+
+impl Player<&'static str, &'static str> for Side {}
+
+const MOVES: [(&str, &str, &str); 12] = [
+    ("A", "a1", "B"), ("A", "a2", "C"), ("A", "a3", "D"),
+    ("B", "b1", "B1"), ("B", "b2", "B2"), ("B", "b3", "B3"),
+    ("C", "c1", "C1"), ("C", "c2", "C2"), ("C", "c3", "C3"),
+    ("D", "d1", "D1"), ("D", "d2", "D2"), ("D", "d3", "D3"),
+];
+
+const LEAVES: [(&str, f64); 9] = [
+    ("B1", 3.), ("B2", 12.), ("B3", 8.),
+    ("C1", 2.), ("C2", 4.), ("C3", 6.),
+    ("D1", 14.), ("D2", 5.), ("D3", 2.),
+];
+
+impl Game<&'static str, &'static str, Side> for TwoPlyGame {
+    fn create_game() -> Self {
+        Self { initial: "A" }
+    }
+
+    fn get_initial_state(&self) -> &&'static str {
+        &self.initial
+    }
+
+    fn to_move<'a>(state: &'a &'static str) -> &'a Side {
+        if *state == "A" { &MAX } else { &MIN }
+    }
+
+    fn actions(&self, state: &&'static str) -> Vec<&'static str> {
+        MOVES.iter().filter(|(s, _, _)| s == state).map(|(_, a, _)| *a).collect()
+    }
+
+    fn result(&mut self, state: &&'static str, action: &&'static str) -> &'static str {
+        MOVES.iter().find(|(s, a, _)| s == state && a == action).unwrap().2
+    }
+
+    fn is_terminal(&self, state: &&'static str) -> bool {
+        LEAVES.iter().any(|(s, _)| s == state)
+    }
+
+    /// Utilities are for MAX, whoever is asking
+    fn utility(&self, state: &&'static str, _player: &Side) -> f64 {
+        LEAVES.iter().find(|(s, _)| s == state).map(|(_, u)| *u).unwrap_or(0.)
+    }
+
+    fn take_action(&mut self, _state: &&'static str, action: &&'static str) -> &&'static str {
+        let initial = self.initial;
+        self.initial = self.result(&initial, action);
+        &self.initial
+    }
+}
+
+#[test]
+fn minimax_picks_the_best_worst_case() {
+    let game = TwoPlyGame::create_game();
+    assert_eq!(minimax_search(&game, game.get_initial_state(), 10), Some("a1"));
+}
+
+#[test]
+fn alpha_beta_agrees_with_minimax() {
+    let game = TwoPlyGame::create_game();
+    assert_eq!(alpha_beta_search(&game, game.get_initial_state(), 10), Some("a1"));
+}
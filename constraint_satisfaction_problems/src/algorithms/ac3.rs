@@ -1,22 +1,22 @@
-/// function AC-3(csp) returns false if an inconsistency is found and true otherwise
-///     queue <- a queue of arcs, initially all the arcs in csp
-///
-///     while queue is not empty do
-///         (X_i, X_j) <- Pop(queue)
-///         if Revise(csp, X_i, X_j) then
-///             if size of D_i = 0 then return false
-///             for each X_k in X_i.neighbors - {X_j} do
-///                 add (X_k, X_i) to queue
-///     return true
-///
-/// function Revise(csp, X_i, X_j) returns true iff we revise the domain of X_i
-///     revised <- false
-///     for each x in D_i do
-///         if no value y in D_j allows (x,y) to satisfy the constraint between X_i and X_j then
-///             delete x from D_i
-///             revised <- true
-///     return revised
-///
+//! function AC-3(csp) returns false if an inconsistency is found and true otherwise
+//!     queue <- a queue of arcs, initially all the arcs in csp
+//!
+//!     while queue is not empty do
+//!         (X_i, X_j) <- Pop(queue)
+//!         if Revise(csp, X_i, X_j) then
+//!             if size of D_i = 0 then return false
+//!             for each X_k in X_i.neighbors - {X_j} do
+//!                 add (X_k, X_i) to queue
+//!     return true
+//!
+//! function Revise(csp, X_i, X_j) returns true iff we revise the domain of X_i
+//!     revised <- false
+//!     for each x in D_i do
+//!         if no value y in D_j allows (x,y) to satisfy the constraint between X_i and X_j then
+//!             delete x from D_i
+//!             revised <- true
+//!     return revised
+//!
 
 use crate::algorithms::constraint::*;
 
@@ -29,9 +29,9 @@ where
     let mut new_arc: (String, String);
     while !queue.is_empty() {
         let (x_i, x_j) = queue.pop_front().unwrap();
-        let (x_j, mut x_i) = (local_csp.get_variable(&x_j).clone(), local_csp.get_variable_as_mut(&x_i));
-        if revise(csp, &mut x_i, &x_j) {
-            if x_i.get_domain().len() == 0 {
+        let (x_j, x_i) = (local_csp.get_variable(&x_j).clone(), local_csp.get_variable_as_mut(&x_i));
+        if revise(csp, x_i, &x_j) {
+            if x_i.get_domain().is_empty() {
                 return None;
             }
             for x_k in x_i.get_neighbors() {
@@ -43,7 +43,7 @@ where
         }
     }
     
-    return Some(local_csp);
+    Some(local_csp)
 }
 
 fn revise<T>(csp: &CSP<T>, x_i: &mut Variable<T>, x_j: &Variable<T>) -> bool 
@@ -66,15 +66,10 @@ where
     }
     
     for x in to_remove {
-        let mut index = 0;
-        for d in &x_i.get_domain() {
-            if d == &x {
-                x_i.get_domain_as_mut().remove(index);
-                break;
-            }
-            index += 1;
+        if let Some(index) = x_i.get_domain().iter().position(|d| d == &x) {
+            x_i.get_domain_as_mut().remove(index);
         }
     }
 
-    return revised;
+    revised
 }
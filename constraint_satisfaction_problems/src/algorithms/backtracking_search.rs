@@ -1,21 +1,21 @@
-/// function Backtracking-Search(csp) returns a solution or failure
-///     return Backtrack(csp, {})
-///
-/// function Backtrack(csp, assignment) returns a solution or failure
-///     if assignment is complete then return assignment
-///     var <- Select-Unassigned-Variable(csp, assignment)
-///     for each value in Order-Domain-Values(csp, var, assignment) do
-///         if value is consistent with assignment then
-///             add {var = value} to assignment
-///             inferences <- Inference(csp, var, assignment)
-///             if inferences != failure then
-///                 add inferences to csp
-///                 result <- Backtrack(csp, assignment)
-///                 if result != failure then return result
-///                 remove inferences from csp
-///             remove {var = value} from assignment
-///     return failure
-///
+//! function Backtracking-Search(csp) returns a solution or failure
+//!     return Backtrack(csp, {})
+//!
+//! function Backtrack(csp, assignment) returns a solution or failure
+//!     if assignment is complete then return assignment
+//!     var <- Select-Unassigned-Variable(csp, assignment)
+//!     for each value in Order-Domain-Values(csp, var, assignment) do
+//!         if value is consistent with assignment then
+//!             add {var = value} to assignment
+//!             inferences <- Inference(csp, var, assignment)
+//!             if inferences != failure then
+//!                 add inferences to csp
+//!                 result <- Backtrack(csp, assignment)
+//!                 if result != failure then return result
+//!                 remove inferences from csp
+//!             remove {var = value} from assignment
+//!     return failure
+//!
 
 use crate::algorithms::{
     constraint::*,
@@ -27,7 +27,7 @@ where
     T: Clone + PartialEq + PartialOrd + std::hash::Hash + Eq + std::fmt::Debug
 {
     let mut assignment = csp.clone();
-    return backtrack(csp, &mut assignment);
+    backtrack(csp, &mut assignment)
 }
 
 fn backtrack<T>(csp: &CSP<T>, assignment: &mut CSP<T>) -> Option<CSP<T>>
@@ -44,9 +44,9 @@ where
         let current_domain = assignment.get_variable(&var).get_domain().clone();
         assignment.set_domain(&var, vec![value.clone()]);
         let inference = ac3::ac3(assignment);
-        if inference.is_some() {
-            let mut new_assignment = inference.as_ref().unwrap().clone();
-            let result = backtrack(&inference.unwrap(), &mut new_assignment);
+        if let Some(inference) = inference {
+            let mut new_assignment = inference.clone();
+            let result = backtrack(&inference, &mut new_assignment);
 
             if result.is_some() {
                 return result;
@@ -59,7 +59,7 @@ where
         assignment.set_domain(&var, updated_domain);
     }
    
-    return None;
+    None
 }
 
 fn assignment_complete<T>(csp: &CSP<T>) -> bool
@@ -75,10 +75,10 @@ where
         }
     }
 
-    return is_assignment_complete;
+    is_assignment_complete
 }
 
-fn select_unassigned_variable<'a, T>(csp: &'a CSP<T>, assignment: &'a CSP<T>) -> String
+fn select_unassigned_variable<'a, T>(_csp: &'a CSP<T>, assignment: &'a CSP<T>) -> String
 where
     T: Clone + PartialEq + PartialOrd + std::hash::Hash + Eq + std::fmt::Debug
 {
@@ -90,6 +90,6 @@ where
         }
     }
 
-    return ret_val;
+    ret_val
 }
 
@@ -3,7 +3,7 @@ use std::{
     hash::Hash
 };
 
-type Domain<T> = Vec<T>;
+pub type Domain<T> = Vec<T>;
 
 #[derive(Clone, Copy, PartialEq, PartialOrd)]
 pub enum ConstraintType {
@@ -100,7 +100,7 @@ where
     T: Clone + PartialEq + PartialOrd + Eq + Hash + std::fmt::Debug
 {
     pub fn new(name: &str, value: T, domain: Domain<T>, neighbors: Option<Vec<String>>) -> Self {
-        let neighbors = if neighbors.is_some() { neighbors.unwrap() } else { Vec::new() };
+        let neighbors = neighbors.unwrap_or_default();
         Self {
             name: name.to_owned(), 
             domain,
@@ -145,6 +145,15 @@ where
     constraints: HashMap<(String, String), VecDeque<BinaryConstraint>>
 }
 
+impl<T> Default for CSP<T>
+where
+    T: Clone + PartialEq + PartialOrd + Eq + Hash + std::fmt::Debug,
+ {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T> CSP<T>
 where
     T: Clone + PartialEq + PartialOrd + Eq + Hash + std::fmt::Debug,
@@ -163,7 +172,7 @@ where
     }
 
     pub fn get_arcs(&self) -> VecDeque<(String, String)> {
-        self.constraints.keys().map(|k| k.clone()).collect()
+        self.constraints.keys().cloned().collect()
     }
 
     pub fn satisfies_constraint(&self, x_1: &str, x_2: &str, x: &T, y: &T) -> bool {
@@ -171,11 +180,8 @@ where
     }
 
     pub fn add_constraint(&mut self, x_1: &str, x_2: &str, _ctype: ConstraintType, rtype: RuleType) {
-        if !self.constraints.contains_key(&(x_1.to_owned(), x_2.to_owned())) {
-            self.constraints.insert((x_1.to_owned(), x_2.to_owned()), VecDeque::new());
-        }
         let c = <BinaryConstraint as Constraint<T>>::new(rtype);
-        self.constraints.get_mut(&(x_1.to_owned(), x_2.to_owned())).unwrap().push_back( c );
+        self.constraints.entry((x_1.to_owned(), x_2.to_owned())).or_default().push_back(c);
     }
 
     pub fn get_variable(&self, name: &str) -> &Variable<T> {
@@ -202,7 +208,7 @@ where
             }
         }
 
-        return is_assignment_complete;
+        is_assignment_complete
     }
 
     pub fn get_num_conflicts(&self, name: &str) -> u32 {
@@ -210,7 +216,7 @@ where
         let var = self.get_variable(name);
         for n in &var.neighbors {
             for c in self.constraints.get(&(name.to_string(), n.to_string())).unwrap() {
-                for o in self.get_variable(&n).get_domain() {
+                for o in self.get_variable(n).get_domain() {
                     if !c.is_satisfied(&var.domain[0], &o) {
                         conflicts += 1;
                     }
@@ -226,9 +232,9 @@ where
     T: Clone + PartialEq + PartialOrd + Eq + Hash + std::fmt::Debug
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Variables: \n")?;
+        writeln!(f, "Variables: ")?;
         for var in &self.variables {
-            write!(f, "     {} = {:?}\n", var.0, var.1.get_domain())?;
+            writeln!(f, "     {} = {:?}", var.0, var.1.get_domain())?;
         }
         Ok(())
     }
@@ -257,13 +263,13 @@ impl Rule {
         }
     }
 
-    pub fn check_set<T>(&self, a: &T, b: &Vec<T>) -> bool
+    pub fn check_set<T>(&self, a: &T, b: &[T]) -> bool
     where
         T: PartialEq + PartialOrd
     {
         match self.rtype {
-            RuleType::IsIn => b.contains(&a),
-            RuleType::IsNotIn => !b.contains(&a),
+            RuleType::IsIn => b.contains(a),
+            RuleType::IsNotIn => !b.contains(a),
             _ => false
         }
     }
@@ -285,7 +291,7 @@ macro_rules! alldiff2binary {
         for x_i in &$vars {
             for x_j in &$vars {
                 if ( x_i != x_j ) {
-                    $csp.add_constraint(&x_i, &x_j, constraint::ConstraintType::Binary, constraint::RuleType::NotEqualTo);
+                    $csp.add_constraint(&x_i, &x_j, $crate::ConstraintType::Binary, $crate::RuleType::NotEqualTo);
                 }
             }
         }
@@ -1,15 +1,15 @@
-/// function Min-Conflicts(csp, max_steps) returns a solution or failure
-///     inputs: csp, a constraint satisfaction problem
-///     max_steps, the number of steps allowed before giving up
-///
-///     current <- an initial complete assignment for csp
-///     for i = 1 to max_steps do
-///         if current is a solution for csp then return current
-///         var <- a randomly chosen conflicted variable from csp.Variables
-///         value <- the value v for var that minimizes Conflicts(csp, var, v, current)
-///         set var = value in current
-///     return failure
-///
+//! function Min-Conflicts(csp, max_steps) returns a solution or failure
+//!     inputs: csp, a constraint satisfaction problem
+//!     max_steps, the number of steps allowed before giving up
+//!
+//!     current <- an initial complete assignment for csp
+//!     for i = 1 to max_steps do
+//!         if current is a solution for csp then return current
+//!         var <- a randomly chosen conflicted variable from csp.Variables
+//!         value <- the value v for var that minimizes Conflicts(csp, var, v, current)
+//!         set var = value in current
+//!     return failure
+//!
 
 use rand::prelude::*;
 use crate::algorithms::constraint::*;
@@ -33,7 +33,7 @@ where
         current.set_domain(&rand_var, vec![val]);
     }
 
-    return None;
+    None
 }
 
 fn choose_random_solution<T>(csp: &CSP<T>) -> CSP<T>
@@ -49,7 +49,7 @@ where
         solution.set_domain(&var.0, vec![rand_value]);
     }
 
-    return solution;
+    solution
 }
 
 fn var_minimizing_conflicts<T>(csp: &CSP<T>, rand_var: &str, current: &CSP<T>) -> Option<T>
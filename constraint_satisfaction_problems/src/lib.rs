@@ -0,0 +1,25 @@
+//!
+//! Constraint satisfaction problems from chapter 6 of AIMA.
+//!
+//! A problem is built up as a [`CSP`] of variables with finite domains and binary constraints
+//! between them, and solved by [`backtracking_search`] or [`min_conflicts`]. [`ac3`] makes the
+//! domains arc consistent without searching.
+//!
+
+pub mod algorithms;
+
+pub use algorithms::{
+    constraint::{
+        BinaryConstraint,
+        Constraint,
+        ConstraintType,
+        Domain,
+        Rule,
+        RuleType,
+        Variable,
+        CSP,
+    },
+    ac3::ac3,
+    backtracking_search::backtracking_search,
+    min_conflicts::min_conflicts,
+};
@@ -1,12 +1,12 @@
 use itertools::Itertools;
 
-pub mod algorithms;
-
-use crate::algorithms::{
-    constraint,
-    ac3,
+use constraint_satisfaction_problems::{
+    alldiff2binary,
     backtracking_search,
-    min_conflicts
+    min_conflicts,
+    ConstraintType,
+    RuleType,
+    CSP,
 };
 
 fn main() {
@@ -19,7 +19,7 @@ fn main() {
 fn try_sudoku() {
     
     /* Initialize Sudoku Problem */
-    let mut sudoku = constraint::CSP::new();
+    let mut sudoku = CSP::new();
     let domain = vec![
         Some(1),
         Some(2),
@@ -54,25 +54,23 @@ fn try_sudoku() {
         std::array::from_fn(|i| format!("I{}", i + 1))
     ];
     
-    let mut i = 0;
-    for row in variables.as_ref() {
+    for (i, row) in variables.iter().enumerate() {
         for v in row {
-            sudoku.add_variable(&v, None, domain.clone(), Some(row.clone().into_iter().filter(|i| i != v).map(|i| i.to_owned()).collect()));
+            sudoku.add_variable(v, None, domain.clone(), Some(row.clone().into_iter().filter(|i| i != v).map(|i| i.to_owned()).collect()));
             for s in squares.clone() {
-                if s.contains(&v) {
+                if s.contains(v) {
                     alldiff2binary!(sudoku, s);
                 }
             }
         }
         let mut col: Vec<String> = Vec::new();
-        let all_variables = variables.as_ref().into_iter().flatten().collect::<Vec<&String>>();
+        let all_variables = variables.as_ref().iter().flatten().collect::<Vec<&String>>();
         for c in 0..9 {
             col.push(all_variables[9 * c + i].to_owned());
         }
         alldiff2binary!(sudoku, col);
         let tmp = row.clone();
         alldiff2binary!(sudoku, tmp);
-        i += 1;
     }
 
     /* Set values of known cells */
@@ -101,7 +99,7 @@ fn try_sudoku() {
     sudoku.set_domain("I9", vec![Some(5)]);
 
     /* Solve problem */
-    if let Some(solution) = min_conflicts::min_conflicts(&sudoku, 1000) {
+    if let Some(solution) = min_conflicts(&sudoku, 1000) {
         println!("solution = ");
         for v in solution.get_variables().values().sorted_by_key(|x| x.get_name()) {
             println!("  {} = {:?}", v.get_name(), v.get_domain());
@@ -111,7 +109,7 @@ fn try_sudoku() {
         println!("no solution exists");
     }
 
-    //if let Some(solution) = backtracking_search::backtracking_search(&sudoku) {
+    //if let Some(solution) = backtracking_search(&sudoku) {
     //    println!("solution = ");
     //    for v in solution.get_variables().values().sorted_by_key(|x| x.get_name()) {
     //        println!("  {} = {:?}", v.get_name(), v.get_domain());
@@ -123,7 +121,7 @@ fn try_sudoku() {
 }
 
 fn try_paint_australia() {
-    let mut paint_australia = constraint::CSP::new();
+    let mut paint_australia = CSP::new();
     let domain = vec![
         Some("red"),
         Some("blue"),
@@ -136,25 +134,25 @@ fn try_paint_australia() {
     paint_australia.add_variable("NSW", None, domain.clone(), Some(vec!["Q".to_owned(), "SA".to_owned(), "V".to_owned()]));
     paint_australia.add_variable("V", None, domain.clone(), Some(vec!["SA".to_owned(), "NSW".to_owned()]));
     paint_australia.add_variable("T", None, domain.clone(), Some(vec![]));
-    paint_australia.add_constraint("WA", "NT", constraint::ConstraintType::Binary, constraint::RuleType::NotEqualTo);
-    paint_australia.add_constraint("WA", "SA", constraint::ConstraintType::Binary, constraint::RuleType::NotEqualTo);
-    paint_australia.add_constraint("NT", "WA", constraint::ConstraintType::Binary, constraint::RuleType::NotEqualTo);
-    paint_australia.add_constraint("NT", "SA", constraint::ConstraintType::Binary, constraint::RuleType::NotEqualTo);
-    paint_australia.add_constraint("NT", "Q", constraint::ConstraintType::Binary, constraint::RuleType::NotEqualTo);
-    paint_australia.add_constraint("SA", "WA", constraint::ConstraintType::Binary, constraint::RuleType::NotEqualTo);
-    paint_australia.add_constraint("SA", "NT", constraint::ConstraintType::Binary, constraint::RuleType::NotEqualTo);
-    paint_australia.add_constraint("SA", "Q", constraint::ConstraintType::Binary, constraint::RuleType::NotEqualTo);
-    paint_australia.add_constraint("SA", "NSW", constraint::ConstraintType::Binary, constraint::RuleType::NotEqualTo);
-    paint_australia.add_constraint("SA", "V", constraint::ConstraintType::Binary, constraint::RuleType::NotEqualTo);
-    paint_australia.add_constraint("Q", "NT", constraint::ConstraintType::Binary, constraint::RuleType::NotEqualTo);
-    paint_australia.add_constraint("Q", "SA", constraint::ConstraintType::Binary, constraint::RuleType::NotEqualTo);
-    paint_australia.add_constraint("Q", "NSW", constraint::ConstraintType::Binary, constraint::RuleType::NotEqualTo);
-    paint_australia.add_constraint("NSW", "Q", constraint::ConstraintType::Binary, constraint::RuleType::NotEqualTo);
-    paint_australia.add_constraint("NSW", "V", constraint::ConstraintType::Binary, constraint::RuleType::NotEqualTo);
-    paint_australia.add_constraint("NSW", "SA", constraint::ConstraintType::Binary, constraint::RuleType::NotEqualTo);
-    paint_australia.add_constraint("V", "SA", constraint::ConstraintType::Binary, constraint::RuleType::NotEqualTo);
-    paint_australia.add_constraint("V", "NSW", constraint::ConstraintType::Binary, constraint::RuleType::NotEqualTo);
-    if let Some(solution) = backtracking_search::backtracking_search(&paint_australia) {
+    paint_australia.add_constraint("WA", "NT", ConstraintType::Binary, RuleType::NotEqualTo);
+    paint_australia.add_constraint("WA", "SA", ConstraintType::Binary, RuleType::NotEqualTo);
+    paint_australia.add_constraint("NT", "WA", ConstraintType::Binary, RuleType::NotEqualTo);
+    paint_australia.add_constraint("NT", "SA", ConstraintType::Binary, RuleType::NotEqualTo);
+    paint_australia.add_constraint("NT", "Q", ConstraintType::Binary, RuleType::NotEqualTo);
+    paint_australia.add_constraint("SA", "WA", ConstraintType::Binary, RuleType::NotEqualTo);
+    paint_australia.add_constraint("SA", "NT", ConstraintType::Binary, RuleType::NotEqualTo);
+    paint_australia.add_constraint("SA", "Q", ConstraintType::Binary, RuleType::NotEqualTo);
+    paint_australia.add_constraint("SA", "NSW", ConstraintType::Binary, RuleType::NotEqualTo);
+    paint_australia.add_constraint("SA", "V", ConstraintType::Binary, RuleType::NotEqualTo);
+    paint_australia.add_constraint("Q", "NT", ConstraintType::Binary, RuleType::NotEqualTo);
+    paint_australia.add_constraint("Q", "SA", ConstraintType::Binary, RuleType::NotEqualTo);
+    paint_australia.add_constraint("Q", "NSW", ConstraintType::Binary, RuleType::NotEqualTo);
+    paint_australia.add_constraint("NSW", "Q", ConstraintType::Binary, RuleType::NotEqualTo);
+    paint_australia.add_constraint("NSW", "V", ConstraintType::Binary, RuleType::NotEqualTo);
+    paint_australia.add_constraint("NSW", "SA", ConstraintType::Binary, RuleType::NotEqualTo);
+    paint_australia.add_constraint("V", "SA", ConstraintType::Binary, RuleType::NotEqualTo);
+    paint_australia.add_constraint("V", "NSW", ConstraintType::Binary, RuleType::NotEqualTo);
+    if let Some(solution) = backtracking_search(&paint_australia) {
         println!("Solution = ");
         for v in solution.get_variables().values() {
             println!("  {} = {:?}", v.get_name(), v.get_domain());
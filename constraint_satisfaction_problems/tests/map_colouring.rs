@@ -0,0 +1,69 @@
+use constraint_satisfaction_problems::{
+    ac3,
+    alldiff2binary,
+    backtracking_search,
+    ConstraintType,
+    RuleType,
+    CSP,
+};
+
+const REGIONS: [(&str, &[&str]); 7] = [
+    ("WA", &["NT", "SA"]),
+    ("NT", &["WA", "SA", "Q"]),
+    ("SA", &["WA", "NT", "Q", "NSW", "V"]),
+    ("Q", &["NT", "SA", "NSW"]),
+    ("NSW", &["Q", "SA", "V"]),
+    ("V", &["SA", "NSW"]),
+    ("T", &[]),
+];
+
+fn australia(colours: &[&'static str]) -> CSP<Option<&'static str>> {
+    let mut csp = CSP::new();
+    let domain: Vec<_> = colours.iter().copied().map(Some).collect();
+    for (region, neighbors) in REGIONS {
+        csp.add_variable(region, None, domain.clone(), Some(neighbors.iter().map(|n| n.to_string()).collect()));
+        for n in neighbors {
+            csp.add_constraint(region, n, ConstraintType::Binary, RuleType::NotEqualTo);
+        }
+    }
+    csp
+}
+
+#[test]
+fn backtracking_colours_australia() {
+    let solution = backtracking_search(&australia(&["red", "green", "blue"])).expect("Australia is 3-colourable");
+    assert!(solution.assignment_complete());
+    for (region, neighbors) in REGIONS {
+        let colour = solution.get_variable(region).get_domain();
+        assert_eq!(colour.len(), 1);
+        for n in neighbors {
+            assert_ne!(colour, solution.get_variable(n).get_domain(), "{} and {} share a colour", region, n);
+        }
+    }
+}
+
+#[test]
+fn two_colours_are_not_enough() {
+    assert!(backtracking_search(&australia(&["red", "green"])).is_none());
+}
+
+#[test]
+fn ac3_detects_an_empty_domain() {
+    let mut csp = australia(&["red", "green", "blue"]);
+    csp.set_domain("WA", vec![Some("red")]);
+    csp.set_domain("NT", vec![Some("red")]);
+    assert!(ac3(&csp).is_none());
+}
+
+#[test]
+fn alldiff_expands_to_binary_constraints() {
+    let mut csp = CSP::new();
+    let vars = vec!["X".to_string(), "Y".to_string(), "Z".to_string()];
+    for v in &vars {
+        csp.add_variable(v, 0, vec![1, 2, 3], Some(vars.iter().filter(|o| *o != v).cloned().collect()));
+    }
+    alldiff2binary!(csp, vars);
+    assert_eq!(csp.get_arcs().len(), 6);
+    assert!(csp.satisfies_constraint("X", "Y", &1, &2));
+    assert!(!csp.satisfies_constraint("Z", "X", &3, &3));
+}
@@ -0,0 +1,320 @@
+//!
+//! Runs every search algorithm on Romania, the 8-puzzle, a grid map and random graphs.
+//!
+//!     cargo bench -p search --bench algorithms [-- <filter>]
+//!
+//! Criterion reports the runtime of each run. Afterwards the mean runtime it measured is combined
+//! with the node counts of the search into a table, printed as markdown and written to
+//! target/criterion/search-summary.csv and .md, with the columns
+//!     depth       number of actions of the solution
+//!     b*          effective branching factor (see `Statistics::effective_branching_factor`)
+//!     penetrance  solution depth / generated nodes
+//!     ns/node     runtime / generated nodes
+//!
+//! Iterative deepening keeps no reached set, so it searches every path to a state, and the work
+//! grows exponentially with the depth of the solution. It runs on every family, but is left out
+//! on the 26-move 8-puzzle, the 58-move route across the grid and the large random graph, which
+//! it does not finish within 30 seconds; the grid has a 12-move case for it.
+//! IDA* and RBFS are left out on the large random graph: nearly every path has its own cost, so
+//! each new f limit admits only a few more nodes, and they search the graph again for every one.
+//!
+
+/* Std library */
+use std::fmt::{Debug, Write as _};
+use std::hash::Hash;
+use std::hint::black_box;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/* External crates */
+use criterion::{criterion_group, Criterion};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/* Internal crates */
+use search::{
+    astar_search_traced,
+    best_first_search_traced,
+    bibf_search_traced,
+    breadth_first_search_traced,
+    ida_star_search_traced,
+    iterative_deepening_search_traced,
+    recursive_best_first_search_traced,
+    uniform_cost_search_traced,
+    AradToBucharestProblem,
+    Board,
+    Cell,
+    GraphProblem,
+    GridMapProblem,
+    NPuzzleHeuristic,
+    NPuzzleProblem,
+    Node,
+    NoTracer,
+    Problem,
+    SearchResult,
+    Statistics,
+    Tracer,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Algorithm {
+    Bfs,
+    Ucs,
+    Greedy,
+    Astar,
+    IdaStar,
+    Ids,
+    Bibf,
+    Rbfs,
+}
+
+const ALGORITHMS: [Algorithm; 8] = [
+    Algorithm::Bfs,
+    Algorithm::Ucs,
+    Algorithm::Greedy,
+    Algorithm::Astar,
+    Algorithm::IdaStar,
+    Algorithm::Ids,
+    Algorithm::Bibf,
+    Algorithm::Rbfs,
+];
+
+impl Algorithm {
+    fn name(&self) -> &'static str {
+        match self {
+            Algorithm::Bfs => "bfs",
+            Algorithm::Ucs => "ucs",
+            Algorithm::Greedy => "greedy",
+            Algorithm::Astar => "astar",
+            Algorithm::IdaStar => "ida-star",
+            Algorithm::Ids => "ids",
+            Algorithm::Bibf => "bibf",
+            Algorithm::Rbfs => "rbfs",
+        }
+    }
+
+    fn run<P, S, A, T>(&self, problem: &P, tracer: &mut T) -> SearchResult<S, A>
+    where
+        P: Problem<S, A>,
+        S: Clone + Eq + Ord + Hash + Debug,
+        A: Clone + Eq + Ord + Hash + Debug,
+        T: Tracer<S, A>
+    {
+        let h: fn(&P, &Node<S, A>) -> u32 = |problem, node| problem.get_heuristic_cost(&node.state);
+        match self {
+            Algorithm::Bfs => breadth_first_search_traced(problem, tracer),
+            Algorithm::Ucs => uniform_cost_search_traced(problem, tracer),
+            Algorithm::Greedy => best_first_search_traced(problem, h, tracer),
+            Algorithm::Astar => astar_search_traced(problem, h, tracer),
+            Algorithm::IdaStar => ida_star_search_traced(problem, h, tracer),
+            Algorithm::Ids => iterative_deepening_search_traced(problem, tracer),
+            Algorithm::Bibf => bibf_search_traced(problem, tracer),
+            Algorithm::Rbfs => recursive_best_first_search_traced(problem, h, tracer),
+        }
+    }
+}
+
+/// One line of the summary
+struct Row {
+    family: &'static str,
+    case: String,
+    algorithm: Algorithm,
+    /// Cost and depth of the solution, None if the search failed
+    solution: Option<(u32, usize)>,
+    statistics: Statistics,
+    /// Mean time of a run, over all the runs of criterion
+    time: Duration,
+}
+
+/// Benchmarks every algorithm but the skipped ones on the problem, and adds their rows
+fn bench_case<P, S, A>(c: &mut Criterion, rows: &mut Vec<Row>, family: &'static str, case: &str, problem: &P, skip: &[Algorithm])
+where
+    P: Problem<S, A>,
+    S: Clone + Eq + Ord + Hash + Debug,
+    A: Clone + Eq + Ord + Hash + Debug
+{
+    let mut group = c.benchmark_group(format!("{}/{}", family, case));
+    for algorithm in ALGORITHMS.iter().filter(|a| !skip.contains(a)) {
+        let (mut total, mut runs) = (Duration::ZERO, 0);
+        group.bench_function(algorithm.name(), |b| b.iter_custom(|iterations| {
+            let start = Instant::now();
+            for _ in 0..iterations {
+                black_box(algorithm.run(black_box(problem), &mut NoTracer).ok());
+            }
+            let elapsed = start.elapsed();
+            total += elapsed;
+            runs += iterations;
+            elapsed
+        }));
+        /* Not run when filtered out */
+        if runs == 0 {
+            continue;
+        }
+
+        let mut statistics = Statistics::default();
+        let solution = algorithm.run(problem, &mut statistics).ok().map(|node| {
+            let mut depth = 0;
+            let mut n = &node;
+            while let Some(parent) = &n.parent {
+                depth += 1;
+                n = parent;
+            }
+            (node.path_cost, depth)
+        });
+        rows.push(Row {
+            family,
+            case: case.to_string(),
+            algorithm: *algorithm,
+            solution,
+            statistics,
+            time: total / runs as u32,
+        });
+    }
+    group.finish();
+}
+
+fn romania(c: &mut Criterion, rows: &mut Vec<Row>) {
+    for start in ["Arad", "Oradea", "Neamt"] {
+        let problem: AradToBucharestProblem = AradToBucharestProblem::new(start, "Bucharest").unwrap();
+        bench_case(c, rows, "romania", &format!("{}-bucharest", start.to_lowercase()), &problem, &[]);
+    }
+}
+
+fn npuzzle(c: &mut Criterion, rows: &mut Vec<Row>) {
+    /* Optimal solutions of 8, 16 and 26 moves; the last one is the start state of AIMA figure 3.3 */
+    let boards = [
+        ("depth-8", "1,5,4,3,7,2,6,8,0"),
+        ("depth-16", "3,1,7,2,4,5,6,8,0"),
+        ("depth-26", "7,2,4,5,0,6,8,3,1"),
+    ];
+    for (case, board) in boards {
+        let start: Board = board.parse().unwrap();
+        let problem = NPuzzleProblem::new(start, Board::goal(3), NPuzzleHeuristic::Manhattan).unwrap();
+        let skip: &[Algorithm] = if case == "depth-26" { &[Algorithm::Ids] } else { &[] };
+        bench_case(c, rows, "npuzzle", case, &problem, skip);
+    }
+}
+
+fn grid(c: &mut Criterion, rows: &mut Vec<Row>) {
+    let map = include_str!("../fixtures/grid/rooms32.map");
+    let problem = GridMapProblem::parse(map, None, None).unwrap();
+    bench_case(c, rows, "grid", "rooms32", &problem, &[Algorithm::Ids]);
+    /* A goal 12 moves from the start, in the same room */
+    let problem = GridMapProblem::parse(map, None, Some(Cell { x: 7, y: 7 })).unwrap();
+    bench_case(c, rows, "grid", "rooms32-12-moves", &problem, &[]);
+}
+
+/// A random geometric graph: the nodes are points in a square, connected to their nearest
+/// neighbours. Arcs cost their length rounded up and the heuristic is the straight-line distance
+/// rounded down, so it is admissible. The goal is the reachable node farthest from the start.
+fn random_graph(nodes: usize, neighbours: usize, seed: u64) -> GraphProblem {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let points: Vec<(f64, f64)> = (0..nodes).map(|_| (rng.gen_range(0.0..1000.0), rng.gen_range(0.0..1000.0))).collect();
+    let distance = |u: usize, v: usize| (points[u].0 - points[v].0).hypot(points[u].1 - points[v].1);
+
+    let mut file = String::new();
+    let mut adjacent = vec![Vec::new(); nodes];
+    for u in 0..nodes {
+        let mut nearest: Vec<usize> = (0..nodes).filter(|v| *v != u).collect();
+        nearest.sort_by(|a, b| distance(u, *a).total_cmp(&distance(u, *b)));
+        for v in nearest.into_iter().take(neighbours) {
+            writeln!(file, "edge n{} n{} {}", u, v, distance(u, v).ceil()).unwrap();
+            adjacent[u].push(v);
+            adjacent[v].push(u);
+        }
+    }
+
+    let mut reachable = vec![false; nodes];
+    let mut stack = vec![0];
+    while let Some(u) = stack.pop() {
+        if !std::mem::replace(&mut reachable[u], true) {
+            stack.extend(&adjacent[u]);
+        }
+    }
+    let goal = (1..nodes)
+        .filter(|v| reachable[*v])
+        .max_by(|a, b| distance(0, *a).total_cmp(&distance(0, *b)))
+        .unwrap();
+    for u in 0..nodes {
+        writeln!(file, "h n{} {}", u, distance(u, goal).floor()).unwrap();
+    }
+    writeln!(file, "start n0\ngoal n{}", goal).unwrap();
+    GraphProblem::parse(&file, None, None).unwrap()
+}
+
+fn random_graphs(c: &mut Criterion, rows: &mut Vec<Row>) {
+    let skip = [Algorithm::Ids, Algorithm::IdaStar, Algorithm::Rbfs];
+    for (nodes, skip) in [(100, &skip[..0]), (1000, &skip[..])] {
+        let problem = random_graph(nodes, 4, 0x5EA2C4);
+        bench_case(c, rows, "random-graph", &format!("{}-nodes", nodes), &problem, skip);
+    }
+}
+
+fn format_number(value: Option<f64>, decimals: usize) -> String {
+    value.map(|v| format!("{:.*}", decimals, v)).unwrap_or_else(|| String::from("-"))
+}
+
+/* The cells of a row, in the order of HEADER */
+const HEADER: [&str; 11] = [
+    "family", "case", "algorithm", "cost", "depth", "generated", "expanded",
+    "time (us)", "b*", "penetrance", "ns/node",
+];
+
+fn cells(row: &Row) -> Vec<String> {
+    let s = &row.statistics;
+    let depth = row.solution.map(|(_, depth)| depth);
+    let time = row.time.as_secs_f64();
+    vec![
+        row.family.to_string(),
+        row.case.clone(),
+        row.algorithm.name().to_string(),
+        row.solution.map(|(cost, _)| cost.to_string()).unwrap_or_else(|| String::from("failure")),
+        depth.map(|d| d.to_string()).unwrap_or_else(|| String::from("-")),
+        s.generated.to_string(),
+        s.expanded.to_string(),
+        format!("{:.1}", time * 1e6),
+        format_number(depth.and_then(|d| s.effective_branching_factor(d)), 3),
+        format_number(depth.and_then(|d| s.penetrance(d)), 4),
+        format_number((s.generated > 0).then(|| time * 1e9 / s.generated as f64), 1),
+    ]
+}
+
+fn write_summary(rows: &[Row]) -> std::io::Result<()> {
+    let mut csv = HEADER.join(",") + "\n";
+    let mut markdown = format!("| {} |\n|{}\n", HEADER.join(" | "), "---|".repeat(HEADER.len()));
+    for row in rows {
+        let cells = cells(row);
+        csv += &(cells.join(",") + "\n");
+        markdown += &format!("| {} |\n", cells.join(" | "));
+    }
+    println!("\n{}", markdown);
+
+    /* Next to the reports of criterion */
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).parent().unwrap().join("criterion");
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join("search-summary.csv"), csv)?;
+    std::fs::write(dir.join("search-summary.md"), markdown)?;
+    println!("Summary written to {}", dir.join("search-summary.{csv,md}").display());
+    Ok(())
+}
+
+fn algorithms(c: &mut Criterion) {
+    let mut rows = Vec::new();
+    romania(c, &mut rows);
+    npuzzle(c, &mut rows);
+    grid(c, &mut rows);
+    random_graphs(c, &mut rows);
+    if !rows.is_empty() {
+        write_summary(&rows).expect("Could not write the summary");
+    }
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10).warm_up_time(Duration::from_millis(500)).measurement_time(Duration::from_secs(2));
+    targets = algorithms
+}
+
+fn main() {
+    benches();
+    Criterion::default().configure_from_args().final_summary();
+}
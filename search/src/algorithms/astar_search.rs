@@ -0,0 +1,44 @@
+//!
+//! function A*-Search(problem, h) returns a solution node or failure
+//!     return Best-First-Search(problem, g + h)
+//!
+//! where g(n) is the path cost of n. With an admissible heuristic h, which never overestimates
+//! the cost to reach a goal, the solution is optimal.
+//!
+
+/* Std library */
+use std::hash::Hash;
+
+/* Internal crates */
+use crate::algorithms::{
+    best_first_search::best_first_search_traced,
+    cost::Cost,
+    node::Node,
+    problem::*,
+    tracer::{Tracer, NoTracer},
+};
+
+pub fn astar_search<P, S, A, C>(problem: &P, h: fn(&P, &Node<S, A, C>) -> C) -> SearchResult<S, A, C>
+where
+    P: Problem<S, A, C>,
+    S: Clone + Eq + Hash,
+    A: Clone,
+    C: Cost + Ord
+{
+    astar_search_traced(problem, h, &mut NoTracer)
+}
+
+pub fn astar_search_traced<P, S, A, C, T>(problem: &P, h: fn(&P, &Node<S, A, C>) -> C, tracer: &mut T) -> SearchResult<S, A, C>
+where
+    P: Problem<S, A, C>,
+    S: Clone + Eq + Hash,
+    A: Clone,
+    C: Cost + Ord,
+    T: Tracer<S, A, C>
+{
+    /* f only orders the frontier, so a sum too large for C is treated as infinite */
+    let f = |problem: &P, node: &Node<S, A, C>| {
+        node.path_cost.checked_add(h(problem, node)).unwrap_or_else(C::max_value)
+    };
+    best_first_search_traced(problem, f, tracer)
+}
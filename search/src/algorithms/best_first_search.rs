@@ -1,63 +1,92 @@
-///
-///
-/// Best first search algorithm based on the following pseudo-code from AIMA
-///
-/// function Best-First-Search(problem, f) returns a solution node or failure
-///     node <- Node(State=problem.INITIAL)
-///     frontier <- a priority queue ordered by f, with node as an element
-///     reached <- a lookup table, with one entry with key problem.INITIAL and value node
-///
-///     while not Is_Empty(frontier) do
-///         node <- Pop(frontier)
-///         if problem.Is_Goal(node.STATE) then return node
-///         for each child in Expand(node.STATE) do
-///             s <- child.STATE
-///             if s is not in reached or child.PATH_COST < reached[s].PATH_COST then
-///                 reached[s] <- child
-///                 add child to frontier
-///     return failure
-///
-/// function Expand(problem, node) yields nodes
-///     s <- node.STATE
-///     for each action in problem.ACTIONS(s) do
-///         s' <- problem.RESULT(s,action)
-///         cost <- node.PATH_COST + problem.ACTION_COST(s,actions,s')
-///         yield Node(State=s', Parent=node, Action=action, Path_Cost=cost)
-///
+//!
+//!
+//! Best first search algorithm based on the following pseudo-code from AIMA
+//!
+//! function Best-First-Search(problem, f) returns a solution node or failure
+//!     node <- Node(State=problem.INITIAL)
+//!     frontier <- a priority queue ordered by f, with node as an element
+//!     reached <- a lookup table, with one entry with key problem.INITIAL and value node
+//!
+//!     while not Is_Empty(frontier) do
+//!         node <- Pop(frontier)
+//!         if problem.Is_Goal(node.STATE) then return node
+//!         for each child in Expand(node.STATE) do
+//!             s <- child.STATE
+//!             if s is not in reached or child.PATH_COST < reached[s].PATH_COST then
+//!                 reached[s] <- child
+//!                 add child to frontier
+//!     return failure
+//!
+//! function Expand(problem, node) yields nodes
+//!     s <- node.STATE
+//!     for each action in problem.ACTIONS(s) do
+//!         s' <- problem.RESULT(s,action)
+//!         cost <- node.PATH_COST + problem.ACTION_COST(s,actions,s')
+//!         yield Node(State=s', Parent=node, Action=action, Path_Cost=cost)
+//!
 
 /* Std library */
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
 use std::hash::Hash;
 
 /* Internal crates */
+use crate::algorithms::cost::Cost;
 use crate::algorithms::node::Node;
 use crate::algorithms::problem::*;
+use crate::algorithms::tracer::{Tracer, NoTracer};
 
 
 
-pub fn best_first_search<P, S, A>(problem: &P) -> SearchResult<S, A> 
+/// Best-first search ordered by `f`, which is also stored in the `f` field of the nodes.
+/// Nodes with equal f are taken in the order they were generated.
+pub fn best_first_search<P, S, A, C, F>(problem: &P, f: F) -> SearchResult<S, A, C>
 where
-    P: Problem<S, A>,
-    S: Clone + Eq + Ord + Hash,
-    A: Clone + Eq + Ord + Hash
+    P: Problem<S, A, C>,
+    S: Clone + Eq + Hash,
+    A: Clone,
+    C: Cost + Ord,
+    F: Fn(&P, &Node<S, A, C>) -> C
+{
+    best_first_search_traced(problem, f, &mut NoTracer)
+}
+
+pub fn best_first_search_traced<P, S, A, C, F, T>(problem: &P, f: F, tracer: &mut T) -> SearchResult<S, A, C>
+where
+    P: Problem<S, A, C>,
+    S: Clone + Eq + Hash,
+    A: Clone,
+    C: Cost + Ord,
+    F: Fn(&P, &Node<S, A, C>) -> C,
+    T: Tracer<S, A, C>
 {
     let mut node = problem.get_initial_node();
-    let mut frontier: Vec<Node<S, A>> = vec![node.clone()];
-    let mut reached: HashMap<S, Node<S, A>> = HashMap::new(); 
-    reached.insert(node.state.clone(), node.clone());
-    
-    while !frontier.is_empty() {
-        frontier.sort();
-        node = frontier.pop().unwrap();
+    node.f = f(problem, &node);
+    tracer.generated(&node);
+    let mut frontier: BinaryHeap<FrontierEntry<S, A, C>> = BinaryHeap::new();
+    let mut generated = 0;
+    frontier.push(FrontierEntry { node: node.clone(), order: generated });
+    let mut reached: HashMap<S, Node<S, A, C>> = HashMap::new();
+    reached.insert(node.state.clone(), node);
+
+    while let Some(entry) = frontier.pop() {
+        node = entry.node;
         if problem.is_goal(&node.state) {
+            tracer.solution(&node);
             return Ok(node);
         }
 
-        for child in expand(problem, node) {
+        tracer.expanded(&node);
+        for mut child in expand(problem, node)? {
+            child.f = f(problem, &child);
+            tracer.generated(&child);
             let s = child.state.clone();
             if !reached.contains_key(&s) || child.path_cost < reached.get(&s).unwrap().path_cost {
                 reached.insert(s, child.clone());
-                frontier.push(child.clone());
+                generated += 1;
+                frontier.push(FrontierEntry { node: child, order: generated });
+            } else {
+                tracer.pruned(&child);
             }
         }
     }
@@ -65,3 +94,28 @@ where
     Err(SearchError::Failure)
 }
 
+/* A node on the frontier. The heap pops the entry with the lowest f, then the oldest one. */
+struct FrontierEntry<S, A, C> {
+    node: Node<S, A, C>,
+    order: usize,
+}
+
+impl<S, A, C: Ord> Ord for FrontierEntry<S, A, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.node.f.cmp(&self.node.f).then(other.order.cmp(&self.order))
+    }
+}
+
+impl<S, A, C: Ord> PartialOrd for FrontierEntry<S, A, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, A, C: Ord> PartialEq for FrontierEntry<S, A, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S, A, C: Ord> Eq for FrontierEntry<S, A, C> {}
@@ -1,45 +1,51 @@
-///
-/// function Bibf_Search(problem_F, f_F, problem_B, f_B) returns a solution node or failure
-///     node_F <- Node(problem_F.initial)   // Node for a start gate
-///     node_B <- Node(problem_B.initial)   // Node for a goal state
-///     frontier_F <- a priority queue ordered by f_F, with node_F as an element
-///     frontier_B <- a priority queue ordered by f_B, with node_B as an element
-///     reached_F <- a lookup table, with one key node_F.State and value node_F
-///     reached_B <- a lookup table, with one key node_B.State and value node_B
-///     solution <- failure
-///
-///     while not Terminated(solution, frontier_F, frontier_B) do
-///         if f_F(Top(frontier_F)) < f_B(Top(frontier_B)) then
-///             solution <- Proceed(F, problem_F, frontier_F, reached_F, reached_B, solution)
-///         else solution <- Proceed(B, problem_B, frontier_B, reached_B, reached_F, solution)
-///     return solution
-///
-///
-/// function Proceed(dir, problem, frontier, reached, reached_2, solution) returns a solution
-///         // Expand node on frontier; check against the other frontier in reached_2.
-///         // The variable "dir" is the direction: either F for forward or B for backward
-///     node <- Pop(frontier)
-///     for each child in Expand(problem, node) do
-///         s <- child.State
-///         if s not in reached or Path-Cost(child) < Path-Cost(reached[s]) then
-///             reached[s] <- child
-///             add child to frontier
-///             if s is in reached_2 then
-///                 solution_2 <- Join-Nodes(dir, child, reached_2[s]))
-///                 if Path-Cost(solution_2) < Path-Cost(solution) then
-///                     solution <- solution_2
-///     return solution
-///
+//!
+//! function Bibf_Search(problem_F, f_F, problem_B, f_B) returns a solution node or failure
+//!     node_F <- Node(problem_F.initial)   // Node for a start gate
+//!     node_B <- Node(problem_B.initial)   // Node for a goal state
+//!     frontier_F <- a priority queue ordered by f_F, with node_F as an element
+//!     frontier_B <- a priority queue ordered by f_B, with node_B as an element
+//!     reached_F <- a lookup table, with one key node_F.State and value node_F
+//!     reached_B <- a lookup table, with one key node_B.State and value node_B
+//!     solution <- failure
+//!
+//!     while not Terminated(solution, frontier_F, frontier_B) do
+//!         if f_F(Top(frontier_F)) < f_B(Top(frontier_B)) then
+//!             solution <- Proceed(F, problem_F, frontier_F, reached_F, reached_B, solution)
+//!         else solution <- Proceed(B, problem_B, frontier_B, reached_B, reached_F, solution)
+//!     return solution
+//!
+//!
+//! function Proceed(dir, problem, frontier, reached, reached_2, solution) returns a solution
+//!         // Expand node on frontier; check against the other frontier in reached_2.
+//!         // The variable "dir" is the direction: either F for forward or B for backward
+//!     node <- Pop(frontier)
+//!     for each child in Expand(problem, node) do
+//!         s <- child.State
+//!         if s not in reached or Path-Cost(child) < Path-Cost(reached[s]) then
+//!             reached[s] <- child
+//!             add child to frontier
+//!             if s is in reached_2 then
+//!                 solution_2 <- Join-Nodes(dir, child, reached_2[s]))
+//!                 if Path-Cost(solution_2) < Path-Cost(solution) then
+//!                     solution <- solution_2
+//!     return solution
+//!
+//! A problem with several goal states starts the backward search from all of them at once: the
+//! backward frontier and reached_B hold one node per goal (`Problem::get_goal_nodes`).
+//!
 
 use std::{
-    collections::{HashMap, VecDeque},
+    cmp::Reverse,
+    collections::HashMap,
     hash::Hash,
     fmt::Debug
 };
 
 use crate::algorithms::{
+    cost::Cost,
     problem::*,
-    node::*
+    node::*,
+    tracer::{Tracer, NoTracer},
 };
 
 enum Direction {
@@ -47,42 +53,79 @@ enum Direction {
     Backward
 }
 
-pub fn bibf_search<P, S, A>(problem: &P) -> SearchResult<S, A> 
+pub fn bibf_search<P, S, A, C>(problem: &P) -> SearchResult<S, A, C> 
 where
-    P: Problem<S, A>,
+    P: Problem<S, A, C>,
     S: Clone + Eq + Ord + Hash + Debug,
-    A: Clone + Eq + Ord + Hash + Debug
+    A: Clone + Eq + Ord + Hash + Debug,
+    C: Cost + Ord
+{
+    bibf_search_traced(problem, &mut NoTracer)
+}
+
+/// Both search trees are reported to the same tracer, the backward one rooted in the goals
+pub fn bibf_search_traced<P, S, A, C, T>(problem: &P, tracer: &mut T) -> SearchResult<S, A, C> 
+where
+    P: Problem<S, A, C>,
+    S: Clone + Eq + Ord + Hash + Debug,
+    A: Clone + Eq + Ord + Hash + Debug,
+    C: Cost + Ord,
+    T: Tracer<S, A, C>
 {
     let node_f = problem.get_initial_node();
-    let node_b = problem.get_goal_node();
-    let mut frontier_f: Vec<Node<S, A>> = vec![node_f.clone()];
-    let mut frontier_b: Vec<Node<S, A>> = vec![node_b.clone()];
-    let mut reached_f: HashMap<S, Node<S, A>> = HashMap::new();
-    let mut reached_b: HashMap<S, Node<S, A>> = HashMap::new();
+    tracer.generated(&node_f);
+    if problem.is_goal(&node_f.state) {
+        tracer.solution(&node_f);
+        return Ok(node_f);
+    }
+    let mut frontier_f: Vec<Node<S, A, C>> = vec![node_f.clone()];
+    let mut frontier_b: Vec<Node<S, A, C>> = Vec::new();
+    let mut reached_f: HashMap<S, Node<S, A, C>> = HashMap::new();
+    let mut reached_b: HashMap<S, Node<S, A, C>> = HashMap::new();
     reached_f.insert(node_f.state.clone(), node_f);
-    reached_b.insert(node_b.state.clone(), node_b);
+    for node_b in problem.get_goal_nodes() {
+        if !reached_b.contains_key(&node_b.state) {
+            tracer.generated(&node_b);
+            reached_b.insert(node_b.state.clone(), node_b.clone());
+            frontier_b.push(node_b);
+        }
+    }
     let mut solution = Err(SearchError::Failure);
 
-    while !terminated(&solution, &frontier_f, &frontier_b) {
-        frontier_f.sort();
-        frontier_b.sort();
-        if frontier_f.first().unwrap() < frontier_b.first().unwrap() {
-            solution = proceed(problem, Direction::Forward, &mut frontier_f, &mut reached_f, &mut reached_b, solution.clone());
+    loop {
+        /* Both frontiers are ordered by path cost, descending, so Top is the last node */
+        frontier_f.sort_by_key(|n| Reverse(n.path_cost));
+        frontier_b.sort_by_key(|n| Reverse(n.path_cost));
+        if terminated(&solution, &frontier_f, &frontier_b) {
+            break;
+        }
+
+        if frontier_f.last().unwrap().path_cost < frontier_b.last().unwrap().path_cost {
+            solution = proceed(problem, Direction::Forward, &mut frontier_f, &mut reached_f, &mut reached_b, solution.clone(), tracer);
         } else {
-            solution = proceed(problem, Direction::Backward, &mut frontier_b, &mut reached_b, &mut reached_f, solution.clone());
+            solution = proceed(problem, Direction::Backward, &mut frontier_b, &mut reached_b, &mut reached_f, solution.clone(), tracer);
+        }
+        if let Err(SearchError::CostOverflow) = solution {
+            return solution;
         }
     }
 
+    if let Ok(node) = &solution {
+        tracer.solution(node);
+    }
     solution
 }
 
 
 
-fn proceed<P, S, A>(problem: &P, dir: Direction, frontier: &mut Vec<Node<S, A>>, reached: &mut HashMap<S, Node<S, A>>, reached_2: &mut HashMap<S, Node<S, A>>, solution: SearchResult<S, A>) -> SearchResult<S, A> 
+#[allow(clippy::too_many_arguments)]
+fn proceed<P, S, A, C, T>(problem: &P, dir: Direction, frontier: &mut Vec<Node<S, A, C>>, reached: &mut HashMap<S, Node<S, A, C>>, reached_2: &mut HashMap<S, Node<S, A, C>>, solution: SearchResult<S, A, C>, tracer: &mut T) -> SearchResult<S, A, C> 
 where 
-    P: Problem<S, A>,
+    P: Problem<S, A, C>,
     S: Clone + Eq + Ord + Hash + Debug, 
-    A: Clone + Eq + Ord + Hash + Debug
+    A: Clone + Eq + Ord + Hash + Debug,
+    C: Cost + Ord,
+    T: Tracer<S, A, C>
 {
     if frontier.is_empty() {
         return Err(SearchError::Failure);
@@ -90,65 +133,78 @@ where
 
     let mut new_solution = solution;
     let node = frontier.pop().unwrap();
+    tracer.expanded(&node);
     
-    for child in expand(problem, node.clone()) {
+    for child in expand(problem, node.clone())? {
+        tracer.generated(&child);
         let s = child.state.clone();
 
         if !reached.contains_key(&s) || child.path_cost < reached.get(&s).unwrap().path_cost {
             reached.insert(s.clone(), child.clone());
             frontier.push(child.clone());
             if reached_2.contains_key(&s) {
-                let solution_2 = join_nodes(&dir, child, reached_2.get(&s).unwrap().clone());
-                if new_solution.is_err() || solution_2.path_cost < new_solution.as_ref().unwrap().path_cost {
-                    new_solution = Ok(solution_2);
+                match join_nodes(problem, &dir, child, reached_2.get(&s).unwrap().clone()) {
+                    Ok(solution_2) => {
+                        if new_solution.is_err() || solution_2.path_cost < new_solution.as_ref().unwrap().path_cost {
+                            new_solution = Ok(solution_2);
+                        }
+                    },
+                    /* An arc of the backward tree can not be taken forwards */
+                    Err(SearchError::Failure) => {},
+                    Err(e) => return Err(e),
                 }
             }
+        } else {
+            tracer.pruned(&child);
         }
     }
 
-    return new_solution;
+    new_solution
 }
 
-fn join_nodes<S, A>(dir: &Direction, node: Node<S, A>, reached: Node<S, A>) -> Node<S, A>
+/// Joins a node of the forward tree and a node of the backward tree for the same state into a
+/// path from the initial state to the goal, recomputing the path costs along the backward part
+fn join_nodes<P, S, A, C>(problem: &P, dir: &Direction, node: Node<S, A, C>, reached: Node<S, A, C>) -> SearchResult<S, A, C>
 where
-    S: Clone,
-    A: Clone
+    P: Problem<S, A, C>,
+    S: Clone + Eq,
+    A: Clone,
+    C: Cost
 {
-    // Move all from other chain into a vector
-    let mut prev: Option<Box<Node<S, A>>> = Some(Box::new(node.clone()));
-    let mut tmp_vec: VecDeque<Box<Node<S, A>>> = VecDeque::from([Box::new(reached.clone())]);
-    let mut n = reached.parent;
-
-    while n.is_some() {
-        tmp_vec.push_back(n.as_ref().unwrap().clone());
-        n = n.unwrap().parent;
+    let (mut joined, backward) = match dir {
+        Direction::Forward => (node, reached),
+        Direction::Backward => (reached, node),
+    };
+
+    let mut next = backward.parent;
+    while let Some(b) = next {
+        let action = problem.actions(&joined.state)
+            .into_iter()
+            .find(|a| problem.result(&joined.state, a) == b.state)
+            .ok_or(SearchError::Failure)?;
+        let cost = joined.path_cost
+            .checked_add(problem.action_cost(&joined.state, &action, &b.state))
+            .ok_or(SearchError::CostOverflow)?;
+        joined = Node::new(b.state.clone(), Some(Box::new(joined)), Some(action), cost, cost);
+        next = b.parent;
     }
 
-    tmp_vec.pop_front();
-
-    while let Some(mut current) = tmp_vec.pop_front() {
-          current.parent = prev;
-          prev = Some(current);
-    }
-/*
-    while current.is_some() {
-        next = current.clone().unwrap().parent;
-        current.clone().unwrap().parent = prev;
-        prev = current.clone();
-        current = next;
-    }
-*/
-    *prev.unwrap()
+    Ok(joined)
 }
 
-fn terminated<S, A>(solution: &SearchResult<S, A>, frontier_F: &Vec<Node<S, A>>, frontier_B: &Vec<Node<S, A>>) -> bool 
+/// The search is over when a frontier is empty, or when no path through the frontiers can be
+/// cheaper than the solution: the cheapest nodes of both frontiers together cost at least as much
+fn terminated<S, A, C>(solution: &SearchResult<S, A, C>, frontier_f: &[Node<S, A, C>], frontier_b: &[Node<S, A, C>]) -> bool
 where
     S: Clone,
-    A: Clone
+    A: Clone,
+    C: Cost
 {
-    if frontier_F.is_empty() || frontier_B.is_empty() {
-        true
-    } else {
-        false
+    match (frontier_f.last(), frontier_b.last(), solution) {
+        (Some(top_f), Some(top_b), Ok(solution)) => {
+            top_f.path_cost.checked_add(top_b.path_cost).is_none_or(|c| c >= solution.path_cost)
+        },
+        (Some(_), Some(_), Err(_)) => false,
+        _ => true,
     }
 }
@@ -1,18 +1,18 @@
-///
-/// function Breadth-First-Search(problem) returns a solution node or failure
-///     node <- Node(problem.Initial)
-///     if problem.Is_Goal(node.State) then return node
-///     frontier <- a FIFO queue, with node as an element
-///     reached <- {problem.Initial}
-///
-///     while not Is_Empty(frontier) do
-///         s <- child.State
-///         if problem.Is_Goal(s) then return child
-///         if s is not in reached then
-///             add s to reached
-///             add child to frontier
-///     return failure
-///
+//!
+//! function Breadth-First-Search(problem) returns a solution node or failure
+//!     node <- Node(problem.Initial)
+//!     if problem.Is_Goal(node.State) then return node
+//!     frontier <- a FIFO queue, with node as an element
+//!     reached <- {problem.Initial}
+//!
+//!     while not Is_Empty(frontier) do
+//!         s <- child.State
+//!         if problem.Is_Goal(s) then return child
+//!         if s is not in reached then
+//!             add s to reached
+//!             add child to frontier
+//!     return failure
+//!
 
 use std::collections::{
     VecDeque,
@@ -20,37 +20,60 @@ use std::collections::{
 };
 use std::hash::Hash;
 
-use crate::algorithms::{node::Node, problem::*};
+use crate::algorithms::{
+    best_first_search::best_first_search_traced,
+    cost::Cost,
+    node::Node,
+    problem::*,
+    tracer::{Tracer, NoTracer},
+};
+
 
+pub fn breadth_first_search<P, S, A, C>(problem: &P) -> SearchResult<S, A, C>
+where
+    P: Problem<S, A, C>,
+    S: Clone + Eq + Ord + Hash,
+    A: Clone + Eq + Ord + Hash,
+    C: Cost + Ord
+{
+    breadth_first_search_traced(problem, &mut NoTracer)
+}
 
-pub fn breadth_first_search<P, S, A>(problem: &P) -> SearchResult<S, A>
+pub fn breadth_first_search_traced<P, S, A, C, T>(problem: &P, tracer: &mut T) -> SearchResult<S, A, C>
 where
-    P: Problem<S, A>,
+    P: Problem<S, A, C>,
     S: Clone + Eq + Ord + Hash,
-    A: Clone + Eq + Ord + Hash
+    A: Clone + Eq + Ord + Hash,
+    C: Cost + Ord,
+    T: Tracer<S, A, C>
 {
     
-    let mut node = problem.get_initial_node();
+    let node = problem.get_initial_node();
+    tracer.generated(&node);
     if problem.is_goal(&node.state) {
+        tracer.solution(&node);
         return Ok(node);
     }
     
-    let mut frontier: VecDeque<Node<S, A>> = VecDeque::new();
+    let mut frontier: VecDeque<Node<S, A, C>> = VecDeque::new();
     frontier.push_back(node.clone());
-    let mut reached: HashMap<S, Node<S, A>> = HashMap::new();
+    let mut reached: HashMap<S, Node<S, A, C>> = HashMap::new();
     reached.insert(node.state.clone(), node);
 
 
-    while !frontier.is_empty() {
-        let n = frontier.pop_front().unwrap();
-        
-        for child in expand(problem, n) {
+    while let Some(n) = frontier.pop_front() {
+        tracer.expanded(&n);
+        for child in expand(problem, n)? {
+            tracer.generated(&child);
             if problem.is_goal(&child.state) {
+                tracer.solution(&child);
                 return Ok(child);
             }
             if !reached.contains_key(&child.state) {
                 reached.insert(child.state.clone(), child.clone());
                 frontier.push_back(child);
+            } else {
+                tracer.pruned(&child);
             }
         }
     }
@@ -59,11 +82,24 @@ where
 }
 
 
-pub fn uniform_cost_search<P, S, A>(problem: &P) -> SearchResult<S, A> 
+/// Uniform-cost search: best-first search ordered by the path cost
+pub fn uniform_cost_search<P, S, A, C>(problem: &P) -> SearchResult<S, A, C>
+where
+    P: Problem<S, A, C>,
+    S: Clone + Eq + Ord + Hash,
+    A: Clone + Eq + Ord + Hash,
+    C: Cost + Ord
+{
+    uniform_cost_search_traced(problem, &mut NoTracer)
+}
+
+pub fn uniform_cost_search_traced<P, S, A, C, T>(problem: &P, tracer: &mut T) -> SearchResult<S, A, C>
 where
-    P: Problem<S, A>,
+    P: Problem<S, A, C>,
     S: Clone + Eq + Ord + Hash,
-    A: Clone + Eq + Ord + Hash
+    A: Clone + Eq + Ord + Hash,
+    C: Cost + Ord,
+    T: Tracer<S, A, C>
 {
-    breadth_first_search(problem)
+    best_first_search_traced(problem, |_, node| node.path_cost, tracer)
 }
@@ -0,0 +1,236 @@
+//!
+//! A log of the events reported by a traced algorithm, which can be replayed one expansion at a
+//! time to show the frontier and the reached table of every step, like the AIMA figures do.
+//!
+//! The log does not look inside the algorithm; it rebuilds the frontier and reached from the
+//! events alone:
+//!     - a generated node that is not pruned right away was added to the frontier and to reached,
+//!       replacing the entry of its state
+//!     - an expanded node was taken from the frontier, and so was the solution node
+//!     - a node pruned later on (RBFS forgetting a subtree) leaves the frontier
+//! This is what best-first search (and UCS, greedy search and A*) and bidirectional search do.
+//!
+//! Bidirectional search has a frontier and a reached table in each direction. Its forward tree
+//! is rooted in the first root node that is reported, the backward tree in all later roots (the
+//! goals). The frontiers are shown in the order the nodes are taken out: lowest f first, then
+//! the oldest one.
+//!
+
+/* Std library */
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/* Internal crates */
+use crate::algorithms::{
+    node::Node,
+    tracer::Tracer,
+};
+
+/// Search directions of bidirectional search; the other algorithms only go forward
+pub const FORWARD: usize = 0;
+pub const BACKWARD: usize = 1;
+
+/// A node as it was reported, with its parent as an index into the log
+#[derive(Clone, Debug)]
+pub struct LoggedNode<S, C> {
+    pub parent: Option<usize>,
+    pub state: S,
+    pub action: Option<String>,
+    pub path_cost: C,
+    pub f: C,
+    /// FORWARD, or BACKWARD for the tree that bidirectional search grows from the goals
+    pub direction: usize,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Event {
+    /// The node was generated, and added to the frontier unless it was pruned right away
+    Generated { node: usize, added: bool },
+    Expanded(usize),
+    Pruned(usize),
+    Solution(usize),
+}
+
+/// The search after one step: the expansion of a node, or the initial state of the search, or
+/// the solution that was found
+#[derive(Clone, Debug, Default)]
+pub struct Snapshot {
+    /// The node expanded in this step
+    pub expanded: Option<usize>,
+    /// The nodes generated in this step, and whether they were added to the frontier
+    pub generated: Vec<(usize, bool)>,
+    /// The frontier of each direction after the step, in the order the nodes are taken out
+    pub frontiers: Vec<Vec<usize>>,
+    /// The reached table of each direction after the step, in the order the states were reached
+    pub reached: Vec<Vec<usize>>,
+    pub solution: Option<usize>,
+}
+
+/// Records the events of a search. Nodes are identified by the sequence of states from their
+/// root, like in `DotTracer`.
+pub struct EventLog<S, C = u32> {
+    ids: HashMap<Vec<S>, usize>,
+    nodes: Vec<LoggedNode<S, C>>,
+    events: Vec<Event>,
+}
+
+impl<S, C> Default for EventLog<S, C> {
+    fn default() -> Self {
+        Self { ids: HashMap::new(), nodes: Vec::new(), events: Vec::new() }
+    }
+}
+
+impl<S, C> EventLog<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Clone + Ord
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    pub fn node(&self, id: usize) -> &LoggedNode<S, C> {
+        &self.nodes[id]
+    }
+
+    /// The nodes from the root of its tree down to the given one
+    pub fn path(&self, id: usize) -> Vec<usize> {
+        let mut path = vec![id];
+        while let Some(parent) = self.nodes[*path.last().unwrap()].parent {
+            path.push(parent);
+        }
+        path.reverse();
+        path
+    }
+
+    /// Number of directions the search went in: 2 for bidirectional search, otherwise 1
+    pub fn directions(&self) -> usize {
+        self.nodes.iter().map(|n| n.direction + 1).max().unwrap_or(1)
+    }
+
+    fn lookup<A: Debug>(&mut self, node: &Node<S, A, C>) -> usize {
+        let mut path = vec![node.state.clone()];
+        let mut n = node.parent.as_ref();
+        while let Some(parent) = n {
+            path.push(parent.state.clone());
+            n = parent.parent.as_ref();
+        }
+        path.reverse();
+        if let Some(id) = self.ids.get(&path) {
+            return *id;
+        }
+
+        let parent = node.parent.as_ref().map(|p| self.lookup(p));
+        let direction = match parent {
+            Some(p) => self.nodes[p].direction,
+            None if self.nodes.iter().any(|n| n.parent.is_none()) => BACKWARD,
+            None => FORWARD,
+        };
+        let id = self.nodes.len();
+        self.nodes.push(LoggedNode {
+            parent,
+            state: node.state.clone(),
+            action: node.action.as_ref().map(|a| format!("{:?}", a)),
+            path_cost: node.path_cost.clone(),
+            f: node.f.clone(),
+            direction,
+        });
+        self.ids.insert(path, id);
+        id
+    }
+
+    /// Replays the events: the first snapshot is the search before its first expansion, then
+    /// there is one per expansion, and a last one if a solution was found
+    pub fn snapshots(&self) -> Vec<Snapshot> {
+        let directions = self.directions();
+        let mut current = Snapshot {
+            frontiers: vec![Vec::new(); directions],
+            reached: vec![Vec::new(); directions],
+            ..Snapshot::default()
+        };
+        let mut snapshots = Vec::new();
+
+        for event in &self.events {
+            match *event {
+                Event::Generated { node, added } => {
+                    current.generated.push((node, added));
+                    if added {
+                        let direction = self.nodes[node].direction;
+                        current.frontiers[direction].push(node);
+                        let reached = &mut current.reached[direction];
+                        match reached.iter_mut().find(|r| self.nodes[**r].state == self.nodes[node].state) {
+                            Some(entry) => *entry = node,
+                            None => reached.push(node),
+                        }
+                    }
+                },
+                Event::Expanded(node) => {
+                    snapshots.push(self.ordered(current.clone()));
+                    current.expanded = Some(node);
+                    current.generated.clear();
+                    self.remove(&mut current, node);
+                },
+                Event::Pruned(node) => self.remove(&mut current, node),
+                Event::Solution(node) => {
+                    snapshots.push(self.ordered(current.clone()));
+                    current.expanded = None;
+                    current.generated.clear();
+                    current.solution = Some(node);
+                    self.remove(&mut current, node);
+                },
+            }
+        }
+        snapshots.push(self.ordered(current));
+        snapshots
+    }
+
+    fn remove(&self, snapshot: &mut Snapshot, node: usize) {
+        let frontier = &mut snapshot.frontiers[self.nodes[node].direction];
+        if let Some(i) = frontier.iter().position(|n| *n == node) {
+            frontier.remove(i);
+        }
+    }
+
+    /* The frontiers are kept in the order the nodes were added, so a stable sort breaks ties by age */
+    fn ordered(&self, mut snapshot: Snapshot) -> Snapshot {
+        for frontier in snapshot.frontiers.iter_mut() {
+            frontier.sort_by_key(|n| self.nodes[*n].f.clone());
+        }
+        snapshot
+    }
+}
+
+impl<S, A, C> Tracer<S, A, C> for EventLog<S, C>
+where
+    S: Clone + Eq + Hash,
+    A: Debug,
+    C: Clone + Ord
+{
+    fn generated(&mut self, node: &Node<S, A, C>) {
+        let id = self.lookup(node);
+        self.events.push(Event::Generated { node: id, added: true });
+    }
+
+    fn expanded(&mut self, node: &Node<S, A, C>) {
+        let id = self.lookup(node);
+        self.events.push(Event::Expanded(id));
+    }
+
+    fn pruned(&mut self, node: &Node<S, A, C>) {
+        let id = self.lookup(node);
+        match self.events.last_mut() {
+            Some(Event::Generated { node, added }) if *node == id => *added = false,
+            _ => self.events.push(Event::Pruned(id)),
+        }
+    }
+
+    fn solution(&mut self, node: &Node<S, A, C>) {
+        let id = self.lookup(node);
+        self.events.push(Event::Solution(id));
+    }
+}
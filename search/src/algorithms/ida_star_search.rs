@@ -0,0 +1,117 @@
+//!
+//! function IDA*-Search(problem, h) returns a solution node or failure
+//!     root <- Node(problem.initial)
+//!     bound <- f(root)
+//!     while true do
+//!         result, bound <- Contour(problem, root, bound)
+//!         if result is a node then return result
+//!         if bound = infty then return failure
+//!
+//! function Contour(problem, node, bound) returns a solution node or failure, and a new bound
+//!         // Depth-first search of the nodes with f <= bound; the new bound is the lowest f
+//!         // that was over it
+//!     if f(node) > bound then return failure, f(node)
+//!     if problem.Is-Goal(node.state) then return node, bound
+//!     next <- infty
+//!     for each child in Expand(problem, node) do
+//!         if not Is-Cycle(child) then
+//!             result, f_child <- Contour(problem, child, bound)
+//!             if result is a node then return result, bound
+//!             next <- min(next, f_child)
+//!     return failure, next
+//!
+//! where f(n) = g(n) + h(n). Like A*, the solution is optimal when h is admissible, but the
+//! memory used is only the current path.
+//!
+
+use crate::algorithms::{
+    cost::Cost,
+    problem::*,
+    node::{Node, is_cycle},
+    tracer::{Tracer, NoTracer},
+};
+
+pub fn ida_star_search<P, S, A, C>(problem: &P, h: fn(&P, &Node<S, A, C>) -> C) -> SearchResult<S, A, C>
+where
+    P: Problem<S, A, C>,
+    S: Clone + Eq,
+    A: Clone + Eq,
+    C: Cost + Ord
+{
+    ida_star_search_traced(problem, h, &mut NoTracer)
+}
+
+pub fn ida_star_search_traced<P, S, A, C, T>(problem: &P, h: fn(&P, &Node<S, A, C>) -> C, tracer: &mut T) -> SearchResult<S, A, C>
+where
+    P: Problem<S, A, C>,
+    S: Clone + Eq,
+    A: Clone + Eq,
+    C: Cost + Ord,
+    T: Tracer<S, A, C>
+{
+    let mut root = problem.get_initial_node();
+    root.f = f(problem, h, &root);
+    let mut bound = root.f;
+
+    loop {
+        tracer.generated(&root);
+        let (result, next) = contour(problem, h, root.clone(), bound, tracer);
+        match result {
+            Ok(node) => {
+                tracer.solution(&node);
+                return Ok(node);
+            },
+            Err(SearchError::Failure) if next != C::max_value() => bound = next,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/* Like in A*, a sum too large for C is treated as infinite, so the node is never expanded */
+fn f<P, S, A, C>(problem: &P, h: fn(&P, &Node<S, A, C>) -> C, node: &Node<S, A, C>) -> C
+where
+    P: Problem<S, A, C>,
+    S: Clone,
+    A: Clone,
+    C: Cost
+{
+    node.path_cost.checked_add(h(problem, node)).unwrap_or_else(C::max_value)
+}
+
+fn contour<P, S, A, C, T>(problem: &P, h: fn(&P, &Node<S, A, C>) -> C, node: Node<S, A, C>, bound: C, tracer: &mut T) -> (SearchResult<S, A, C>, C)
+where
+    P: Problem<S, A, C>,
+    S: Clone + Eq,
+    A: Clone + Eq,
+    C: Cost + Ord,
+    T: Tracer<S, A, C>
+{
+    if node.f > bound || node.f == C::max_value() {
+        tracer.pruned(&node);
+        return (Err(SearchError::Failure), node.f);
+    }
+    if problem.is_goal(&node.state) {
+        return (Ok(node), bound);
+    }
+
+    tracer.expanded(&node);
+    let children = match expand(problem, node) {
+        Ok(children) => children,
+        Err(e) => return (Err(e), C::max_value()),
+    };
+    let mut next = C::max_value();
+    for mut child in children {
+        child.f = f(problem, h, &child);
+        tracer.generated(&child);
+        if is_cycle(child.clone()) {
+            tracer.pruned(&child);
+            continue;
+        }
+        let (result, f_child) = contour(problem, h, child, bound, tracer);
+        if !matches!(result, Err(SearchError::Failure)) {
+            return (result, bound);
+        }
+        next = next.min(f_child);
+    }
+    (Err(SearchError::Failure), next)
+}
@@ -1,73 +1,109 @@
-///
-/// function Iterative_Deepening_Search(problem) returns a solution node or failure
-///     for depth = 0 to infty do
-///         result <- Depth_Limited_Search(problem, depth)
-///         if result != cutoff then return result
-///
-/// function Depth_Limited_Search(problem, l) returns a node or failure or cutoff
-///     frontier <- a LIFO queue (stack) with Node(problem.initial) as an element
-///     result <- failure
-///     while not Is_Empty(frontier) do
-///         node <- Pop(frontier)
-///         if problem.Is_Goal(node.state) then return node
-///         if depth(node) > l then
-///             result <- cutoff
-///         else if not Is_Cycle(node) do
-///             for each child in expand(problem, node) do
-///                 add child to frontier
-///     return result
+//!
+//! function Iterative_Deepening_Search(problem) returns a solution node or failure
+//!     for depth = 0 to infty do
+//!         result <- Depth_Limited_Search(problem, depth)
+//!         if result != cutoff then return result
+//!
+//! function Depth_Limited_Search(problem, l) returns a node or failure or cutoff
+//!     frontier <- a LIFO queue (stack) with Node(problem.initial) as an element
+//!     result <- failure
+//!     while not Is_Empty(frontier) do
+//!         node <- Pop(frontier)
+//!         if problem.Is_Goal(node.state) then return node
+//!         if depth(node) > l then
+//!             result <- cutoff
+//!         else if not Is_Cycle(node) do
+//!             for each child in expand(problem, node) do
+//!                 add child to frontier
+//!     return result
 
 use crate::algorithms::{
+    cost::Cost,
     problem::*,
     node::{Node, depth, is_cycle},
+    tracer::{Tracer, NoTracer},
 };
 
 const MAX_LIMIT: usize = 1_000;
 
-pub fn iterative_deepening_search<P, S, A>(problem: &P) -> SearchResult<S, A> 
+pub fn iterative_deepening_search<P, S, A, C>(problem: &P) -> SearchResult<S, A, C> 
 where
-    P: Problem<S, A>,
+    P: Problem<S, A, C>,
     S: Clone + Eq,
-    A: Clone + Eq
+    A: Clone + Eq,
+    C: Cost + Ord
+{
+    iterative_deepening_search_traced(problem, &mut NoTracer)
+}
+
+pub fn iterative_deepening_search_traced<P, S, A, C, T>(problem: &P, tracer: &mut T) -> SearchResult<S, A, C> 
+where
+    P: Problem<S, A, C>,
+    S: Clone + Eq,
+    A: Clone + Eq,
+    C: Cost + Ord,
+    T: Tracer<S, A, C>
+{
+    iterative_deepening_search_limited(problem, MAX_LIMIT, tracer)
+}
+
+/// Iterative deepening which gives up on solutions of more than `max_depth` actions
+pub fn iterative_deepening_search_limited<P, S, A, C, T>(problem: &P, max_depth: usize, tracer: &mut T) -> SearchResult<S, A, C> 
+where
+    P: Problem<S, A, C>,
+    S: Clone + Eq,
+    A: Clone + Eq,
+    C: Cost + Ord,
+    T: Tracer<S, A, C>
 {
     
-    for depth in 0..MAX_LIMIT {
-        match depth_limited_search(problem, depth) {
+    for depth in 0..max_depth {
+        match depth_limited_search(problem, depth, tracer) {
             Ok(result) => {
+                tracer.solution(&result);
                 return Ok(result);
             },
-            _ => {}
+            /* Without a cutoff, the whole space was searched */
+            Err(SearchError::CutOffReached) => {},
+            Err(error) => return Err(error),
         }
-         
     }
     
     Err(SearchError::Failure)
 }
 
 
-fn depth_limited_search<P, S, A>(problem: &P, l: usize) -> SearchResult<S, A>
+fn depth_limited_search<P, S, A, C, T>(problem: &P, l: usize, tracer: &mut T) -> SearchResult<S, A, C>
 where
-    P: Problem<S, A>,
+    P: Problem<S, A, C>,
     S: Clone + Eq,
-    A: Clone + Eq
+    A: Clone + Eq,
+    C: Cost + Ord,
+    T: Tracer<S, A, C>
 {
-    let mut frontier: Vec<Node<S, A>> = vec![problem.get_initial_node()];
-    let mut result: SearchResult<S, A> = Err(SearchError::Failure);    
+    let root = problem.get_initial_node();
+    tracer.generated(&root);
+    let mut frontier: Vec<Node<S, A, C>> = vec![root];
+    let mut result: SearchResult<S, A, C> = Err(SearchError::Failure);    
     
-    while !frontier.is_empty() {
-        let node = frontier.pop().unwrap();
+    while let Some(node) = frontier.pop() {
         if problem.is_goal(&node.state) {
             return Ok(node);
         }
-        if depth(node.clone()) == l {
+        if depth(node.clone()) > l {
+            tracer.pruned(&node);
             result = Err(SearchError::CutOffReached);
         }
         else if !is_cycle(node.clone()) {
-            for child in expand(problem, node.clone()) {
+            tracer.expanded(&node);
+            for child in expand(problem, node.clone())? {
+                tracer.generated(&child);
                 frontier.push(child);
             }
+        } else {
+            tracer.pruned(&node);
         }
     }
 
-    return result;
+    result
 }
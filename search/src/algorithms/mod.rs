@@ -1,7 +1,11 @@
-pub mod problem;
-pub mod node;
+pub use search_core::{cost, node, problem};
+pub mod problems;
+pub mod tracer;
+pub mod event_log;
+pub mod astar_search;
 pub mod best_first_search;
 pub mod bibf_search;
 pub mod breadth_first_search;
+pub mod ida_star_search;
 pub mod iterative_deepening_search;
 pub mod rbfs;
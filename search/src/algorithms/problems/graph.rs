@@ -0,0 +1,144 @@
+//!
+//! Route finding on a weighted graph read from a text file. Each line is one of
+//!     edge <u> <v> <cost>     an undirected edge
+//!     arc <u> <v> <cost>      a directed edge from u to v
+//!     h <u> <value>           heuristic value of u (0 for nodes without one)
+//!     start <u>               default start node
+//!     goal <u> [<v> ...]      default goal nodes: reaching any of them solves the problem
+//! Empty lines and lines starting with '#' are ignored. Costs are parsed as the cost type of
+//! the problem, so `GraphProblem<OrderedFloat<f64>>` reads fractional distances.
+//!
+//! Note that bidirectional search expands the goal with the forward actions, so it is only
+//! correct for graphs where every arc has a matching arc back.
+//!
+
+/* Std library */
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+
+/* Internal crates */
+use crate::algorithms::{
+    cost::Cost,
+    node::Node,
+    problem::Problem,
+};
+
+pub type GraphState = String;
+
+/// Moving along the arc to the given node
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GraphAction(pub GraphState);
+
+impl Debug for GraphAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "To {}", self.0)
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct GraphProblem<C = u32> {
+    /* Sorted, so that the order of the actions does not depend on hashing */
+    edges: BTreeMap<GraphState, BTreeMap<GraphState, C>>,
+    heuristic: HashMap<GraphState, C>,
+    start: GraphState,
+    goals: Vec<GraphState>
+}
+
+impl<C> GraphProblem<C>
+where
+    C: Cost + Default
+{
+    /// Parses a graph file. `start` and `goal` override the ones given in the file; `goal` may
+    /// list several nodes, separated by commas.
+    pub fn parse(text: &str, start: Option<&str>, goal: Option<&str>) -> Result<Self, String> {
+        let mut problem = Self::default();
+        let (mut file_start, mut file_goals) = (None, None);
+
+        for (n, line) in text.lines().enumerate() {
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let error = || format!("Line {}: can not parse '{}'", n + 1, line.trim());
+            let cost = |w: &str| C::from_str_radix(w, 10).map_err(|_| error());
+            match words.as_slice() {
+                [] => {},
+                [w, ..] if w.starts_with('#') => {},
+                ["edge", u, v, c] => {
+                    problem.add_arc(u, v, cost(c)?);
+                    problem.add_arc(v, u, cost(c)?);
+                },
+                ["arc", u, v, c] => problem.add_arc(u, v, cost(c)?),
+                ["h", u, h] => {
+                    problem.heuristic.insert(u.to_string(), cost(h)?);
+                },
+                ["start", u] => file_start = Some(u.to_string()),
+                ["goal", goals @ ..] if !goals.is_empty() => {
+                    file_goals = Some(goals.iter().map(|g| g.to_string()).collect());
+                },
+                _ => return Err(error()),
+            }
+        }
+
+        problem.start = start.map(String::from).or(file_start).ok_or("No start node given")?;
+        problem.goals = goal.map(|g| g.split(',').map(|g| g.trim().to_string()).collect())
+            .or(file_goals)
+            .ok_or("No goal node given")?;
+        for node in std::iter::once(&problem.start).chain(&problem.goals) {
+            if !problem.edges.contains_key(node) {
+                return Err(format!("The node '{}' is not in the graph", node));
+            }
+        }
+
+        Ok(problem)
+    }
+
+    /// Adds the arc u -> v, keeping the cheapest cost if it already exists
+    pub fn add_arc(&mut self, u: &str, v: &str, cost: C) {
+        let arcs = self.edges.entry(u.to_string()).or_default();
+        let c = arcs.entry(v.to_string()).or_insert(cost);
+        if cost < *c {
+            *c = cost;
+        }
+        self.edges.entry(v.to_string()).or_default();
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &GraphState> {
+        self.edges.keys()
+    }
+}
+
+impl<C> Problem<GraphState, GraphAction, C> for GraphProblem<C>
+where
+    C: Cost
+{
+    fn is_goal(&self, state: &GraphState) -> bool {
+        self.goals.contains(state)
+    }
+
+    fn actions(&self, state: &GraphState) -> Vec<GraphAction> {
+        self.edges.get(state).map(|arcs| arcs.keys().cloned().map(GraphAction).collect()).unwrap_or_default()
+    }
+
+    fn result(&self, _state: &GraphState, action: &GraphAction) -> GraphState {
+        action.0.clone()
+    }
+
+    fn action_cost(&self, state: &GraphState, action: &GraphAction, _new_state: &GraphState) -> C {
+        self.edges[state][&action.0]
+    }
+
+    fn get_initial_node(&self) -> Node<GraphState, GraphAction, C> {
+        Node::new(self.start.clone(), None, None, C::zero(), self.get_heuristic_cost(&self.start))
+    }
+
+    /// The first goal node
+    fn get_goal_node(&self) -> Node<GraphState, GraphAction, C> {
+        Node::new(self.goals[0].clone(), None, None, C::zero(), C::zero())
+    }
+
+    fn get_goal_nodes(&self) -> Vec<Node<GraphState, GraphAction, C>> {
+        self.goals.iter().map(|g| Node::new(g.clone(), None, None, C::zero(), C::zero())).collect()
+    }
+
+    fn get_heuristic_cost(&self, state: &GraphState) -> C {
+        self.heuristic.get(state).copied().unwrap_or_else(C::zero)
+    }
+}
@@ -0,0 +1,152 @@
+//!
+//! Path finding on a 4-connected grid map where every move costs 1.
+//!
+//! Maps are plain text, one row per line:
+//!     '.' or ' '              free cell
+//!     '#', '@', 'T', 'O', 'W' blocked cell
+//!     'S' and 'G'             start and goal (free cells)
+//! A Moving AI benchmark header ("type", "height", "width", "map") is skipped if present.
+//!
+
+/* Std library */
+use std::fmt::Display;
+
+/* Internal crates */
+use crate::algorithms::{
+    node::Node,
+    problem::Problem,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Cell {
+    pub x: usize,
+    pub y: usize
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum GridAction {
+    Up,
+    Down,
+    Left,
+    Right
+}
+
+#[derive(Clone)]
+pub struct GridMapProblem {
+    width: usize,
+    height: usize,
+    blocked: Vec<bool>,
+    start: Cell,
+    goal: Cell
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl std::str::FromStr for Cell {
+    type Err = String;
+
+    /// Parses "x,y"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s.split_once(',').ok_or_else(|| format!("Expected a cell as 'x,y', got '{}'", s))?;
+        let parse = |v: &str| v.trim().parse::<usize>().map_err(|_| format!("Invalid coordinate '{}'", v));
+        Ok(Cell { x: parse(x)?, y: parse(y)? })
+    }
+}
+
+impl GridMapProblem {
+    /// Parses a map. `start` and `goal` override the 'S' and 'G' cells of the map.
+    pub fn parse(map: &str, start: Option<Cell>, goal: Option<Cell>) -> Result<Self, String> {
+        let mut lines: Vec<&str> = map.lines().collect();
+        if lines.first().is_some_and(|l| l.starts_with("type")) {
+            let header = lines.iter().position(|l| l.trim() == "map").ok_or("Moving AI map without a 'map' line")?;
+            lines.drain(..=header);
+        }
+        while lines.last().is_some_and(|l| l.trim().is_empty()) {
+            lines.pop();
+        }
+
+        /* Lines shorter than the widest one are padded with free cells */
+        let height = lines.len();
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        if width == 0 || height == 0 {
+            return Err(String::from("The map is empty"));
+        }
+
+        let mut blocked = vec![false; width * height];
+        let (mut map_start, mut map_goal) = (None, None);
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '.' | ' ' => {},
+                    '#' | '@' | 'T' | 'O' | 'W' => blocked[y * width + x] = true,
+                    'S' => map_start = Some(Cell { x, y }),
+                    'G' => map_goal = Some(Cell { x, y }),
+                    _ => return Err(format!("Unknown map character '{}' at ({}, {})", c, x, y)),
+                }
+            }
+        }
+
+        let start = start.or(map_start).ok_or("No start given, and the map has no 'S' cell")?;
+        let goal = goal.or(map_goal).ok_or("No goal given, and the map has no 'G' cell")?;
+        let problem = Self { width, height, blocked, start, goal };
+        for cell in [start, goal] {
+            if !problem.is_free(cell) {
+                return Err(format!("The cell {} is outside the map or blocked", cell));
+            }
+        }
+
+        Ok(problem)
+    }
+
+    pub fn is_free(&self, cell: Cell) -> bool {
+        cell.x < self.width && cell.y < self.height && !self.blocked[cell.y * self.width + cell.x]
+    }
+
+    fn neighbor(&self, cell: &Cell, action: &GridAction) -> Option<Cell> {
+        let neighbor = match action {
+            GridAction::Up => Cell { x: cell.x, y: cell.y.checked_sub(1)? },
+            GridAction::Down => Cell { x: cell.x, y: cell.y + 1 },
+            GridAction::Left => Cell { x: cell.x.checked_sub(1)?, y: cell.y },
+            GridAction::Right => Cell { x: cell.x + 1, y: cell.y },
+        };
+        if self.is_free(neighbor) { Some(neighbor) } else { None }
+    }
+}
+
+impl Problem<Cell, GridAction> for GridMapProblem {
+    fn is_goal(&self, state: &Cell) -> bool {
+        state == &self.goal
+    }
+
+    fn actions(&self, state: &Cell) -> Vec<GridAction> {
+        [GridAction::Up, GridAction::Down, GridAction::Left, GridAction::Right]
+            .into_iter()
+            .filter(|a| self.neighbor(state, a).is_some())
+            .collect()
+    }
+
+    fn result(&self, state: &Cell, action: &GridAction) -> Cell {
+        self.neighbor(state, action).unwrap()
+    }
+
+    fn action_cost(&self, _state: &Cell, _action: &GridAction, _new_state: &Cell) -> u32 {
+        1
+    }
+
+    fn get_initial_node(&self) -> Node<Cell, GridAction> {
+        Node::new(self.start, None, None, 0, self.get_heuristic_cost(&self.start))
+    }
+
+    fn get_goal_node(&self) -> Node<Cell, GridAction> {
+        Node::new(self.goal, None, None, 0, 0)
+    }
+
+    /// Manhattan distance to the goal
+    fn get_heuristic_cost(&self, state: &Cell) -> u32 {
+        (state.x.abs_diff(self.goal.x) + state.y.abs_diff(self.goal.y)) as u32
+    }
+}
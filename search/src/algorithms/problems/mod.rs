@@ -0,0 +1,6 @@
+pub use search_core::romania;
+pub mod graph;
+pub mod grid_map;
+pub mod npuzzle;
+pub mod planning;
+pub mod tsp;
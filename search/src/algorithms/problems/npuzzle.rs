@@ -0,0 +1,207 @@
+//!
+//! The n-puzzle: numbered tiles on a k x k board with one blank square. An action slides the
+//! blank up, down, left or right, swapping it with the neighbouring tile. Every action costs 1.
+//!
+//! Boards are written row by row, e.g. "1 2 3 4 5 6 7 8 0" or "1,2,3,4,5,6,7,8,0", with 0 as
+//! the blank. The default goal is the one used in AIMA, with the blank in the top left corner.
+//!
+
+/* Std library */
+use std::fmt::Display;
+use std::str::FromStr;
+
+/* Internal crates */
+use crate::algorithms::{
+    node::Node,
+    problem::Problem,
+};
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Board {
+    size: usize,
+    tiles: Vec<u8>
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum NPuzzleAction {
+    Up,
+    Down,
+    Left,
+    Right
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NPuzzleHeuristic {
+    /// Number of tiles not in their goal position (h1 in AIMA)
+    Misplaced,
+    /// Sum of the Manhattan distances of the tiles to their goal positions (h2 in AIMA)
+    Manhattan
+}
+
+#[derive(Clone)]
+pub struct NPuzzleProblem {
+    initial: Board,
+    goal: Board,
+    heuristic: NPuzzleHeuristic,
+    /* goal_positions[t] is the index of tile t in the goal board */
+    goal_positions: Vec<usize>
+}
+
+impl Board {
+    /// The AIMA goal board: 0 (the blank), 1, 2, ..., k*k - 1
+    pub fn goal(size: usize) -> Self {
+        Self { size, tiles: (0..(size * size) as u8).collect() }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn tiles(&self) -> &[u8] {
+        &self.tiles
+    }
+
+    fn blank(&self) -> usize {
+        self.tiles.iter().position(|t| *t == 0).unwrap()
+    }
+
+    fn inversions(&self) -> usize {
+        let tiles: Vec<u8> = self.tiles.iter().copied().filter(|t| *t != 0).collect();
+        let mut inversions = 0;
+        for i in 0..tiles.len() {
+            for j in i + 1..tiles.len() {
+                if tiles[i] > tiles[j] {
+                    inversions += 1;
+                }
+            }
+        }
+        inversions
+    }
+
+    /// Whether `goal` can be reached from this board. Half of all boards can not, and searching
+    /// for them would exhaust the whole reachable half of the state space.
+    pub fn can_reach(&self, goal: &Board) -> bool {
+        if self.size != goal.size {
+            return false;
+        }
+        if self.size % 2 == 1 {
+            self.inversions() % 2 == goal.inversions() % 2
+        } else {
+            /* On even boards moving the blank vertically also flips the inversion parity */
+            let row = |b: &Board| b.blank() / b.size;
+            (self.inversions() + row(self)) % 2 == (goal.inversions() + row(goal)) % 2
+        }
+    }
+}
+
+impl FromStr for Board {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tiles = s.split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|t| !t.is_empty())
+            .map(|t| t.parse::<u8>().map_err(|_| format!("Invalid tile '{}'", t)))
+            .collect::<Result<Vec<u8>, String>>()?;
+
+        let size = (tiles.len() as f64).sqrt() as usize;
+        if size < 2 || size * size != tiles.len() {
+            return Err(format!("A board needs k*k tiles with k >= 2, got {}", tiles.len()));
+        }
+
+        let mut sorted = tiles.clone();
+        sorted.sort();
+        if sorted != Board::goal(size).tiles {
+            return Err(format!("A {}x{} board must contain each of the tiles 0..{} once", size, size, size * size - 1));
+        }
+
+        Ok(Self { size, tiles })
+    }
+}
+
+impl Display for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows: Vec<String> = self.tiles.chunks(self.size)
+            .map(|row| row.iter().map(|t| if *t == 0 { String::from("_") } else { t.to_string() }).collect::<Vec<_>>().join(" "))
+            .collect();
+        write!(f, "{}", rows.join(" / "))
+    }
+}
+
+impl NPuzzleProblem {
+    pub fn new(initial: Board, goal: Board, heuristic: NPuzzleHeuristic) -> Result<Self, String> {
+        if !initial.can_reach(&goal) {
+            return Err(format!("The goal {} can not be reached from {}", goal, initial));
+        }
+
+        let mut goal_positions = vec![0; goal.tiles.len()];
+        for (i, t) in goal.tiles.iter().enumerate() {
+            goal_positions[*t as usize] = i;
+        }
+
+        Ok(Self { initial, goal, heuristic, goal_positions })
+    }
+}
+
+impl Problem<Board, NPuzzleAction> for NPuzzleProblem {
+    fn is_goal(&self, state: &Board) -> bool {
+        state == &self.goal
+    }
+
+    fn actions(&self, state: &Board) -> Vec<NPuzzleAction> {
+        let (row, col) = (state.blank() / state.size, state.blank() % state.size);
+        let mut actions = Vec::new();
+        if row > 0 {
+            actions.push(NPuzzleAction::Up);
+        }
+        if row + 1 < state.size {
+            actions.push(NPuzzleAction::Down);
+        }
+        if col > 0 {
+            actions.push(NPuzzleAction::Left);
+        }
+        if col + 1 < state.size {
+            actions.push(NPuzzleAction::Right);
+        }
+        actions
+    }
+
+    fn result(&self, state: &Board, action: &NPuzzleAction) -> Board {
+        let blank = state.blank();
+        let target = match action {
+            NPuzzleAction::Up => blank - state.size,
+            NPuzzleAction::Down => blank + state.size,
+            NPuzzleAction::Left => blank - 1,
+            NPuzzleAction::Right => blank + 1,
+        };
+        let mut board = state.clone();
+        board.tiles.swap(blank, target);
+        board
+    }
+
+    fn action_cost(&self, _state: &Board, _action: &NPuzzleAction, _new_state: &Board) -> u32 {
+        1
+    }
+
+    fn get_initial_node(&self) -> Node<Board, NPuzzleAction> {
+        let h = self.get_heuristic_cost(&self.initial);
+        Node::new(self.initial.clone(), None, None, 0, h)
+    }
+
+    fn get_goal_node(&self) -> Node<Board, NPuzzleAction> {
+        Node::new(self.goal.clone(), None, None, 0, 0)
+    }
+
+    fn get_heuristic_cost(&self, state: &Board) -> u32 {
+        let size = state.size;
+        state.tiles.iter().enumerate()
+            .filter(|(_, t)| **t != 0)
+            .map(|(i, t)| {
+                let goal = self.goal_positions[*t as usize];
+                match self.heuristic {
+                    NPuzzleHeuristic::Misplaced => (i != goal) as u32,
+                    NPuzzleHeuristic::Manhattan => ((i / size).abs_diff(goal / size) + (i % size).abs_diff(goal % size)) as u32,
+                }
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,163 @@
+//!
+//! Domain-independent heuristics (AIMA 11.3.2), all based on the relaxed problem that ignores
+//! delete lists. In the relaxed problem a fact, once true, stays true, so
+//!     cost(f) = 0                                          if f is in the state
+//!     cost(f) = min over actions a adding f of 1 + cost(Pre(a))
+//! where the cost of a set of facts is either the sum (h_add) or the maximum (h_max) of the
+//! costs of its facts. h_max is admissible, h_add is not, as it counts shared subgoals twice.
+//!
+//! h_FF follows the cheapest supporter of each goal back to the state, and counts the distinct
+//! actions on the way: a relaxed plan, so h_max <= h+ <= h_FF. h+, the length of an optimal
+//! relaxed plan, is found with A* using h_max.
+//!
+//! Goals that can not be reached even in the relaxed problem get u32::MAX.
+//!
+
+/* Std library */
+use std::collections::HashSet;
+
+/* Internal crates */
+use crate::algorithms::{
+    astar_search::astar_search,
+    node::Node,
+    problem::Problem,
+};
+use super::{FactSet, PlanningAction, PlanningHeuristic, PlanningProblem};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Combine {
+    Add,
+    Max,
+}
+
+/* Relaxed costs of every fact, and the action that achieves each fact most cheaply */
+struct RelaxedCosts {
+    cost: Vec<u32>,
+    supporter: Vec<Option<usize>>,
+}
+
+/// The relaxed problem from a given state: an action adds facts without deleting any
+struct RelaxedProblem<'a> {
+    problem: &'a PlanningProblem,
+    initial: FactSet,
+}
+
+impl PlanningProblem {
+    /// The value of a heuristic in a state, whatever the heuristic of the problem is
+    pub fn heuristic_value(&self, heuristic: PlanningHeuristic, state: &FactSet) -> u32 {
+        match heuristic {
+            PlanningHeuristic::Zero => 0,
+            PlanningHeuristic::Max => self.goal_cost(state, Combine::Max),
+            PlanningHeuristic::Add => self.goal_cost(state, Combine::Add),
+            PlanningHeuristic::FF => self.relaxed_plan_length(state),
+            PlanningHeuristic::IgnoreDeleteLists => self.optimal_relaxed_plan_length(state),
+        }
+    }
+
+    fn relaxed_costs(&self, state: &FactSet, combine: Combine) -> RelaxedCosts {
+        let mut cost = vec![u32::MAX; self.facts.len()];
+        let mut supporter = vec![None; self.facts.len()];
+        for f in &state.facts {
+            cost[*f] = 0;
+        }
+
+        /* Bellman-Ford style: relax every action until no cost changes */
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (index, action) in self.actions.iter().enumerate() {
+                let mut pre = 0u32;
+                for f in &action.precondition {
+                    pre = match combine {
+                        Combine::Add => pre.saturating_add(cost[*f]),
+                        Combine::Max => pre.max(cost[*f]),
+                    };
+                }
+                if pre == u32::MAX {
+                    continue;
+                }
+                for f in &action.add {
+                    if pre + 1 < cost[*f] {
+                        cost[*f] = pre + 1;
+                        supporter[*f] = Some(index);
+                        changed = true;
+                    }
+                }
+            }
+        }
+        RelaxedCosts { cost, supporter }
+    }
+
+    fn goal_cost(&self, state: &FactSet, combine: Combine) -> u32 {
+        let costs = self.relaxed_costs(state, combine);
+        let goals = self.goal.iter().map(|g| costs.cost[*g]);
+        match combine {
+            Combine::Add => goals.fold(0, u32::saturating_add),
+            Combine::Max => goals.max().unwrap_or(0),
+        }
+    }
+
+    fn relaxed_plan_length(&self, state: &FactSet) -> u32 {
+        let costs = self.relaxed_costs(state, Combine::Add);
+        if self.goal.iter().any(|g| costs.cost[*g] == u32::MAX) {
+            return u32::MAX;
+        }
+        let mut plan = HashSet::new();
+        let mut open: Vec<usize> = self.goal.clone();
+        let mut seen: HashSet<usize> = HashSet::new();
+        while let Some(f) = open.pop() {
+            if !seen.insert(f) {
+                continue;
+            }
+            if let Some(action) = costs.supporter[f] {
+                if plan.insert(action) {
+                    open.extend(&self.actions[action].precondition);
+                }
+            }
+        }
+        plan.len() as u32
+    }
+
+    fn optimal_relaxed_plan_length(&self, state: &FactSet) -> u32 {
+        if self.heuristic_value(PlanningHeuristic::Max, state) == u32::MAX {
+            return u32::MAX;
+        }
+        let relaxed = RelaxedProblem { problem: self, initial: state.clone() };
+        let h = |relaxed: &RelaxedProblem, node: &Node<FactSet, PlanningAction>| relaxed.get_heuristic_cost(&node.state);
+        astar_search(&relaxed, h).map(|node| node.path_cost).unwrap_or(u32::MAX)
+    }
+}
+
+impl Problem<FactSet, PlanningAction> for RelaxedProblem<'_> {
+    fn is_goal(&self, state: &FactSet) -> bool {
+        self.problem.is_goal(state)
+    }
+
+    /* Actions that add nothing new are useless in the relaxed problem */
+    fn actions(&self, state: &FactSet) -> Vec<PlanningAction> {
+        self.problem.actions(state).into_iter()
+            .filter(|a| !state.contains_all(&self.problem.actions[a.index].add))
+            .collect()
+    }
+
+    fn result(&self, state: &FactSet, action: &PlanningAction) -> FactSet {
+        let facts = state.facts.iter().chain(&self.problem.actions[action.index].add).copied().collect();
+        FactSet::new(facts, &state.names)
+    }
+
+    fn action_cost(&self, _state: &FactSet, _action: &PlanningAction, _new_state: &FactSet) -> u32 {
+        1
+    }
+
+    fn get_initial_node(&self) -> Node<FactSet, PlanningAction> {
+        Node::new(self.initial.clone(), None, None, 0, 0)
+    }
+
+    fn get_goal_node(&self) -> Node<FactSet, PlanningAction> {
+        self.problem.get_goal_node()
+    }
+
+    fn get_heuristic_cost(&self, state: &FactSet) -> u32 {
+        self.problem.heuristic_value(PlanningHeuristic::Max, state)
+    }
+}
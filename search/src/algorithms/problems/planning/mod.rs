@@ -0,0 +1,389 @@
+//!
+//! Classical planning (AIMA chapter 11) as a search problem. A planning task is read from a
+//! PDDL domain and problem (STRIPS with typing, see `pddl`), and grounded: every action schema
+//! is instantiated with every combination of objects of the right types.
+//!
+//! States are sets of facts (ground atoms that are true). An action is applicable when its
+//! preconditions are in the state, and its result is
+//!     Result(s, a) = (s - Del(a)) ∪ Add(a)
+//! Every action costs 1.
+//!
+//! Grounding leaves out
+//!     - static facts, which no action adds or deletes: they are checked once, while grounding
+//!     - actions whose preconditions can not all become true, even ignoring delete lists
+//!
+//! The goal is only a partial state, so `get_goal_node` returns a node with just the goal
+//! facts and bidirectional search does not apply.
+//!
+
+mod heuristics;
+pub mod pddl;
+
+/* Std library */
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Display};
+use std::sync::Arc;
+
+/* Internal crates */
+use crate::algorithms::{
+    node::Node,
+    problem::Problem,
+};
+use pddl::{ActionSchema, Atom, Domain, PddlProblem};
+
+/// A set of facts, identified by their index in the task
+#[derive(Clone)]
+pub struct FactSet {
+    /* Sorted */
+    facts: Vec<usize>,
+    names: Arc<Vec<String>>,
+}
+
+/// A ground action, shown like "(stack a b)"
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PlanningAction {
+    index: usize,
+    name: Arc<str>,
+}
+
+#[derive(Clone, Debug)]
+struct GroundAction {
+    name: Arc<str>,
+    precondition: Vec<usize>,
+    add: Vec<usize>,
+    delete: Vec<usize>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PlanningHeuristic {
+    /// h = 0
+    Zero,
+    /// Cost of the most expensive goal, in the relaxed problem without delete lists. Admissible.
+    Max,
+    /// Sum of the costs of the goals in the relaxed problem. Not admissible, but informative.
+    Add,
+    /// Length of a relaxed plan, found backwards from the goals along the h_add estimates
+    FF,
+    /// Length of an optimal relaxed plan (h+), found with A*. Admissible, but costly: solving
+    /// the relaxed problem is NP-hard.
+    IgnoreDeleteLists,
+}
+
+#[derive(Clone)]
+pub struct PlanningProblem {
+    name: String,
+    facts: Arc<Vec<String>>,
+    actions: Vec<GroundAction>,
+    initial: FactSet,
+    goal: Vec<usize>,
+    heuristic: PlanningHeuristic,
+}
+
+impl FactSet {
+    fn new(mut facts: Vec<usize>, names: &Arc<Vec<String>>) -> Self {
+        facts.sort_unstable();
+        facts.dedup();
+        Self { facts, names: names.clone() }
+    }
+
+    fn contains_all(&self, facts: &[usize]) -> bool {
+        facts.iter().all(|f| self.facts.binary_search(f).is_ok())
+    }
+
+    /// True if the fact, written like "(on a b)", is in the set
+    pub fn contains(&self, fact: &str) -> bool {
+        self.facts().any(|f| f == fact)
+    }
+
+    pub fn facts(&self) -> impl Iterator<Item = &str> {
+        self.facts.iter().map(|f| self.names[*f].as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.facts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.facts.is_empty()
+    }
+}
+
+/* Fact sets are equal when they hold the same facts, the names are only used for printing */
+impl PartialEq for FactSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.facts == other.facts
+    }
+}
+
+impl Eq for FactSet {}
+
+impl PartialOrd for FactSet {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FactSet {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.facts.cmp(&other.facts)
+    }
+}
+
+impl std::hash::Hash for FactSet {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.facts.hash(state);
+    }
+}
+
+impl Display for FactSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.facts().collect::<Vec<_>>().join(" "))
+    }
+}
+
+impl Debug for FactSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{{}}}", self)
+    }
+}
+
+impl PlanningAction {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Debug for PlanningAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/* Interns facts while grounding */
+struct FactTable {
+    names: Vec<String>,
+    index: HashMap<String, usize>,
+}
+
+impl FactTable {
+    fn intern(&mut self, fact: String) -> usize {
+        if let Some(i) = self.index.get(&fact) {
+            return *i;
+        }
+        self.names.push(fact.clone());
+        self.index.insert(fact, self.names.len() - 1);
+        self.names.len() - 1
+    }
+}
+
+
+/* Writes an atom of a schema with its variables replaced by objects, like "(on a b)" */
+fn ground_atom(atom: &Atom, binding: &HashMap<&str, &str>) -> String {
+    let args = atom.args.iter().map(|a| binding.get(a.as_str()).copied().unwrap_or(a));
+    format!("({})", std::iter::once(atom.predicate.as_str()).chain(args).collect::<Vec<_>>().join(" "))
+}
+
+struct Grounder<'a> {
+    domain: &'a Domain,
+    /* Objects of the problem and constants of the domain, with their types */
+    objects: Vec<(&'a str, &'a str)>,
+    /* Predicates that no action adds or deletes, and their atoms in the initial state */
+    statics: HashSet<&'a str>,
+    static_init: HashSet<String>,
+    facts: FactTable,
+    actions: Vec<GroundAction>,
+}
+
+impl<'a> Grounder<'a> {
+    fn new(domain: &'a Domain, problem: &'a PddlProblem) -> Self {
+        let objects = problem.objects.iter().chain(&domain.constants)
+            .map(|(o, t)| (o.as_str(), t.as_str()))
+            .collect();
+        let statics: HashSet<&str> = domain.predicates.keys()
+            .map(String::as_str)
+            .filter(|p| domain.actions.iter().all(|a| a.add.iter().chain(&a.delete).all(|e| e.predicate != *p)))
+            .collect();
+        let static_init = problem.init.iter()
+            .filter(|a| statics.contains(a.predicate.as_str()))
+            .map(|a| ground_atom(a, &HashMap::new()))
+            .collect();
+        let facts = FactTable { names: Vec::new(), index: HashMap::new() };
+        Self { domain, objects, statics, static_init, facts, actions: Vec::new() }
+    }
+
+    /// False if a static precondition or a distinctness constraint whose arguments are all bound fails
+    fn consistent(&self, schema: &ActionSchema, binding: &HashMap<&str, &str>) -> bool {
+        let is_bound = |a: &String| !a.starts_with('?') || binding.contains_key(a.as_str());
+        let statics_hold = schema.precondition.iter()
+            .filter(|p| self.statics.contains(p.predicate.as_str()) && p.args.iter().all(is_bound))
+            .all(|p| self.static_init.contains(&ground_atom(p, binding)));
+        let distinct = schema.distinct.iter()
+            .filter(|(x, y)| is_bound(x) && is_bound(y))
+            .all(|(x, y)| binding.get(x.as_str()).unwrap_or(&x.as_str()) != binding.get(y.as_str()).unwrap_or(&y.as_str()));
+        statics_hold && distinct
+    }
+
+    fn ground_atoms(&mut self, atoms: &[Atom], binding: &HashMap<&str, &str>) -> Vec<usize> {
+        let mut facts: Vec<usize> = atoms.iter()
+            .filter(|a| !self.statics.contains(a.predicate.as_str()))
+            .map(|a| self.facts.intern(ground_atom(a, binding)))
+            .collect();
+        facts.sort_unstable();
+        facts.dedup();
+        facts
+    }
+
+    /* Binds the parameters one at a time, pruning as soon as a static precondition fails */
+    fn ground(&mut self, schema: &'a ActionSchema, binding: &mut HashMap<&'a str, &'a str>) {
+        if !self.consistent(schema, binding) {
+            return;
+        }
+        match schema.parameters.get(binding.len()) {
+            None => {
+                let precondition = self.ground_atoms(&schema.precondition, binding);
+                let add = self.ground_atoms(&schema.add, binding);
+                let delete = self.ground_atoms(&schema.delete, binding);
+                let args = schema.parameters.iter().map(|(p, _)| binding[p.as_str()]);
+                let name = format!("({})", std::iter::once(schema.name.as_str()).chain(args).collect::<Vec<_>>().join(" "));
+                self.actions.push(GroundAction { name: name.into(), precondition, add, delete });
+            },
+            Some((variable, t)) => {
+                let candidates: Vec<&str> = self.objects.iter()
+                    .filter(|(_, object_type)| self.domain.is_subtype(object_type, t))
+                    .map(|(o, _)| *o)
+                    .collect();
+                for object in candidates {
+                    binding.insert(variable.as_str(), object);
+                    self.ground(schema, binding);
+                    binding.remove(variable.as_str());
+                }
+            },
+        }
+    }
+}
+
+impl PlanningProblem {
+    /// Parses a PDDL domain and problem, and grounds the actions
+    pub fn parse(domain: &str, problem: &str) -> Result<Self, String> {
+        let domain = Domain::parse(domain).map_err(|e| format!("Domain: {}", e))?;
+        let problem = PddlProblem::parse(problem, &domain).map_err(|e| format!("Problem: {}", e))?;
+        Ok(Self::ground(&domain, &problem))
+    }
+
+    pub fn ground(domain: &Domain, problem: &PddlProblem) -> Self {
+        let mut grounder = Grounder::new(domain, problem);
+        for schema in &domain.actions {
+            grounder.ground(schema, &mut HashMap::new());
+        }
+        let init = grounder.ground_atoms(&problem.init, &HashMap::new());
+        /* A static goal either holds from the start or never: only the ones that hold are dropped */
+        let goal = problem.goal.iter()
+            .filter(|g| !grounder.static_init.contains(&ground_atom(g, &HashMap::new())))
+            .map(|g| grounder.facts.intern(ground_atom(g, &HashMap::new())))
+            .collect::<Vec<_>>();
+
+        /* Keeps the actions that are reachable when delete lists are ignored */
+        let mut reachable: HashSet<usize> = init.iter().copied().collect();
+        let mut actions = Vec::new();
+        let mut remaining = grounder.actions;
+        loop {
+            let (applicable, rest): (Vec<_>, Vec<_>) = remaining.into_iter()
+                .partition(|a: &GroundAction| a.precondition.iter().all(|f| reachable.contains(f)));
+            if applicable.is_empty() {
+                break;
+            }
+            for action in applicable {
+                reachable.extend(&action.add);
+                actions.push(action);
+            }
+            remaining = rest;
+        }
+        actions.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let facts = Arc::new(grounder.facts.names);
+        let mut goal = goal;
+        goal.sort_unstable();
+        goal.dedup();
+        Self {
+            name: problem.name.clone(),
+            initial: FactSet::new(init, &facts),
+            facts,
+            actions,
+            goal,
+            heuristic: PlanningHeuristic::Max,
+        }
+    }
+
+    pub fn with_heuristic(mut self, heuristic: PlanningHeuristic) -> Self {
+        self.heuristic = heuristic;
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Number of ground actions left after pruning
+    pub fn action_count(&self) -> usize {
+        self.actions.len()
+    }
+
+    /// The goal facts, like "(on a b)"
+    pub fn goal(&self) -> impl Iterator<Item = &str> {
+        self.goal.iter().map(|f| self.facts[*f].as_str())
+    }
+
+    /// The initial state after applying the actions of `plan` in order, or an error naming the
+    /// first action that is not applicable
+    pub fn execute(&self, plan: &[&str]) -> Result<FactSet, String> {
+        let mut state = self.initial.clone();
+        for step in plan {
+            let action = self.actions(&state).into_iter()
+                .find(|a| a.name() == *step)
+                .ok_or_else(|| format!("{} is not applicable in {{{}}}", step, state))?;
+            state = self.result(&state, &action);
+        }
+        Ok(state)
+    }
+}
+
+impl Problem<FactSet, PlanningAction> for PlanningProblem {
+    fn is_goal(&self, state: &FactSet) -> bool {
+        state.contains_all(&self.goal)
+    }
+
+    fn actions(&self, state: &FactSet) -> Vec<PlanningAction> {
+        self.actions.iter()
+            .enumerate()
+            .filter(|(_, a)| state.contains_all(&a.precondition))
+            .map(|(index, a)| PlanningAction { index, name: a.name.clone() })
+            .collect()
+    }
+
+    fn result(&self, state: &FactSet, action: &PlanningAction) -> FactSet {
+        let action = &self.actions[action.index];
+        let facts = state.facts.iter()
+            .filter(|f| action.delete.binary_search(f).is_err())
+            .chain(&action.add)
+            .copied()
+            .collect();
+        FactSet::new(facts, &self.facts)
+    }
+
+    fn action_cost(&self, _state: &FactSet, _action: &PlanningAction, _new_state: &FactSet) -> u32 {
+        1
+    }
+
+    fn get_initial_node(&self) -> Node<FactSet, PlanningAction> {
+        let h = self.get_heuristic_cost(&self.initial);
+        Node::new(self.initial.clone(), None, None, 0, h)
+    }
+
+    fn get_goal_node(&self) -> Node<FactSet, PlanningAction> {
+        Node::new(FactSet::new(self.goal.clone(), &self.facts), None, None, 0, 0)
+    }
+
+    fn get_heuristic_cost(&self, state: &FactSet) -> u32 {
+        self.heuristic_value(self.heuristic, state)
+    }
+}
@@ -0,0 +1,386 @@
+//!
+//! Parser for the STRIPS subset of PDDL: typed objects, constants, conjunctive preconditions
+//! (with `(not (= ?x ?y))` to require distinct arguments) and add/delete effects.
+//!
+//! PDDL is case-insensitive, so all names are read in lower case. Comments start with ';'.
+//!
+
+/* Std library */
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// The type of objects that are declared without one
+pub const OBJECT: &str = "object";
+
+/// A predicate applied to arguments, which are objects or (in action schemas) ?variables
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Atom {
+    pub predicate: String,
+    pub args: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ActionSchema {
+    pub name: String,
+    /// (variable, type) pairs
+    pub parameters: Vec<(String, String)>,
+    pub precondition: Vec<Atom>,
+    /// Pairs of arguments that must be different objects
+    pub distinct: Vec<(String, String)>,
+    pub add: Vec<Atom>,
+    pub delete: Vec<Atom>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Domain {
+    pub name: String,
+    /// The parent of every declared type
+    pub types: HashMap<String, String>,
+    /// (name, type) pairs
+    pub constants: Vec<(String, String)>,
+    /// Predicate names and their number of arguments
+    pub predicates: HashMap<String, usize>,
+    pub actions: Vec<ActionSchema>,
+}
+
+#[derive(Clone, Debug)]
+pub struct PddlProblem {
+    pub name: String,
+    pub domain: String,
+    /// (name, type) pairs
+    pub objects: Vec<(String, String)>,
+    pub init: Vec<Atom>,
+    pub goal: Vec<Atom>,
+}
+
+impl Display for Atom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}", self.predicate)?;
+        for arg in &self.args {
+            write!(f, " {}", arg)?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[derive(Clone, Debug)]
+enum SExpr {
+    Symbol(String),
+    List(Vec<SExpr>),
+}
+
+impl SExpr {
+    fn symbol(&self) -> Option<&str> {
+        match self {
+            SExpr::Symbol(s) => Some(s),
+            SExpr::List(_) => None,
+        }
+    }
+
+    fn list(&self) -> Result<&[SExpr], String> {
+        match self {
+            SExpr::List(items) => Ok(items),
+            SExpr::Symbol(s) => Err(format!("Expected a list, found '{}'", s)),
+        }
+    }
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    for line in text.lines() {
+        let line = line.split(';').next().unwrap_or("");
+        let spaced = line.replace('(', " ( ").replace(')', " ) ");
+        tokens.extend(spaced.split_whitespace().map(str::to_lowercase));
+    }
+    tokens
+}
+
+fn parse_sexpr(text: &str) -> Result<SExpr, String> {
+    let tokens = tokenize(text);
+    let mut stack: Vec<Vec<SExpr>> = Vec::new();
+    let mut result = None;
+
+    for token in tokens {
+        if result.is_some() {
+            return Err(format!("Unexpected '{}' after the end of the definition", token));
+        }
+        match token.as_str() {
+            "(" => stack.push(Vec::new()),
+            ")" => {
+                let list = SExpr::List(stack.pop().ok_or("Unbalanced ')'")?);
+                match stack.last_mut() {
+                    Some(parent) => parent.push(list),
+                    None => result = Some(list),
+                }
+            },
+            _ => stack.last_mut().ok_or(format!("Unexpected '{}' outside of a list", token))?.push(SExpr::Symbol(token)),
+        }
+    }
+
+    if !stack.is_empty() {
+        return Err(String::from("Unbalanced '(': the definition is not closed"));
+    }
+    result.ok_or_else(|| String::from("Empty definition"))
+}
+
+/// Splits `(define (<kind> <name>) sections...)` into the name and the sections
+fn definition<'a>(expr: &'a SExpr, kind: &str) -> Result<(String, &'a [SExpr]), String> {
+    let items = expr.list()?;
+    match items {
+        [SExpr::Symbol(define), header, sections @ ..] if define == "define" => {
+            match header.list()? {
+                [SExpr::Symbol(k), SExpr::Symbol(name)] if k == kind => Ok((name.clone(), sections)),
+                _ => Err(format!("Expected ({} <name>) after define", kind)),
+            }
+        },
+        _ => Err(String::from("Expected (define ...)")),
+    }
+}
+
+/// Reads `a b - t c - u d`, where names without a type get the type `object`
+fn typed_list(items: &[SExpr]) -> Result<Vec<(String, String)>, String> {
+    let mut typed = Vec::new();
+    let mut pending: Vec<String> = Vec::new();
+    let mut iter = items.iter();
+
+    while let Some(item) = iter.next() {
+        let symbol = item.symbol().ok_or("Expected a name in a typed list")?;
+        if symbol == "-" {
+            let t = iter.next().and_then(SExpr::symbol).ok_or("Expected a type after '-'")?;
+            if pending.is_empty() {
+                return Err(format!("No names before the type '{}'", t));
+            }
+            typed.extend(pending.drain(..).map(|name| (name, t.to_string())));
+        } else {
+            pending.push(symbol.to_string());
+        }
+    }
+    typed.extend(pending.into_iter().map(|name| (name, OBJECT.to_string())));
+    Ok(typed)
+}
+
+fn atom(expr: &SExpr) -> Result<Atom, String> {
+    let items = expr.list()?;
+    let mut symbols = items.iter().map(|i| i.symbol().ok_or("Nested lists are not allowed in an atom"));
+    let predicate = symbols.next().ok_or("Empty atom")??.to_string();
+    let args = symbols.map(|s| s.map(String::from)).collect::<Result<_, _>>()?;
+    Ok(Atom { predicate, args })
+}
+
+/// The conjuncts of `(and a b ...)`, or of a single condition
+fn conjuncts(expr: &SExpr) -> Result<&[SExpr], String> {
+    let items = expr.list()?;
+    match items.first().and_then(SExpr::symbol) {
+        None if items.is_empty() => Ok(items),
+        Some("and") => Ok(&items[1..]),
+        _ => Ok(std::slice::from_ref(expr)),
+    }
+}
+
+/// The atom negated by `(not atom)`, if `expr` is a negation
+fn negated(expr: &SExpr) -> Result<Option<&SExpr>, String> {
+    match expr.list()? {
+        [SExpr::Symbol(not), inner] if not == "not" => Ok(Some(inner)),
+        _ => Ok(None),
+    }
+}
+
+fn action(items: &[SExpr]) -> Result<ActionSchema, String> {
+    let name = items.first().and_then(SExpr::symbol).ok_or("Expected the name of the action")?;
+    let mut schema = ActionSchema {
+        name: name.to_string(),
+        parameters: Vec::new(),
+        precondition: Vec::new(),
+        distinct: Vec::new(),
+        add: Vec::new(),
+        delete: Vec::new(),
+    };
+    let error = |message: &str| format!("Action {}: {}", name, message);
+
+    let mut rest = items[1..].iter();
+    while let Some(key) = rest.next() {
+        let value = rest.next().ok_or_else(|| error("expected a value after a keyword"))?;
+        match key.symbol() {
+            Some(":parameters") => schema.parameters = typed_list(value.list()?)?,
+            Some(":precondition") => {
+                for condition in conjuncts(value)? {
+                    match negated(condition)? {
+                        None => schema.precondition.push(atom(condition)?),
+                        Some(inner) => {
+                            let equality = atom(inner)?;
+                            match equality.args.as_slice() {
+                                [x, y] if equality.predicate == "=" => schema.distinct.push((x.clone(), y.clone())),
+                                _ => return Err(error("negative preconditions are not STRIPS")),
+                            }
+                        },
+                    }
+                }
+            },
+            Some(":effect") => {
+                for effect in conjuncts(value)? {
+                    match negated(effect)? {
+                        None => schema.add.push(atom(effect)?),
+                        Some(inner) => schema.delete.push(atom(inner)?),
+                    }
+                }
+            },
+            _ => return Err(error(&format!("unknown keyword {:?}", key.symbol().unwrap_or("(...)")))),
+        }
+    }
+    Ok(schema)
+}
+
+impl Domain {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let expr = parse_sexpr(text)?;
+        let (name, sections) = definition(&expr, "domain")?;
+        let mut domain = Domain {
+            name,
+            types: HashMap::new(),
+            constants: Vec::new(),
+            predicates: HashMap::new(),
+            actions: Vec::new(),
+        };
+
+        for section in sections {
+            let Some((head, body)) = section.list()?.split_first() else {
+                return Err(String::from("Empty section in the domain"));
+            };
+            match head.symbol() {
+                Some(":requirements") => {
+                    for requirement in body.iter().filter_map(SExpr::symbol) {
+                        if requirement != ":strips" && requirement != ":typing" && requirement != ":equality" {
+                            return Err(format!("Unsupported requirement {}", requirement));
+                        }
+                    }
+                },
+                Some(":types") => domain.types.extend(typed_list(body)?),
+                Some(":constants") => domain.constants = typed_list(body)?,
+                Some(":predicates") => {
+                    for predicate in body {
+                        let items = predicate.list()?;
+                        let name = items.first().and_then(SExpr::symbol).ok_or("Expected a predicate name")?;
+                        domain.predicates.insert(name.to_string(), typed_list(&items[1..])?.len());
+                    }
+                },
+                Some(":action") => domain.actions.push(action(body)?),
+                _ => return Err(String::from("Unknown section in the domain")),
+            }
+        }
+
+        domain.check()?;
+        Ok(domain)
+    }
+
+    /// True if `t` is `ancestor` or one of its subtypes
+    pub fn is_subtype(&self, t: &str, ancestor: &str) -> bool {
+        let mut current = t;
+        /* The number of steps is bounded, in case the types are declared in a cycle */
+        for _ in 0..=self.types.len() {
+            if current == ancestor {
+                return true;
+            }
+            match self.types.get(current) {
+                Some(parent) => current = parent,
+                None => return false,
+            }
+        }
+        false
+    }
+
+    fn check_type(&self, t: &str) -> Result<(), String> {
+        if t == OBJECT || self.types.contains_key(t) {
+            Ok(())
+        } else {
+            Err(format!("Unknown type {}", t))
+        }
+    }
+
+    fn check_atom(&self, atom: &Atom, bound: &dyn Fn(&str) -> bool) -> Result<(), String> {
+        match self.predicates.get(&atom.predicate) {
+            None => return Err(format!("Unknown predicate in {}", atom)),
+            Some(arity) if *arity != atom.args.len() => {
+                return Err(format!("{} has {} arguments, but {} takes {}", atom, atom.args.len(), atom.predicate, arity));
+            },
+            Some(_) => {},
+        }
+        match atom.args.iter().find(|a| !bound(a)) {
+            Some(arg) => Err(format!("Unknown argument {} in {}", arg, atom)),
+            None => Ok(()),
+        }
+    }
+
+    fn check(&self) -> Result<(), String> {
+        for t in self.types.values() {
+            self.check_type(t)?;
+        }
+        for (_, t) in &self.constants {
+            self.check_type(t)?;
+        }
+        for schema in &self.actions {
+            for (_, t) in &schema.parameters {
+                self.check_type(t)?;
+            }
+            let bound = |arg: &str| {
+                schema.parameters.iter().any(|(p, _)| p == arg) || self.constants.iter().any(|(c, _)| c == arg)
+            };
+            for atom in schema.precondition.iter().chain(&schema.add).chain(&schema.delete) {
+                self.check_atom(atom, &bound).map_err(|e| format!("Action {}: {}", schema.name, e))?;
+            }
+            for (x, y) in &schema.distinct {
+                if !bound(x) || !bound(y) {
+                    return Err(format!("Action {}: unknown argument in (= {} {})", schema.name, x, y));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl PddlProblem {
+    /// Parses a problem file, checking it against its domain
+    pub fn parse(text: &str, domain: &Domain) -> Result<Self, String> {
+        let expr = parse_sexpr(text)?;
+        let (name, sections) = definition(&expr, "problem")?;
+        let mut problem = PddlProblem {
+            name,
+            domain: String::new(),
+            objects: Vec::new(),
+            init: Vec::new(),
+            goal: Vec::new(),
+        };
+
+        for section in sections {
+            let Some((head, body)) = section.list()?.split_first() else {
+                return Err(String::from("Empty section in the problem"));
+            };
+            match head.symbol() {
+                Some(":domain") => {
+                    problem.domain = body.first().and_then(SExpr::symbol).ok_or("Expected the name of the domain")?.to_string();
+                },
+                Some(":requirements") => {},
+                Some(":objects") => problem.objects = typed_list(body)?,
+                Some(":init") => problem.init = body.iter().map(atom).collect::<Result<_, _>>()?,
+                Some(":goal") => {
+                    let goal = body.first().ok_or("Expected a goal")?;
+                    problem.goal = conjuncts(goal)?.iter().map(atom).collect::<Result<_, _>>()?;
+                },
+                _ => return Err(String::from("Unknown section in the problem")),
+            }
+        }
+
+        if problem.domain != domain.name {
+            return Err(format!("The problem is for the domain '{}', not '{}'", problem.domain, domain.name));
+        }
+        for (_, t) in &problem.objects {
+            domain.check_type(t)?;
+        }
+        let known = |arg: &str| {
+            problem.objects.iter().any(|(o, _)| o == arg) || domain.constants.iter().any(|(c, _)| c == arg)
+        };
+        for atom in problem.init.iter().chain(&problem.goal) {
+            domain.check_atom(atom, &known)?;
+        }
+        Ok(problem)
+    }
+}
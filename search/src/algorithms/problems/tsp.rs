@@ -0,0 +1,191 @@
+//!
+//! The travelling salesperson problem of the AIMA chapter 3 exercises: visit every city once and
+//! return to the first one, along the shortest tour.
+//!
+//! A state is a partial tour that starts in the first city: the set of visited cities and the
+//! current one. Only those matter for the rest of the tour, so partial tours that visited the
+//! same cities in another order are the same state. The actions go to an unvisited city, or back
+//! to the first city once all are visited.
+//!
+//! The heuristic is the cost of a minimum spanning tree of the unvisited cities together with
+//! the current and the first city. The rest of the tour is a path through exactly those cities,
+//! which is a spanning tree itself, so the heuristic is admissible.
+//!
+//! Instances are read from TSPLIB files by `search_core::tsplib`, which computes the distances
+//! between coordinates as TSPLIB does. Cities are shown with their TSPLIB numbers, starting at 1.
+//!
+
+/* Std library */
+use std::fmt::{Debug, Display};
+
+/* Internal crates */
+use crate::algorithms::{
+    node::Node,
+    problem::Problem,
+};
+use search_core::Tsplib;
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct TspState {
+    visited: Vec<bool>,
+    current: usize,
+}
+
+/// Going to the given city (counted from 0)
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TspAction(pub usize);
+
+#[derive(Clone)]
+pub struct TspProblem {
+    name: String,
+    distances: Vec<Vec<u32>>,
+}
+
+impl TspState {
+    /// The city the partial tour ends in (counted from 0)
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    pub fn is_visited(&self, city: usize) -> bool {
+        self.visited[city]
+    }
+
+    fn is_complete(&self) -> bool {
+        self.visited.iter().all(|v| *v)
+    }
+}
+
+impl Display for TspState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let visited: Vec<String> = (0..self.visited.len())
+            .filter(|c| self.visited[*c])
+            .map(|c| (c + 1).to_string())
+            .collect();
+        write!(f, "at {}, visited {{{}}}", self.current + 1, visited.join(" "))
+    }
+}
+
+impl Debug for TspAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "To {}", self.0 + 1)
+    }
+}
+
+impl TspProblem {
+    /// A problem with the given distances, which must be a symmetric square matrix
+    pub fn new(name: &str, distances: Vec<Vec<u32>>) -> Result<Self, String> {
+        let n = distances.len();
+        if n == 0 {
+            return Err(String::from("There are no cities"));
+        }
+        for (i, row) in distances.iter().enumerate() {
+            if row.len() != n {
+                return Err(format!("Row {} of the distances has {} values instead of {}", i + 1, row.len(), n));
+            }
+            for (j, d) in row.iter().enumerate() {
+                if *d != distances[j][i] {
+                    return Err(format!("The distances from {} to {} and back differ", i + 1, j + 1));
+                }
+            }
+        }
+        Ok(Self { name: name.to_string(), distances })
+    }
+
+    /// Parses a TSPLIB file, whose distances must be whole and not negative
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let Tsplib { name, distances } = Tsplib::parse(text)?;
+        let distances = distances.into_iter()
+            .map(|row| row.into_iter().map(|d| whole(d).ok_or(format!("{} is not a whole distance", d))).collect())
+            .collect::<Result<_, String>>()?;
+        Self::new(&name, distances)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn cities(&self) -> usize {
+        self.distances.len()
+    }
+
+    /// Distance between two cities (counted from 0)
+    pub fn distance(&self, from: usize, to: usize) -> u32 {
+        self.distances[from][to]
+    }
+
+    /// Cost of a minimum spanning tree of the given cities (Prim's algorithm)
+    pub fn spanning_tree_cost(&self, cities: &[usize]) -> u32 {
+        let Some((&first, rest)) = cities.split_first() else {
+            return 0;
+        };
+        let mut outside: Vec<usize> = rest.to_vec();
+        /* Distance from each city outside the tree to the nearest city in it */
+        let mut nearest: Vec<u32> = outside.iter().map(|c| self.distances[first][*c]).collect();
+        let mut cost = 0;
+        while let Some(i) = (0..outside.len()).min_by_key(|i| nearest[*i]) {
+            let city = outside.swap_remove(i);
+            cost += nearest.swap_remove(i);
+            for (other, d) in outside.iter().zip(nearest.iter_mut()) {
+                *d = (*d).min(self.distances[city][*other]);
+            }
+        }
+        cost
+    }
+}
+
+fn whole(distance: f64) -> Option<u32> {
+    (distance.fract() == 0. && (0. ..=u32::MAX as f64).contains(&distance)).then_some(distance as u32)
+}
+
+impl Problem<TspState, TspAction> for TspProblem {
+    /// Every city was visited, and the tour is back in the first one
+    fn is_goal(&self, state: &TspState) -> bool {
+        state.is_complete() && state.current == 0
+    }
+
+    fn actions(&self, state: &TspState) -> Vec<TspAction> {
+        if state.is_complete() {
+            if state.current == 0 { vec![] } else { vec![TspAction(0)] }
+        } else {
+            (0..self.cities()).filter(|c| !state.visited[*c]).map(TspAction).collect()
+        }
+    }
+
+    fn result(&self, state: &TspState, action: &TspAction) -> TspState {
+        let mut visited = state.visited.clone();
+        visited[action.0] = true;
+        TspState { visited, current: action.0 }
+    }
+
+    fn action_cost(&self, state: &TspState, action: &TspAction, _new_state: &TspState) -> u32 {
+        self.distances[state.current][action.0]
+    }
+
+    fn get_initial_node(&self) -> Node<TspState, TspAction> {
+        let mut visited = vec![false; self.cities()];
+        visited[0] = true;
+        let state = TspState { visited, current: 0 };
+        let h = self.get_heuristic_cost(&state);
+        Node::new(state, None, None, 0, h)
+    }
+
+    /// The complete tour, back in the first city. The actions can not be reversed, so
+    /// bidirectional search does not apply.
+    fn get_goal_node(&self) -> Node<TspState, TspAction> {
+        let state = TspState { visited: vec![true; self.cities()], current: 0 };
+        Node::new(state, None, None, 0, 0)
+    }
+
+    fn get_heuristic_cost(&self, state: &TspState) -> u32 {
+        if self.is_goal(state) {
+            return 0;
+        }
+        let mut cities: Vec<usize> = (0..self.cities()).filter(|c| !state.visited[*c]).collect();
+        cities.push(state.current);
+        if state.current != 0 {
+            cities.push(0);
+        }
+        self.spanning_tree_cost(&cities)
+    }
+}
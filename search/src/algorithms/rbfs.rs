@@ -1,80 +1,108 @@
-///
-/// function Recursive-Best-First-Search(problem) returns a solution or failure
-///     solution,fvalue <- RBFS(problem, Node(problem.initial), infty)
-/// return solution
-///
-///
-/// function RBFS(problem, node, f_limit) returns a solution or failure, and a new f-cost limit
-///     if problem.Is-Goal(node.state) then return node
-///     successors <- List(Expand(node))
-///     if successors is empty then return failure, infty
-///     for each s in successors do     // update f with value from previous search
-///         s,f <- max(s.Path-Cost + h(s), node.f)
-///     while true do
-///         best <- the node in successors with lowest f-value
-///         if best.f > f_limit then return failure, best.f
-///         alternative <- the second-lowest f-value among successors
-///         result,best.f <- RBFS(problem, best, min(f_limit,alternative))
-///         if result != then return result, best.f
-///
-///
+//!
+//! function Recursive-Best-First-Search(problem) returns a solution or failure
+//!     solution,fvalue <- RBFS(problem, Node(problem.initial), infty)
+//! return solution
+//!
+//!
+//! function RBFS(problem, node, f_limit) returns a solution or failure, and a new f-cost limit
+//!     if problem.Is-Goal(node.state) then return node
+//!     successors <- List(Expand(node))
+//!     if successors is empty then return failure, infty
+//!     for each s in successors do     // update f with value from previous search
+//!         s,f <- max(s.Path-Cost + h(s), node.f)
+//!     while true do
+//!         best <- the node in successors with lowest f-value
+//!         if best.f > f_limit then return failure, best.f
+//!         alternative <- the second-lowest f-value among successors
+//!         result,best.f <- RBFS(problem, best, min(f_limit,alternative))
+//!         if result != then return result, best.f
+//!
+//!
 
 use std::{
+    cmp::Reverse,
     fmt::Debug
 };
 
 use crate::algorithms::{
+    cost::Cost,
     problem::*,
     node::*,
+    tracer::{Tracer, NoTracer},
 };
 
-pub fn recursive_best_first_search<P, S, A>(problem: &P, h: fn(&P, &Node<S, A>) -> u32) -> SearchResult<S, A>
+pub fn recursive_best_first_search<P, S, A, C>(problem: &P, h: fn(&P, &Node<S, A, C>) -> C) -> SearchResult<S, A, C>
 where
-    P: Problem<S, A>,
+    P: Problem<S, A, C>,
     S: Clone + Debug,
-    A: Clone + Debug
+    A: Clone + Debug,
+    C: Cost + Ord
 {
-    let (solution, fvalue) = rbfs(problem, h, problem.get_initial_node(), u32::MAX);
-    return solution;
+    recursive_best_first_search_traced(problem, h, &mut NoTracer)
 }
 
-fn rbfs<P, S, A>(problem: &P, h: fn(&P, &Node<S, A>) -> u32, node: Node<S, A>, f_limit: u32) -> (SearchResult<S, A>, u32)
+pub fn recursive_best_first_search_traced<P, S, A, C, T>(problem: &P, h: fn(&P, &Node<S, A, C>) -> C, tracer: &mut T) -> SearchResult<S, A, C>
 where
-    P: Problem<S, A>,
+    P: Problem<S, A, C>,
     S: Clone + Debug,
-    A: Clone + Debug
+    A: Clone + Debug,
+    C: Cost + Ord,
+    T: Tracer<S, A, C>
+{
+    let root = problem.get_initial_node();
+    tracer.generated(&root);
+    let (solution, _fvalue) = rbfs(problem, h, root, C::max_value(), tracer);
+    if let Ok(node) = &solution {
+        tracer.solution(node);
+    }
+    solution
+}
+
+fn rbfs<P, S, A, C, T>(problem: &P, h: fn(&P, &Node<S, A, C>) -> C, node: Node<S, A, C>, f_limit: C, tracer: &mut T) -> (SearchResult<S, A, C>, C)
+where
+    P: Problem<S, A, C>,
+    S: Clone + Debug,
+    A: Clone + Debug,
+    C: Cost + Ord,
+    T: Tracer<S, A, C>
 {
     if problem.is_goal(&node.state) {
         return (Ok(node), f_limit);
     }
-    let mut successors = expand(problem, node.clone());
-    successors.reverse();
+    tracer.expanded(&node);
+    let mut successors = match expand(problem, node.clone()) {
+        Ok(successors) => successors,
+        Err(e) => return (Err(e), C::max_value()),
+    };
 
     if successors.is_empty() {
-        return (Err(SearchError::Failure), u32::MAX);
+        return (Err(SearchError::Failure), C::max_value());
     }
 
-    for mut s in &mut successors {
-        s.f = (s.path_cost + h(problem, &s)).max(node.clone().f);
+    for s in &mut successors {
+        /* Like in A*, a sum too large for C is treated as infinite, so the node is never expanded */
+        let f = s.path_cost.checked_add(h(problem, s)).unwrap_or_else(C::max_value);
+        s.f = f.max(node.f);
+        tracer.generated(s);
     }
     
     loop {
-        //println!("{:#?}", successors);           
-        let mut best = successors.pop().unwrap();
-        if best.f >= f_limit {
+        /* Sort descending on f, so the best node is the last one */
+        successors.sort_by_key(|s| Reverse(s.f));
+        let n = successors.len();
+        let alternative = if n > 1 { successors[n - 2].f } else { C::max_value() };
+        let best = &mut successors[n - 1];
+        /* An infinite f means that no goal can be reached through best */
+        if best.f > f_limit || best.f == C::max_value() {
+            tracer.pruned(best);
             return (Err(SearchError::Failure), best.f);
         }
         
-        let alternative = successors.pop().unwrap();
-        let result: SearchResult<S, A>;
-        (result, best.f) = rbfs(problem, h, best.clone(), f_limit.min(alternative.f));
-        match result {
-            Ok(_) => {
-                return (result, best.f);
-            },
-            _ => {}
+        let result: SearchResult<S, A, C>;
+        (result, best.f) = rbfs(problem, h, best.clone(), f_limit.min(alternative), tracer);
+        if !matches!(result, Err(SearchError::Failure)) {
+            return (result, best.f);
         }
+        tracer.pruned(best);
     }
 }
-
-
@@ -0,0 +1,338 @@
+//!
+//! Tracing of the search tree explored by the algorithms
+//!
+//! Every algorithm has a `_traced` variant which reports the nodes it generates, expands and
+//! prunes to a `Tracer`. The plain variants pass `NoTracer`, which does nothing.
+//!
+//! `DotTracer` records the whole explored tree and writes it as a Graphviz DOT file:
+//!     - expanded nodes are filled, and labelled with the order(s) in which they were expanded
+//!     - nodes expanded more than once (RBFS, IDS) are drawn with a double border
+//!     - pruned nodes (not added to the frontier, cut off, or forgotten by RBFS and not expanded
+//!       again afterwards) are dashed and grey
+//!     - the solution path is drawn in bold red
+//!
+
+/* Std library */
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Write as _};
+use std::hash::Hash;
+use std::path::Path;
+
+/* Internal crates */
+use crate::algorithms::node::Node;
+
+pub trait Tracer<S, A, C = u32> {
+    /// A child node was created by `expand` (or the root node was created)
+    fn generated(&mut self, _node: &Node<S, A, C>) {}
+    /// A node was taken from the frontier and its children are about to be generated
+    fn expanded(&mut self, _node: &Node<S, A, C>) {}
+    /// A generated node was discarded, or its subtree was abandoned
+    fn pruned(&mut self, _node: &Node<S, A, C>) {}
+    /// The algorithm returned this node as its solution
+    fn solution(&mut self, _node: &Node<S, A, C>) {}
+}
+
+/// Tracer used by the untraced algorithms
+pub struct NoTracer;
+
+impl<S, A, C> Tracer<S, A, C> for NoTracer {}
+
+/// Reports every event to both tracers
+impl<S, A, C, T1, T2> Tracer<S, A, C> for (T1, T2)
+where
+    T1: Tracer<S, A, C>,
+    T2: Tracer<S, A, C>
+{
+    fn generated(&mut self, node: &Node<S, A, C>) {
+        self.0.generated(node);
+        self.1.generated(node);
+    }
+
+    fn expanded(&mut self, node: &Node<S, A, C>) {
+        self.0.expanded(node);
+        self.1.expanded(node);
+    }
+
+    fn pruned(&mut self, node: &Node<S, A, C>) {
+        self.0.pruned(node);
+        self.1.pruned(node);
+    }
+
+    fn solution(&mut self, node: &Node<S, A, C>) {
+        self.0.solution(node);
+        self.1.solution(node);
+    }
+}
+
+/// Tracing which can be switched off at runtime
+impl<S, A, C, T> Tracer<S, A, C> for Option<T>
+where
+    T: Tracer<S, A, C>
+{
+    fn generated(&mut self, node: &Node<S, A, C>) {
+        if let Some(t) = self { t.generated(node) }
+    }
+
+    fn expanded(&mut self, node: &Node<S, A, C>) {
+        if let Some(t) = self { t.expanded(node) }
+    }
+
+    fn pruned(&mut self, node: &Node<S, A, C>) {
+        if let Some(t) = self { t.pruned(node) }
+    }
+
+    fn solution(&mut self, node: &Node<S, A, C>) {
+        if let Some(t) = self { t.solution(node) }
+    }
+}
+
+impl<S, A, C, T> Tracer<S, A, C> for &mut T
+where
+    T: Tracer<S, A, C>
+{
+    fn generated(&mut self, node: &Node<S, A, C>) {
+        (**self).generated(node);
+    }
+
+    fn expanded(&mut self, node: &Node<S, A, C>) {
+        (**self).expanded(node);
+    }
+
+    fn pruned(&mut self, node: &Node<S, A, C>) {
+        (**self).pruned(node);
+    }
+
+    fn solution(&mut self, node: &Node<S, A, C>) {
+        (**self).solution(node);
+    }
+}
+
+/// Counts the events, without remembering the nodes
+#[derive(Clone, Copy, Default, Debug)]
+pub struct Statistics {
+    pub generated: usize,
+    pub expanded: usize,
+    pub pruned: usize,
+    /// Largest depth (number of actions from the root) of any generated node
+    pub max_depth: usize,
+}
+
+impl Statistics {
+    /// The branching factor b* that a uniform tree of the solution depth d would need to hold
+    /// the N generated nodes: N + 1 = 1 + b* + (b*)^2 + ... + (b*)^d. None at depth 0.
+    pub fn effective_branching_factor(&self, solution_depth: usize) -> Option<f64> {
+        if solution_depth == 0 || self.generated == 0 {
+            return None;
+        }
+        let n = self.generated as f64;
+        let nodes = |b: f64| (1..=solution_depth).map(|i| b.powi(i as i32)).sum::<f64>();
+        /* nodes(b) grows with b, and nodes(N) >= N, so b* is found by bisection */
+        let (mut low, mut high) = (0.0, n.max(1.0));
+        for _ in 0..100 {
+            let mid = (low + high) / 2.0;
+            if nodes(mid) < n { low = mid } else { high = mid }
+        }
+        Some((low + high) / 2.0)
+    }
+
+    /// Solution depth over the number of generated nodes: 1 when only the nodes on the
+    /// solution path are generated, close to 0 when the search wanders off
+    pub fn penetrance(&self, solution_depth: usize) -> Option<f64> {
+        (self.generated > 0).then(|| solution_depth as f64 / self.generated as f64)
+    }
+}
+
+impl<S, A, C> Tracer<S, A, C> for Statistics {
+    fn generated(&mut self, node: &Node<S, A, C>) {
+        self.generated += 1;
+        let mut depth = 0;
+        let mut n = node.parent.as_ref();
+        while let Some(parent) = n {
+            depth += 1;
+            n = parent.parent.as_ref();
+        }
+        self.max_depth = self.max_depth.max(depth);
+    }
+
+    fn expanded(&mut self, _node: &Node<S, A, C>) {
+        self.expanded += 1;
+    }
+
+    fn pruned(&mut self, _node: &Node<S, A, C>) {
+        self.pruned += 1;
+    }
+}
+
+struct TracedNode {
+    parent: Option<usize>,
+    state: String,
+    action: String,
+    path_cost: String,
+    f: String,
+    expansions: Vec<usize>,
+    pruned: bool,
+    on_solution_path: bool,
+}
+
+/// Records the explored tree. Nodes are identified by the sequence of states from the root,
+/// so a node that is generated again along the same path (RBFS, IDS) is the same DOT node.
+pub struct DotTracer<S> {
+    ids: HashMap<Vec<S>, usize>,
+    nodes: Vec<TracedNode>,
+    expansions: usize,
+}
+
+impl<S> Default for DotTracer<S> {
+    fn default() -> Self {
+        Self { ids: HashMap::new(), nodes: Vec::new(), expansions: 0 }
+    }
+}
+
+impl<S> DotTracer<S>
+where
+    S: Clone + Eq + Hash + Display
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of expansions recorded so far, counting re-expansions
+    pub fn num_expansions(&self) -> usize {
+        self.expansions
+    }
+
+    /// Number of distinct nodes generated
+    pub fn num_generated(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn path_of<A, C>(node: &Node<S, A, C>) -> Vec<S> {
+        let mut path = vec![node.state.clone()];
+        let mut n = node.parent.as_ref();
+        while let Some(parent) = n {
+            path.push(parent.state.clone());
+            n = parent.parent.as_ref();
+        }
+        path.reverse();
+        path
+    }
+
+    fn lookup<A: Debug, C: Display>(&mut self, node: &Node<S, A, C>) -> usize {
+        let path = Self::path_of(node);
+        if let Some(id) = self.ids.get(&path) {
+            let traced = &mut self.nodes[*id];
+            traced.path_cost = node.path_cost.to_string();
+            traced.f = node.f.to_string();
+            return *id;
+        }
+
+        let parent = node.parent.as_ref().map(|p| self.lookup(p));
+        let id = self.nodes.len();
+        self.nodes.push(TracedNode {
+            parent,
+            state: node.state.to_string(),
+            action: node.action.as_ref().map(|a| format!("{:?}", a)).unwrap_or_default(),
+            path_cost: node.path_cost.to_string(),
+            f: node.f.to_string(),
+            expansions: Vec::new(),
+            pruned: false,
+            on_solution_path: false,
+        });
+        self.ids.insert(path, id);
+        id
+    }
+
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        writeln!(dot, "digraph search_tree {{").unwrap();
+        writeln!(dot, "    node [shape=box, fontname=\"Helvetica\"];").unwrap();
+        writeln!(dot, "    edge [fontname=\"Helvetica\", fontsize=10];").unwrap();
+
+        for (id, node) in self.nodes.iter().enumerate() {
+            let order = if node.expansions.is_empty() {
+                String::from("-")
+            } else {
+                node.expansions.iter().map(|e| e.to_string()).collect::<Vec<_>>().join(",")
+            };
+            let mut attributes = vec![format!(
+                "label=\"{}\\ng={} f={}\\n#{}\"",
+                escape(&node.state), node.path_cost, node.f, order
+            )];
+
+            let mut style = Vec::new();
+            if !node.expansions.is_empty() {
+                style.push("filled");
+                attributes.push(String::from("fillcolor=\"lightblue\""));
+            }
+            if node.expansions.len() > 1 {
+                attributes.push(String::from("peripheries=2"));
+            }
+            if node.pruned && !node.on_solution_path {
+                style.push("dashed");
+                attributes.push(String::from("color=\"gray\", fontcolor=\"gray\""));
+            }
+            if node.on_solution_path {
+                style.push("bold");
+                attributes.push(String::from("color=\"red\""));
+            }
+            if !style.is_empty() {
+                attributes.push(format!("style=\"{}\"", style.join(",")));
+            }
+            writeln!(dot, "    n{} [{}];", id, attributes.join(", ")).unwrap();
+        }
+
+        for (id, node) in self.nodes.iter().enumerate() {
+            if let Some(parent) = node.parent {
+                let mut attributes = vec![format!("label=\"{}\"", escape(&node.action))];
+                if node.on_solution_path {
+                    attributes.push(String::from("color=\"red\", penwidth=2"));
+                } else if node.pruned {
+                    attributes.push(String::from("style=\"dashed\", color=\"gray\""));
+                }
+                writeln!(dot, "    n{} -> n{} [{}];", parent, id, attributes.join(", ")).unwrap();
+            }
+        }
+
+        writeln!(dot, "}}").unwrap();
+        dot
+    }
+
+    pub fn write_dot<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.to_dot())
+    }
+}
+
+impl<S, A, C> Tracer<S, A, C> for DotTracer<S>
+where
+    S: Clone + Eq + Hash + Display,
+    A: Debug,
+    C: Display
+{
+    fn generated(&mut self, node: &Node<S, A, C>) {
+        self.lookup(node);
+    }
+
+    fn expanded(&mut self, node: &Node<S, A, C>) {
+        let id = self.lookup(node);
+        self.expansions += 1;
+        self.nodes[id].expansions.push(self.expansions);
+        self.nodes[id].pruned = false;
+    }
+
+    fn pruned(&mut self, node: &Node<S, A, C>) {
+        let id = self.lookup(node);
+        self.nodes[id].pruned = true;
+    }
+
+    fn solution(&mut self, node: &Node<S, A, C>) {
+        let mut id = Some(self.lookup(node));
+        while let Some(i) = id {
+            self.nodes[i].on_solution_path = true;
+            id = self.nodes[i].parent;
+        }
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -0,0 +1,181 @@
+//!
+//! Step-through debugger for the terminal: replays the `EventLog` of a search one expansion at a
+//! time, showing the expanded node and its children, the frontier and the reached table of each
+//! direction, like the AIMA figures of best-first and bidirectional search.
+//!
+//! Keys: → / l / space next step, ← / h previous step, Home / End first and last step, q quit.
+//!
+
+/* Std library */
+use std::fmt::Display;
+use std::hash::Hash;
+
+/* External crates */
+use ratatui::{
+    crossterm::event::{self, Event as TerminalEvent, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Paragraph, Row, Table},
+    Frame,
+};
+
+/* Internal crates */
+use search::{EventLog, Snapshot};
+
+/// Shows the log until the user quits
+pub fn run<S, C>(title: &str, log: &EventLog<S, C>) -> std::io::Result<()>
+where
+    S: Clone + Eq + Hash + Display,
+    C: Clone + Ord + Display
+{
+    let snapshots = log.snapshots();
+    let mut step = 0;
+    let mut terminal = ratatui::try_init().inspect_err(|_| ratatui::restore())?;
+    let result = loop {
+        if let Err(e) = terminal.draw(|frame| render(frame, title, log, &snapshots, step)) {
+            break Err(e);
+        }
+        match event::read() {
+            Ok(TerminalEvent::Key(key)) if key.kind == KeyEventKind::Press => match key.code {
+                KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ') => step = (step + 1).min(snapshots.len() - 1),
+                KeyCode::Left | KeyCode::Char('h') => step = step.saturating_sub(1),
+                KeyCode::Home => step = 0,
+                KeyCode::End => step = snapshots.len() - 1,
+                KeyCode::Char('q') | KeyCode::Esc => break Ok(()),
+                _ => {},
+            },
+            Ok(_) => {},
+            Err(e) => break Err(e),
+        }
+    };
+    ratatui::restore();
+    result
+}
+
+fn render<S, C>(frame: &mut Frame, title: &str, log: &EventLog<S, C>, snapshots: &[Snapshot], step: usize)
+where
+    S: Clone + Eq + Hash + Display,
+    C: Clone + Ord + Display
+{
+    let snapshot = &snapshots[step];
+    let current = current_lines(log, snapshot);
+    let [header, current_area, tables, help] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(current.len() as u16 + 2),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ]).areas(frame.area());
+
+    let header_text = format!("{} - step {} of {}", title, step, snapshots.len() - 1);
+    frame.render_widget(Paragraph::new(header_text).style(Style::new().add_modifier(Modifier::BOLD)), header);
+    frame.render_widget(Paragraph::new(current).block(Block::bordered().title(" Current step ")), current_area);
+
+    let directions = snapshot.frontiers.len();
+    let columns = Layout::horizontal(vec![Constraint::Ratio(1, directions as u32); directions]).split(tables);
+    for (direction, column) in columns.iter().enumerate() {
+        let name = match (directions, direction) {
+            (1, _) => "",
+            (_, 0) => " (forward)",
+            _ => " (backward)",
+        };
+        let [frontier_area, reached_area] = Layout::vertical([Constraint::Percentage(50); 2]).areas(*column);
+        render_frontier(frame, frontier_area, name, log, snapshot, direction);
+        render_reached(frame, reached_area, name, log, snapshot, direction);
+    }
+
+    let help_text = "→/l/space next   ←/h back   Home/End first/last   q quit";
+    frame.render_widget(Paragraph::new(help_text).style(Style::new().fg(Color::DarkGray)), help);
+}
+
+/* What happened in the step: the expansion and its children, or the solution */
+fn current_lines<S, C>(log: &EventLog<S, C>, snapshot: &Snapshot) -> Vec<Line<'static>>
+where
+    S: Clone + Eq + Hash + Display,
+    C: Clone + Ord + Display
+{
+    let mut lines = Vec::new();
+    if let Some(solution) = snapshot.solution {
+        let node = log.node(solution);
+        lines.push(Line::styled(format!("Solution with path cost {}", node.path_cost), Style::new().fg(Color::Red)));
+        lines.push(Line::from(format!("Path: {}", path_text(log, solution))));
+        return lines;
+    }
+    let Some(expanded) = snapshot.expanded else {
+        lines.push(Line::from("Initial frontier, nothing expanded yet"));
+        return lines;
+    };
+
+    let node = log.node(expanded);
+    lines.push(Line::styled(
+        format!("Expanded {} (g = {}, f = {})", node.state, node.path_cost, node.f),
+        Style::new().add_modifier(Modifier::BOLD),
+    ));
+    lines.push(Line::from(format!("Path: {}", path_text(log, expanded))));
+    for (child, added) in &snapshot.generated {
+        let child_node = log.node(*child);
+        let action = child_node.action.clone().unwrap_or_default();
+        let text = format!("  {} -> {} (g = {}, f = {})", action, child_node.state, child_node.path_cost, child_node.f);
+        lines.push(if *added {
+            Line::styled(text + "  added to the frontier", Style::new().fg(Color::Green))
+        } else {
+            Line::styled(text + "  already reached at a lower cost", Style::new().fg(Color::DarkGray))
+        });
+    }
+    lines
+}
+
+fn path_text<S, C>(log: &EventLog<S, C>, id: usize) -> String
+where
+    S: Clone + Eq + Hash + Display,
+    C: Clone + Ord
+{
+    log.path(id).iter().map(|n| log.node(*n).state.to_string()).collect::<Vec<_>>().join(" -> ")
+}
+
+fn render_frontier<S, C>(frame: &mut Frame, area: Rect, name: &str, log: &EventLog<S, C>, snapshot: &Snapshot, direction: usize)
+where
+    S: Clone + Eq + Hash + Display,
+    C: Clone + Ord + Display
+{
+    let rows = snapshot.frontiers[direction].iter().enumerate().map(|(i, id)| {
+        let node = log.node(*id);
+        let mut style = Style::new();
+        if snapshot.generated.contains(&(*id, true)) {
+            style = style.fg(Color::Green);
+        }
+        if i == 0 {
+            style = style.add_modifier(Modifier::BOLD);
+        }
+        Row::new(vec![
+            (i + 1).to_string(),
+            node.state.to_string(),
+            node.action.clone().unwrap_or_default(),
+            node.path_cost.to_string(),
+            node.f.to_string(),
+        ]).style(style)
+    });
+    let widths = [Constraint::Length(4), Constraint::Fill(3), Constraint::Fill(2), Constraint::Length(8), Constraint::Length(8)];
+    let table = Table::new(rows, widths)
+        .header(Row::new(["#", "State", "Action", "g", "f"]).style(Style::new().add_modifier(Modifier::UNDERLINED)))
+        .block(Block::bordered().title(format!(" Frontier{} ", name)));
+    frame.render_widget(table, area);
+}
+
+fn render_reached<S, C>(frame: &mut Frame, area: Rect, name: &str, log: &EventLog<S, C>, snapshot: &Snapshot, direction: usize)
+where
+    S: Clone + Eq + Hash + Display,
+    C: Clone + Ord + Display
+{
+    let rows = snapshot.reached[direction].iter().map(|id| {
+        let node = log.node(*id);
+        let parent = node.parent.map(|p| log.node(p).state.to_string()).unwrap_or_else(|| String::from("-"));
+        let style = if snapshot.generated.contains(&(*id, true)) { Style::new().fg(Color::Green) } else { Style::new() };
+        Row::new(vec![node.state.to_string(), node.path_cost.to_string(), parent]).style(style)
+    });
+    let widths = [Constraint::Fill(3), Constraint::Length(8), Constraint::Fill(3)];
+    let table = Table::new(rows, widths)
+        .header(Row::new(["State", "g", "Parent"]).style(Style::new().add_modifier(Modifier::UNDERLINED)))
+        .block(Block::bordered().title(format!(" Reached{} ", name)));
+    frame.render_widget(table, area);
+}
@@ -0,0 +1,49 @@
+//!
+//! Uninformed and informed search from chapter 3 of AIMA.
+//!
+//! A problem is described by implementing [`Problem`], and solved by passing it to one of the
+//! search functions below. Every search has a `_traced` variant that reports the search tree to
+//! a [`Tracer`].
+//!
+
+pub mod algorithms;
+
+/// The `problem!` macro, which defines a problem from its transition table
+pub use search_core::problem;
+
+pub use algorithms::{
+    cost::{Cost, OrderedFloat},
+    node::Node,
+    problem::{
+        expand,
+        Problem,
+        SearchError,
+        SearchResult,
+    },
+    problems::{
+        romania::{AradToBucharestAction, AradToBucharestProblem},
+        graph::{GraphAction, GraphProblem, GraphState},
+        grid_map::{Cell, GridAction, GridMapProblem},
+        npuzzle::{Board, NPuzzleAction, NPuzzleHeuristic, NPuzzleProblem},
+        planning::{FactSet, PlanningAction, PlanningHeuristic, PlanningProblem},
+        tsp::{TspAction, TspProblem, TspState},
+    },
+    tracer::{DotTracer, NoTracer, Statistics, Tracer},
+    event_log::{Event, EventLog, LoggedNode, Snapshot},
+    astar_search::{astar_search, astar_search_traced},
+    best_first_search::{best_first_search, best_first_search_traced},
+    bibf_search::{bibf_search, bibf_search_traced},
+    breadth_first_search::{
+        breadth_first_search,
+        breadth_first_search_traced,
+        uniform_cost_search,
+        uniform_cost_search_traced,
+    },
+    ida_star_search::{ida_star_search, ida_star_search_traced},
+    iterative_deepening_search::{
+        iterative_deepening_search,
+        iterative_deepening_search_limited,
+        iterative_deepening_search_traced,
+    },
+    rbfs::{recursive_best_first_search, recursive_best_first_search_traced},
+};
@@ -1,67 +1,532 @@
+//!
+//! The command-line runner, built with the `cli` feature:
+//!
+//!     cargo run -p search --features cli -- --algorithm bfs,ids
+//!
 
-use std::fmt::Display;
+use std::{
+    fmt::{Debug, Display, Write as _},
+    hash::Hash,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc,
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use clap::{Parser, ValueEnum};
 
-mod algorithms;
+mod debugger;
 
-use algorithms::{
-    node::Node,
-    problem::{Problem, SearchResult, ARAD_TO_BUCHAREST_PROBLEM},
-    best_first_search,
-    breadth_first_search,
-    iterative_deepening_search,
-    bibf_search,
-    rbfs,
+use search::{
+    astar_search_traced,
+    best_first_search_traced,
+    bibf_search_traced,
+    breadth_first_search_traced,
+    ida_star_search_traced,
+    iterative_deepening_search_limited,
+    recursive_best_first_search_traced,
+    uniform_cost_search_traced,
+    AradToBucharestProblem,
+    Board,
+    Cost,
+    DotTracer,
+    EventLog,
+    GraphProblem,
+    GridMapProblem,
+    NPuzzleHeuristic,
+    NPuzzleProblem,
+    Node,
+    OrderedFloat,
+    PlanningHeuristic,
+    PlanningProblem,
+    Problem,
+    SearchResult,
+    Statistics,
+    TspProblem,
 };
 
-type SearchAlgorithm<P, S, A> = fn(&P) -> SearchResult<S, A>;
-type SearchAlgorithmHeuristic<P, S, A> = fn(&P, fn(&P, &Node<S, A>) -> u32) -> SearchResult<S, A>;
+/// Runs the search algorithms on a problem and reports the paths they find
+#[derive(Parser)]
+#[command(name = "search")]
+struct Cli {
+    /// Algorithms to run, comma separated (all of them if not given)
+    #[arg(short, long, value_enum, value_delimiter = ',')]
+    algorithm: Vec<Algorithm>,
+
+    /// Kind of problem to solve
+    #[arg(short, long, value_enum, default_value = "romania")]
+    problem: ProblemKind,
+
+    /// Graph file, grid map file, PDDL problem file or TSPLIB file
+    #[arg(short, long)]
+    file: Option<PathBuf>,
+
+    /// PDDL domain file (pddl only)
+    #[arg(long)]
+    domain: Option<PathBuf>,
+
+    /// Start state: a city, a graph node, a board like "7,2,4,5,0,6,8,3,1" or a cell like "3,4"
+    #[arg(short, long)]
+    start: Option<String>,
+
+    /// Goal state, written like the start state. A graph may have several goals, comma separated.
+    #[arg(short, long)]
+    goal: Option<String>,
+
+    /// Heuristic used by greedy best-first search, A* and RBFS
+    #[arg(long, value_enum, default_value = "default")]
+    heuristic: HeuristicKind,
+
+    /// Deepest depth limit tried by iterative deepening search
+    #[arg(long, default_value_t = 1_000)]
+    max_depth: usize,
+
+    /// Seconds each algorithm may run before it is given up
+    #[arg(long)]
+    time_limit: Option<f64>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value = "table")]
+    format: Format,
+
+    /// Directory to write the explored search tree of each algorithm to, as DOT files
+    #[arg(long)]
+    dot: Option<PathBuf>,
+
+    /// Step through each search in the terminal, one expansion at a time
+    #[arg(long)]
+    step: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Algorithm {
+    Bfs,
+    Ucs,
+    BestFirst,
+    Astar,
+    IdaStar,
+    Ids,
+    Bibf,
+    Rbfs,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ProblemKind {
+    Romania,
+    Graph,
+    Npuzzle,
+    Grid,
+    Pddl,
+    Tsp,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum HeuristicKind {
+    /// The heuristic of the problem (straight-line distance, Manhattan distance, ...)
+    Default,
+    /// h = 0
+    Zero,
+    /// Number of misplaced tiles (n-puzzle only)
+    Misplaced,
+    /// Manhattan distance (n-puzzle and grid only)
+    Manhattan,
+    /// Relaxed cost of the most expensive goal (pddl only)
+    HMax,
+    /// Sum of the relaxed costs of the goals (pddl only)
+    HAdd,
+    /// Length of a relaxed plan (pddl only)
+    HFf,
+    /// Length of an optimal relaxed plan (pddl only, slow)
+    IgnoreDeleteLists,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+}
+
+impl Algorithm {
+    fn name(&self) -> &'static str {
+        match self {
+            Algorithm::Bfs => "Breadth First Search",
+            Algorithm::Ucs => "Uniform Cost Search",
+            Algorithm::BestFirst => "Greedy Best First Search",
+            Algorithm::Astar => "A* Search",
+            Algorithm::IdaStar => "IDA* Search",
+            Algorithm::Ids => "Iterative Deepening Search",
+            Algorithm::Bibf => "Bidirectional Best First Search",
+            Algorithm::Rbfs => "Recursive Best First Search",
+        }
+    }
 
+    fn file_name(&self) -> &'static str {
+        match self {
+            Algorithm::Bfs => "breadth_first_search",
+            Algorithm::Ucs => "uniform_cost_search",
+            Algorithm::BestFirst => "best_first_search",
+            Algorithm::Astar => "astar_search",
+            Algorithm::IdaStar => "ida_star_search",
+            Algorithm::Ids => "iterative_deepening_search",
+            Algorithm::Bibf => "bibf_search",
+            Algorithm::Rbfs => "rbfs",
+        }
+    }
+}
+
+struct Step {
+    state: String,
+    action: Option<String>,
+    path_cost: String,
+}
+
+enum Outcome {
+    Solved(Vec<Step>),
+    Failed(String),
+    TimedOut,
+}
+
+struct Report {
+    algorithm: Algorithm,
+    outcome: Outcome,
+    statistics: Statistics,
+    elapsed: Duration,
+}
 
 fn main() {
-    try_algorithm(&ARAD_TO_BUCHAREST_PROBLEM, "Best First Search", best_first_search::best_first_search);
-    try_algorithm(&ARAD_TO_BUCHAREST_PROBLEM, "Breadth First Search", breadth_first_search::breadth_first_search);
-    try_algorithm(&ARAD_TO_BUCHAREST_PROBLEM, "Iterative Deepening Search", iterative_deepening_search::iterative_deepening_search);
-    try_algorithm(&ARAD_TO_BUCHAREST_PROBLEM, "Bidirectional Best First Search", bibf_search::bibf_search);
-    try_heuristic(&ARAD_TO_BUCHAREST_PROBLEM, "Recursive Best First Search", rbfs::recursive_best_first_search, h);
+    let cli = Cli::parse();
+    let (description, reports) = match run(&cli) {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(2);
+        }
+    };
+
+    match cli.format {
+        Format::Table => print!("{}", format_table(&description, &reports)),
+        Format::Json => println!("{}", format_json(&description, &reports)),
+    }
+}
+
+fn run(cli: &Cli) -> Result<(String, Vec<Report>), String> {
+    let read = |path: &PathBuf| {
+        std::fs::read_to_string(path).map_err(|e| format!("Could not read {}: {}", path.display(), e))
+    };
+    let read_file = || read(cli.file.as_ref().ok_or("This problem needs a --file")?);
+    let check_heuristic = |allowed: &[HeuristicKind]| {
+        if cli.heuristic == HeuristicKind::Default || cli.heuristic == HeuristicKind::Zero || allowed.contains(&cli.heuristic) {
+            Ok(())
+        } else {
+            Err(String::from("This heuristic is not available for this problem"))
+        }
+    };
+
+    match cli.problem {
+        ProblemKind::Romania => {
+            check_heuristic(&[])?;
+            let start = cli.start.as_deref().unwrap_or("Arad");
+            let goal = cli.goal.as_deref().unwrap_or("Bucharest");
+            let problem: AradToBucharestProblem = AradToBucharestProblem::new(start, goal)?;
+            Ok((format!("romania {} -> {}", start, goal), run_algorithms(cli, problem)?))
+        },
+        ProblemKind::Graph => {
+            check_heuristic(&[])?;
+            /* Graph files may have fractional distances */
+            let problem: GraphProblem<OrderedFloat<f64>> = GraphProblem::parse(&read_file()?, cli.start.as_deref(), cli.goal.as_deref())?;
+            let description = format!("graph {}", cli.file.as_ref().unwrap().display());
+            Ok((description, run_algorithms(cli, problem)?))
+        },
+        ProblemKind::Npuzzle => {
+            check_heuristic(&[HeuristicKind::Misplaced, HeuristicKind::Manhattan])?;
+            let start: Board = cli.start.as_deref().ok_or("The n-puzzle needs a --start board")?.parse()?;
+            let goal = match cli.goal.as_deref() {
+                Some(goal) => goal.parse()?,
+                None => Board::goal(start.size()),
+            };
+            let heuristic = match cli.heuristic {
+                HeuristicKind::Misplaced => NPuzzleHeuristic::Misplaced,
+                _ => NPuzzleHeuristic::Manhattan,
+            };
+            let description = format!("npuzzle {} -> {}", start, goal);
+            Ok((description, run_algorithms(cli, NPuzzleProblem::new(start, goal, heuristic)?)?))
+        },
+        ProblemKind::Grid => {
+            check_heuristic(&[HeuristicKind::Manhattan])?;
+            let start = cli.start.as_deref().map(str::parse).transpose()?;
+            let goal = cli.goal.as_deref().map(str::parse).transpose()?;
+            let problem = GridMapProblem::parse(&read_file()?, start, goal)?;
+            let description = format!("grid {}", cli.file.as_ref().unwrap().display());
+            Ok((description, run_algorithms(cli, problem)?))
+        },
+        ProblemKind::Pddl => {
+            check_heuristic(&[HeuristicKind::HMax, HeuristicKind::HAdd, HeuristicKind::HFf, HeuristicKind::IgnoreDeleteLists])?;
+            /* The goal of a planning problem is a set of states, so there is no state to search back from */
+            if cli.algorithm.contains(&Algorithm::Bibf) {
+                return Err(String::from("Bidirectional search needs a single goal state, which a planning problem does not have"));
+            }
+            let domain = read(cli.domain.as_ref().ok_or("A planning problem needs a --domain")?)?;
+            let heuristic = match cli.heuristic {
+                HeuristicKind::HAdd => PlanningHeuristic::Add,
+                HeuristicKind::HFf => PlanningHeuristic::FF,
+                HeuristicKind::IgnoreDeleteLists => PlanningHeuristic::IgnoreDeleteLists,
+                _ => PlanningHeuristic::Max,
+            };
+            let problem = PlanningProblem::parse(&domain, &read_file()?)?.with_heuristic(heuristic);
+            let description = format!("pddl {} ({} ground actions)", problem.name(), problem.action_count());
+            Ok((description, run_algorithms(cli, problem)?))
+        },
+        ProblemKind::Tsp => {
+            check_heuristic(&[])?;
+            /* The actions of a tour can not be undone, so there is no way to search back from its end */
+            if cli.algorithm.contains(&Algorithm::Bibf) {
+                return Err(String::from("Bidirectional search needs reversible actions, which a tour does not have"));
+            }
+            let problem = TspProblem::parse(&read_file()?)?;
+            let description = format!("tsp {} ({} cities)", problem.name(), problem.cities());
+            Ok((description, run_algorithms(cli, problem)?))
+        },
+    }
 }
 
-fn try_algorithm<P, S, A>(problem: &P, name: &str, algorithm: SearchAlgorithm<P, S, A>) 
+fn run_algorithms<P, S, A, C>(cli: &Cli, problem: P) -> Result<Vec<Report>, String>
 where
-    P: Problem<S, A>,
-    S: Clone + Display,
-    A: Clone
+    P: Problem<S, A, C> + Clone + Send + 'static,
+    S: Clone + Eq + Ord + Hash + Debug + Display + Send + 'static,
+    A: Clone + Eq + Ord + Hash + Debug + Send + 'static,
+    C: Cost + Ord + Display + Send + 'static
 {
-    let res = algorithm(problem).expect("No path found");
-    let mut node = Some(Box::new(res));
-    println!("[{}] Optimal path from Arad to Bucharest:", name);
-    while node.is_some() {
-        println!("      {}, cost: {}", node.as_ref().unwrap().state, node.as_ref().unwrap().path_cost);
-        node = node.unwrap().parent;
+    let algorithms = if cli.algorithm.is_empty() && matches!(cli.problem, ProblemKind::Pddl | ProblemKind::Tsp) {
+        Algorithm::value_variants().iter().copied().filter(|a| *a != Algorithm::Bibf).collect()
+    } else if cli.algorithm.is_empty() {
+        Algorithm::value_variants().to_vec()
+    } else {
+        cli.algorithm.clone()
+    };
+    let h: fn(&Cancellable<P>, &Node<S, A, C>) -> C = match cli.heuristic {
+        HeuristicKind::Zero => |_, _| C::zero(),
+        _ => |problem, node| problem.get_heuristic_cost(&node.state),
+    };
+
+    let mut reports = Vec::new();
+    for algorithm in algorithms {
+        let (tx, rx) = mpsc::channel();
+        let problem = problem.clone();
+        let max_depth = cli.max_depth;
+        let mut dot = cli.dot.as_ref().map(|_| DotTracer::new());
+        let mut log = cli.step.then(EventLog::new);
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let problem = Cancellable { problem, cancelled: Arc::clone(&cancelled) };
+
+        /* The search runs on its own thread, so that it can be stopped at the time limit */
+        let search = thread::spawn(move || {
+            let mut statistics = Statistics::default();
+            let start = Instant::now();
+            let mut tracer = ((&mut statistics, &mut dot), &mut log);
+            let result: SearchResult<S, A, C> = match algorithm {
+                Algorithm::Bfs => breadth_first_search_traced(&problem, &mut tracer),
+                Algorithm::Ucs => uniform_cost_search_traced(&problem, &mut tracer),
+                Algorithm::BestFirst => best_first_search_traced(&problem, h, &mut tracer),
+                Algorithm::Astar => astar_search_traced(&problem, h, &mut tracer),
+                Algorithm::IdaStar => ida_star_search_traced(&problem, h, &mut tracer),
+                Algorithm::Ids => iterative_deepening_search_limited(&problem, max_depth, &mut tracer),
+                Algorithm::Bibf => bibf_search_traced(&problem, &mut tracer),
+                Algorithm::Rbfs => recursive_best_first_search_traced(&problem, h, &mut tracer),
+            };
+            let elapsed = start.elapsed();
+            tx.send((result, statistics, dot, log, elapsed)).ok();
+        });
+
+        let received = match cli.time_limit {
+            Some(seconds) => rx.recv_timeout(Duration::from_secs_f64(seconds)).ok(),
+            None => rx.recv().ok(),
+        };
+        /* A search past the time limit winds down once it finds no more actions, and is waited
+           for so that it does not slow down the next one */
+        cancelled.store(true, Ordering::Relaxed);
+        search.join().map_err(|_| format!("{} panicked", algorithm.name()))?;
+        let report = match received {
+            Some((result, statistics, dot, log, elapsed)) => {
+                if let Some(log) = log {
+                    debugger::run(algorithm.name(), &log).map_err(|e| format!("Could not show the search: {}", e))?;
+                }
+                if let (Some(dir), Some(dot)) = (cli.dot.as_ref(), dot) {
+                    let path = dir.join(format!("{}.dot", algorithm.file_name()));
+                    dot.write_dot(&path).map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+                }
+                let outcome = match result {
+                    Ok(node) => Outcome::Solved(path_of(&node)),
+                    Err(e) => Outcome::Failed(format!("{:?}", e)),
+                };
+                Report { algorithm, outcome, statistics, elapsed }
+            },
+            None => Report {
+                algorithm,
+                outcome: Outcome::TimedOut,
+                statistics: Statistics::default(),
+                elapsed: Duration::from_secs_f64(cli.time_limit.unwrap_or(0.)),
+            },
+        };
+        reports.push(report);
     }
+
+    Ok(reports)
+}
+
+/// A problem whose states have no actions once it is cancelled, which ends any search of it
+#[derive(Clone)]
+struct Cancellable<P> {
+    problem: P,
+    cancelled: Arc<AtomicBool>,
 }
 
-fn try_heuristic<P, S, A>(problem: &P, name: &str, algorithm: SearchAlgorithmHeuristic<P, S, A>, h: fn(&P, &Node<S, A>) -> u32)
+impl<P, S, A, C> Problem<S, A, C> for Cancellable<P>
 where
-    P: Problem<S, A>,
-    S: Clone + Display,
-    A: Clone
+    P: Problem<S, A, C>,
+    S: Clone,
+    A: Clone,
+    C: Cost
 {
-    let res = algorithm(problem, h).expect("No Path found");
-    let mut node = Some(Box::new(res));
-    println!("[{}] Optimal path from Arad to Bucharest:", name);
-    while node.is_some() {
-        println!("      {}, cost: {}", node.as_ref().unwrap().state, node.as_ref().unwrap().path_cost);
+    fn is_goal(&self, state: &S) -> bool {
+        self.problem.is_goal(state)
+    }
+
+    fn actions(&self, state: &S) -> Vec<A> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return Vec::new();
+        }
+        self.problem.actions(state)
+    }
 
-        node = node.unwrap().parent;
+    fn result(&self, state: &S, action: &A) -> S {
+        self.problem.result(state, action)
+    }
+
+    fn action_cost(&self, state: &S, action: &A, new_state: &S) -> C {
+        self.problem.action_cost(state, action, new_state)
+    }
+
+    fn get_initial_node(&self) -> Node<S, A, C> {
+        self.problem.get_initial_node()
+    }
+
+    fn get_goal_node(&self) -> Node<S, A, C> {
+        self.problem.get_goal_node()
+    }
+
+    fn get_heuristic_cost(&self, state: &S) -> C {
+        self.problem.get_heuristic_cost(state)
+    }
+
+    fn get_goal_nodes(&self) -> Vec<Node<S, A, C>> {
+        self.problem.get_goal_nodes()
     }
-    
 }
 
-fn h<P, S, A>(problem: &P, node: &Node<S, A>) -> u32
+fn path_of<S, A, C>(node: &Node<S, A, C>) -> Vec<Step>
 where
-    P: Problem<S, A>,
-    S: Clone,
-    A: Clone
+    S: Display,
+    A: Debug,
+    C: Display
 {
-    problem.get_heuristic_cost(&node.state)
+    let mut steps = Vec::new();
+    let mut n = Some(node);
+    while let Some(current) = n {
+        steps.push(Step {
+            state: current.state.to_string(),
+            action: current.action.as_ref().map(|a| format!("{:?}", a)),
+            path_cost: current.path_cost.to_string(),
+        });
+        n = current.parent.as_deref();
+    }
+    steps.reverse();
+    steps
+}
+
+fn format_table(description: &str, reports: &[Report]) -> String {
+    let mut out = String::new();
+    writeln!(out, "Problem: {}", description).unwrap();
+
+    for report in reports {
+        if let Outcome::Solved(path) = &report.outcome {
+            writeln!(out).unwrap();
+            writeln!(out, "[{}] Path:", report.algorithm.name()).unwrap();
+            let width = path.iter().map(|s| s.state.len()).max().unwrap_or(0).max(5);
+            writeln!(out, "    {:>4}  {:<width$}  {:<20}  {:>10}", "#", "State", "Action", "Path-Cost", width = width).unwrap();
+            for (i, step) in path.iter().enumerate() {
+                let action = step.action.as_deref().unwrap_or("-");
+                writeln!(out, "    {:>4}  {:<width$}  {:<20}  {:>10}", i, step.state, action, step.path_cost, width = width).unwrap();
+            }
+        }
+    }
+
+    writeln!(out).unwrap();
+    writeln!(out, "{:<32} | {:<9} | {:>10} | {:>6} | {:>10} | {:>10} | {:>10} | {:>9} | {:>10}",
+        "Algorithm", "Result", "Cost", "Length", "Generated", "Expanded", "Pruned", "Max depth", "Time (ms)").unwrap();
+    writeln!(out, "{}", "-".repeat(32 + 9 + 10 + 6 + 10 * 4 + 9 + 8 * 3)).unwrap();
+    for report in reports {
+        let (result, cost, length) = match &report.outcome {
+            Outcome::Solved(path) => ("solved", path.last().unwrap().path_cost.clone(), (path.len() - 1).to_string()),
+            Outcome::Failed(e) => (if e == "CutOffReached" { "cutoff" } else { "failure" }, String::from("-"), String::from("-")),
+            Outcome::TimedOut => ("timeout", String::from("-"), String::from("-")),
+        };
+        let s = &report.statistics;
+        writeln!(out, "{:<32} | {:<9} | {:>10} | {:>6} | {:>10} | {:>10} | {:>10} | {:>9} | {:>10.3}",
+            report.algorithm.name(), result, cost, length, s.generated, s.expanded, s.pruned, s.max_depth,
+            report.elapsed.as_secs_f64() * 1000.).unwrap();
+    }
+    out
+}
+
+fn format_json(description: &str, reports: &[Report]) -> String {
+    let mut runs = Vec::new();
+    for report in reports {
+        let s = &report.statistics;
+        let mut run = format!("{{\"algorithm\":{},", json_string(report.algorithm.name()));
+        match &report.outcome {
+            Outcome::Solved(path) => {
+                let steps: Vec<String> = path.iter().map(|step| format!(
+                    "{{\"state\":{},\"action\":{},\"path_cost\":{}}}",
+                    json_string(&step.state),
+                    step.action.as_deref().map(json_string).unwrap_or_else(|| String::from("null")),
+                    json_number(&step.path_cost)
+                )).collect();
+                write!(run, "\"result\":\"solved\",\"cost\":{},\"length\":{},\"path\":[{}],",
+                    json_number(&path.last().unwrap().path_cost), path.len() - 1, steps.join(",")).unwrap();
+            },
+            Outcome::Failed(e) => write!(run, "\"result\":\"failure\",\"error\":{},", json_string(e)).unwrap(),
+            Outcome::TimedOut => run.push_str("\"result\":\"timeout\","),
+        }
+        write!(run, "\"generated\":{},\"expanded\":{},\"pruned\":{},\"max_depth\":{},\"time_ms\":{}}}",
+            s.generated, s.expanded, s.pruned, s.max_depth, report.elapsed.as_secs_f64() * 1000.).unwrap();
+        runs.push(run);
+    }
+    format!("{{\"problem\":{},\"runs\":[{}]}}", json_string(description), runs.join(","))
+}
+
+/* JSON has no literal for an infinite or NaN cost, so those are written as null */
+fn json_number(s: &str) -> String {
+    match s.parse::<f64>() {
+        Ok(x) if x.is_finite() => String::from(s),
+        _ => String::from("null"),
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
 }
@@ -0,0 +1,79 @@
+use num::Bounded;
+
+use search::{
+    bibf_search,
+    breadth_first_search,
+    iterative_deepening_search,
+    recursive_best_first_search,
+    uniform_cost_search,
+    AradToBucharestProblem,
+    Cost,
+    GraphProblem,
+    Node,
+    OrderedFloat,
+    Problem,
+    SearchError,
+};
+
+fn h<P, S, A, C>(problem: &P, node: &Node<S, A, C>) -> C
+where
+    P: Problem<S, A, C>,
+    S: Clone,
+    A: Clone,
+    C: Cost
+{
+    problem.get_heuristic_cost(&node.state)
+}
+
+#[test]
+fn graphs_can_have_fractional_distances() {
+    let graph = "
+edge a b 0.5
+edge b c 1.25
+edge a c 2
+start a
+goal c
+";
+    let problem: GraphProblem<OrderedFloat<f64>> = GraphProblem::parse(graph, None, None).unwrap();
+    let solution = recursive_best_first_search(&problem, h).unwrap();
+    assert_eq!(solution.path_cost, OrderedFloat(1.75));
+    assert_eq!(solution.parent.unwrap().state, "b");
+}
+
+#[test]
+fn romania_works_with_other_cost_types() {
+    let problem: AradToBucharestProblem<u64> = AradToBucharestProblem::arad_to_bucharest();
+    assert_eq!(recursive_best_first_search(&problem, h).unwrap().path_cost, 418);
+
+    let problem: AradToBucharestProblem<OrderedFloat<f64>> = AradToBucharestProblem::arad_to_bucharest();
+    assert_eq!(recursive_best_first_search(&problem, h).unwrap().path_cost, OrderedFloat(418.));
+}
+
+#[test]
+fn overflowing_path_costs_are_errors() {
+    let graph = "
+edge a b 200
+edge b c 100
+start a
+goal c
+";
+    let problem: GraphProblem<u8> = GraphProblem::parse(graph, None, None).unwrap();
+    assert_eq!(breadth_first_search(&problem), Err(SearchError::CostOverflow));
+    assert_eq!(uniform_cost_search(&problem), Err(SearchError::CostOverflow));
+    assert_eq!(iterative_deepening_search(&problem), Err(SearchError::CostOverflow));
+    assert_eq!(recursive_best_first_search(&problem, h), Err(SearchError::CostOverflow));
+    assert_eq!(bibf_search(&problem), Err(SearchError::CostOverflow));
+
+    let problem: GraphProblem<OrderedFloat<f64>> = GraphProblem::parse(&graph.replace("200", "1e308").replace("100", "1e308"), None, None).unwrap();
+    assert_eq!(breadth_first_search(&problem), Err(SearchError::CostOverflow));
+}
+
+#[test]
+fn ordered_floats_are_totally_ordered() {
+    let mut costs = [OrderedFloat(f64::NAN), OrderedFloat(2.), OrderedFloat(f64::INFINITY), OrderedFloat(-1.)];
+    costs.sort();
+    assert_eq!(costs[..3], [OrderedFloat(-1.), OrderedFloat(2.), OrderedFloat(f64::INFINITY)]);
+    assert!(costs[3].0.is_nan());
+    assert_eq!(OrderedFloat(f64::NAN), OrderedFloat(f64::NAN));
+    assert_eq!(OrderedFloat::<f64>::max_value(), OrderedFloat(f64::INFINITY));
+}
@@ -0,0 +1,107 @@
+use search::{
+    astar_search_traced,
+    best_first_search_traced,
+    bibf_search_traced,
+    AradToBucharestAction,
+    AradToBucharestProblem,
+    Event,
+    EventLog,
+    Node,
+    Problem,
+    Snapshot,
+};
+
+type RomaniaNode = Node<&'static str, AradToBucharestAction>;
+
+fn straight_line_h(problem: &AradToBucharestProblem, node: &RomaniaNode) -> u32 {
+    problem.get_heuristic_cost(&node.state)
+}
+
+fn a_star_f(problem: &AradToBucharestProblem, node: &RomaniaNode) -> u32 {
+    node.path_cost + problem.get_heuristic_cost(&node.state)
+}
+
+fn frontier(log: &EventLog<&'static str>, snapshot: &Snapshot, direction: usize) -> Vec<(&'static str, u32)> {
+    snapshot.frontiers[direction].iter().map(|id| (log.node(*id).state, log.node(*id).f)).collect()
+}
+
+fn reached(log: &EventLog<&'static str>, snapshot: &Snapshot, direction: usize) -> Vec<(&'static str, u32)> {
+    snapshot.reached[direction].iter().map(|id| (log.node(*id).state, log.node(*id).path_cost)).collect()
+}
+
+#[test]
+fn astar_replay_matches_the_aima_figure() {
+    let problem = AradToBucharestProblem::new("Arad", "Bucharest").unwrap();
+    let mut log = EventLog::new();
+    let solution = astar_search_traced(&problem, straight_line_h, &mut log).unwrap();
+    let snapshots = log.snapshots();
+
+    assert_eq!(frontier(&log, &snapshots[0], 0), [("Arad", 366)]);
+    assert_eq!(snapshots[0].expanded, None);
+    assert_eq!(frontier(&log, &snapshots[1], 0), [("Sibiu", 393), ("Timisoara", 447), ("Zerind", 449)]);
+    /* Going back to Arad is pruned, it was reached at a lower cost */
+    assert_eq!(
+        frontier(&log, &snapshots[2], 0),
+        [("Rimnicu Vilcea", 413), ("Fagaras", 415), ("Timisoara", 447), ("Zerind", 449), ("Oradea", 671)]
+    );
+    let arad = snapshots[2].generated.iter().find(|(id, _)| log.node(*id).state == "Arad").unwrap();
+    assert!(!arad.1);
+    assert_eq!(log.node(snapshots[2].expanded.unwrap()).state, "Sibiu");
+    assert_eq!(frontier(&log, &snapshots[3], 0)[..2], [("Fagaras", 415), ("Pitesti", 417)]);
+
+    /* One snapshot before the search, one per expansion and one for the solution */
+    let expansions = log.events().iter().filter(|e| matches!(e, Event::Expanded(_))).count();
+    assert_eq!(snapshots.len(), expansions + 2);
+    let last = snapshots.last().unwrap();
+    let found = last.solution.unwrap();
+    assert_eq!(log.node(found).path_cost, solution.path_cost);
+    let path: Vec<_> = log.path(found).iter().map(|id| log.node(*id).state).collect();
+    assert_eq!(path, ["Arad", "Sibiu", "Rimnicu Vilcea", "Pitesti", "Bucharest"]);
+}
+
+#[test]
+fn reached_keeps_the_cheapest_node_of_each_state() {
+    let problem = AradToBucharestProblem::new("Arad", "Bucharest").unwrap();
+    let mut log = EventLog::new();
+    best_first_search_traced(&problem, a_star_f, &mut log).unwrap();
+    let snapshots = log.snapshots();
+    let last = snapshots.last().unwrap();
+
+    /* Bucharest is first reached through Fagaras (450), then through Pitesti (418) */
+    let bucharest: Vec<_> = reached(&log, last, 0).into_iter().filter(|(s, _)| *s == "Bucharest").collect();
+    assert_eq!(bucharest, [("Bucharest", 418)]);
+    assert!(snapshots.iter().any(|s| reached(&log, s, 0).contains(&("Bucharest", 450))));
+}
+
+#[test]
+fn greedy_replay_follows_the_heuristic() {
+    let problem = AradToBucharestProblem::new("Arad", "Bucharest").unwrap();
+    let mut log = EventLog::new();
+    best_first_search_traced(&problem, straight_line_h, &mut log).unwrap();
+    let expanded: Vec<_> = log.snapshots().iter()
+        .filter_map(|s| s.expanded)
+        .map(|id| log.node(id).state)
+        .collect();
+    assert_eq!(expanded, ["Arad", "Sibiu", "Fagaras"]);
+}
+
+#[test]
+fn bidirectional_replay_has_both_directions() {
+    let problem = AradToBucharestProblem::new("Arad", "Bucharest").unwrap();
+    let mut log = EventLog::new();
+    let solution = bibf_search_traced(&problem, &mut log).unwrap();
+    let snapshots = log.snapshots();
+
+    assert_eq!(log.directions(), 2);
+    assert_eq!(frontier(&log, &snapshots[0], 0), [("Arad", 366)]);
+    assert_eq!(frontier(&log, &snapshots[0], 1), [("Bucharest", 0)]);
+    for snapshot in &snapshots[1..] {
+        let expanded = snapshot.expanded.or(snapshot.solution).unwrap();
+        assert!(log.path(expanded).iter().all(|id| log.node(*id).direction == log.node(expanded).direction));
+    }
+    /* Both trees grow, from Arad and from Bucharest */
+    let last = snapshots.last().unwrap();
+    assert!(reached(&log, last, 0).contains(&("Sibiu", 140)));
+    assert!(reached(&log, last, 1).contains(&("Pitesti", 101)));
+    assert_eq!(log.node(last.solution.unwrap()).path_cost, solution.path_cost);
+}
@@ -0,0 +1,162 @@
+use search::{
+    astar_search,
+    breadth_first_search,
+    ida_star_search,
+    recursive_best_first_search,
+    FactSet,
+    Node,
+    PlanningAction,
+    PlanningHeuristic,
+    PlanningProblem,
+    Problem,
+};
+
+const BLOCKSWORLD: &str = include_str!("../fixtures/planning/blocksworld-domain.pddl");
+const SUSSMAN: &str = include_str!("../fixtures/planning/blocksworld-sussman.pddl");
+const REVERSE: &str = include_str!("../fixtures/planning/blocksworld-reverse.pddl");
+const LOGISTICS: &str = include_str!("../fixtures/planning/logistics-domain.pddl");
+const TWO_CITIES: &str = include_str!("../fixtures/planning/logistics-two-cities.pddl");
+
+fn h(problem: &PlanningProblem, node: &Node<FactSet, PlanningAction>) -> u32 {
+    problem.get_heuristic_cost(&node.state)
+}
+
+fn plan(node: &Node<FactSet, PlanningAction>) -> Vec<String> {
+    let mut actions = Vec::new();
+    let mut current = node;
+    while let Some(parent) = &current.parent {
+        actions.push(current.action.as_ref().unwrap().name().to_string());
+        current = parent;
+    }
+    actions.reverse();
+    actions
+}
+
+/// Replays the plan from the initial state, and checks that it reaches the goal
+fn check_plan(problem: &PlanningProblem, plan: &[String]) {
+    let steps: Vec<&str> = plan.iter().map(String::as_str).collect();
+    let end = problem.execute(&steps).unwrap();
+    assert!(problem.is_goal(&end), "{:?} ends in {}", plan, end);
+}
+
+#[test]
+fn grounding_blocksworld() {
+    let problem = PlanningProblem::parse(BLOCKSWORLD, SUSSMAN).unwrap();
+    assert_eq!(problem.name(), "sussman");
+    let initial = problem.get_initial_node().state;
+    assert!(initial.contains("(on c a)"));
+    assert!(initial.contains("(handempty)"));
+    assert_eq!(problem.goal().collect::<Vec<_>>(), ["(on a b)", "(on b c)"]);
+    /* The domain does not rule out (stack a a), so stack and unstack have 3 x 3 groundings */
+    assert_eq!(problem.action_count(), 3 + 3 + 9 + 9);
+}
+
+#[test]
+fn sussman_anomaly_takes_six_steps() {
+    let problem = PlanningProblem::parse(BLOCKSWORLD, SUSSMAN).unwrap();
+    let bfs = plan(&breadth_first_search(&problem).unwrap());
+    let astar = plan(&astar_search(&problem, h).unwrap());
+
+    assert_eq!(bfs.len(), 6);
+    assert_eq!(astar.len(), 6);
+    check_plan(&problem, &bfs);
+    check_plan(&problem, &astar);
+    assert_eq!(astar[0], "(unstack c a)");
+}
+
+#[test]
+fn admissible_heuristics_find_optimal_plans() {
+    for (domain, task, length) in [(BLOCKSWORLD, REVERSE, 10), (LOGISTICS, TWO_CITIES, 16)] {
+        for heuristic in [PlanningHeuristic::Zero, PlanningHeuristic::Max] {
+            let problem = PlanningProblem::parse(domain, task).unwrap().with_heuristic(heuristic);
+            let solution = astar_search(&problem, h).unwrap();
+            check_plan(&problem, &plan(&solution));
+            assert_eq!(solution.path_cost, length, "{} with {:?}", problem.name(), heuristic);
+        }
+    }
+}
+
+#[test]
+fn inadmissible_heuristics_still_find_plans() {
+    for (domain, task) in [(BLOCKSWORLD, REVERSE), (LOGISTICS, TWO_CITIES)] {
+        for heuristic in [PlanningHeuristic::Add, PlanningHeuristic::FF] {
+            let problem = PlanningProblem::parse(domain, task).unwrap().with_heuristic(heuristic);
+            check_plan(&problem, &plan(&astar_search(&problem, h).unwrap()));
+        }
+    }
+}
+
+#[test]
+fn relaxed_heuristics_are_ordered() {
+    for (domain, task) in [(BLOCKSWORLD, SUSSMAN), (BLOCKSWORLD, REVERSE), (LOGISTICS, TWO_CITIES)] {
+        let problem = PlanningProblem::parse(domain, task).unwrap();
+        let initial = problem.get_initial_node().state;
+        let value = |heuristic| problem.heuristic_value(heuristic, &initial);
+
+        let h_max = value(PlanningHeuristic::Max);
+        let h_plus = value(PlanningHeuristic::IgnoreDeleteLists);
+        let h_ff = value(PlanningHeuristic::FF);
+        let h_add = value(PlanningHeuristic::Add);
+        assert!(0 < h_max && h_max <= h_plus && h_plus <= h_ff && h_ff <= h_add,
+            "{}: h_max {}, h+ {}, h_FF {}, h_add {}", problem.name(), h_max, h_plus, h_ff, h_add);
+    }
+}
+
+#[test]
+fn sussman_heuristic_values() {
+    let problem = PlanningProblem::parse(BLOCKSWORLD, SUSSMAN).unwrap();
+    let initial = problem.get_initial_node().state;
+    let value = |heuristic| problem.heuristic_value(heuristic, &initial);
+    /* (on b c) needs pick-up b and stack b c; (on a b) needs unstack c a too */
+    assert_eq!(value(PlanningHeuristic::Max), 3);
+    assert_eq!(value(PlanningHeuristic::IgnoreDeleteLists), 5);
+}
+
+#[test]
+fn unreachable_goal_fails() {
+    /* Without (handempty) the arm can not move */
+    let task = "(define (problem stuck) (:domain blocksworld) (:objects a - block)
+        (:init (ontable a) (clear a)) (:goal (on a a)))";
+    let problem = PlanningProblem::parse(BLOCKSWORLD, task).unwrap();
+    assert_eq!(problem.get_heuristic_cost(&problem.get_initial_node().state), u32::MAX);
+    assert!(breadth_first_search(&problem).is_err());
+}
+
+#[test]
+fn dead_ends_are_pruned_rather_than_overflowing() {
+    /* Falling into the trap leaves no way to the goal, so every heuristic is u32::MAX there */
+    let domain = "(define (domain trap) (:predicates (start) (trapped) (halfway) (done))
+        (:action fall :parameters () :precondition (start) :effect (and (trapped) (not (start))))
+        (:action walk :parameters () :precondition (start) :effect (and (halfway) (not (start))))
+        (:action arrive :parameters () :precondition (halfway) :effect (and (done) (not (halfway)))))";
+    let task = "(define (problem trap) (:domain trap) (:init (start)) (:goal (done)))";
+    for heuristic in [PlanningHeuristic::Max, PlanningHeuristic::Add, PlanningHeuristic::FF] {
+        let problem = PlanningProblem::parse(domain, task).unwrap().with_heuristic(heuristic);
+        let trapped = problem.execute(&["(fall)"]).unwrap();
+        assert_eq!(problem.get_heuristic_cost(&trapped), u32::MAX);
+        for solution in [recursive_best_first_search(&problem, h), ida_star_search(&problem, h), astar_search(&problem, h)] {
+            assert_eq!(plan(&solution.unwrap()), ["(walk)", "(arrive)"], "{:?}", heuristic);
+        }
+    }
+}
+
+#[test]
+fn malformed_pddl_is_rejected() {
+    let errors = [
+        (BLOCKSWORLD, "(define (problem p) (:domain blocksworld) (:objects a - block) (:init (ontable a)) (:goal (on a))"),
+        (BLOCKSWORLD, "(define (problem p) (:domain blocksworld) (:objects a - block) (:init (ontable a)) (:goal (on a)))"),
+        (BLOCKSWORLD, "(define (problem p) (:domain logistics) (:objects a - block) (:init) (:goal (ontable a)))"),
+        (BLOCKSWORLD, "(define (problem p) (:domain blocksworld) (:objects a - ball) (:init) (:goal (ontable a)))"),
+        (BLOCKSWORLD, "(define (problem p) (:domain blocksworld) (:objects a - block) (:init) (:goal (ontable b)))"),
+        ("(define (domain d) (:requirements :adl))", "(define (problem p) (:domain d))"),
+        ("(define (domain d) (:predicates (p ?x)) (:action a :parameters (?x) :precondition (not (p ?x)) :effect (p ?x)))",
+            "(define (problem p) (:domain d))"),
+        ("(define (domain d) (:predicates (p ?x)) (:action a :parameters () :precondition (and) :effect (p ?y)))",
+            "(define (problem p) (:domain d))"),
+        ("(define (domain d) ())", "(define (problem p) (:domain d))"),
+        ("(define (domain d))", "(define (problem p) (:domain d) ())"),
+    ];
+    for (domain, task) in errors {
+        assert!(PlanningProblem::parse(domain, task).is_err(), "{}\n{}", domain, task);
+    }
+}
@@ -0,0 +1,92 @@
+use search::{
+    bibf_search,
+    breadth_first_search,
+    recursive_best_first_search,
+    Board,
+    Cell,
+    GraphProblem,
+    GridMapProblem,
+    NPuzzleHeuristic,
+    NPuzzleProblem,
+    Node,
+    Problem,
+};
+
+fn h<P: Problem<S, A>, S: Clone, A: Clone>(problem: &P, node: &Node<S, A>) -> u32 {
+    problem.get_heuristic_cost(&node.state)
+}
+
+const GRAPH: &str = "
+# A square with a cheap detour
+edge a b 1
+edge b d 1
+edge a c 5
+edge c d 1
+arc d e 1
+h a 2
+start a
+goal e
+";
+
+#[test]
+fn graph_files_are_parsed() {
+    let problem = GraphProblem::parse(GRAPH, None, None).unwrap();
+    assert_eq!(problem.nodes().count(), 5);
+    let solution = recursive_best_first_search(&problem, h).unwrap();
+    assert_eq!(solution.state, "e");
+    assert_eq!(solution.path_cost, 3);
+
+    /* e has no arc back, so nothing is reachable from it */
+    let problem = GraphProblem::<u32>::parse(GRAPH, Some("e"), Some("a")).unwrap();
+    assert!(breadth_first_search(&problem).is_err());
+}
+
+#[test]
+fn malformed_graph_files_are_rejected() {
+    assert!(GraphProblem::<u32>::parse("edge a b", Some("a"), Some("b")).is_err());
+    assert!(GraphProblem::<u32>::parse("edge a b 1", None, Some("b")).is_err());
+    assert!(GraphProblem::<u32>::parse("edge a b 1", Some("a"), Some("z")).is_err());
+}
+
+#[test]
+fn grid_maps_route_around_walls() {
+    let map = "\
+S.#.
+..#G
+....";
+    let problem = GridMapProblem::parse(map, None, None).unwrap();
+    let solution = recursive_best_first_search(&problem, h).unwrap();
+    assert_eq!(solution.state, Cell { x: 3, y: 1 });
+    assert_eq!(solution.path_cost, 6);
+    assert!(GridMapProblem::parse(map, Some(Cell { x: 2, y: 0 }), None).is_err());
+}
+
+#[test]
+fn eight_puzzle_is_solved_optimally() {
+    let start: Board = "1 2 5 3 4 0 6 7 8".parse().unwrap();
+    let problem = NPuzzleProblem::new(start, Board::goal(3), NPuzzleHeuristic::Manhattan).unwrap();
+    let solution = recursive_best_first_search(&problem, h).unwrap();
+    assert_eq!(solution.state, Board::goal(3));
+    assert_eq!(solution.path_cost, 3);
+}
+
+#[test]
+fn unsolvable_boards_are_rejected() {
+    let swapped: Board = "0 2 1 3 4 5 6 7 8".parse().unwrap();
+    assert!(!swapped.can_reach(&Board::goal(3)));
+    assert!(NPuzzleProblem::new(swapped, Board::goal(3), NPuzzleHeuristic::Misplaced).is_err());
+    assert!("1 2 3".parse::<Board>().is_err());
+}
+
+#[test]
+fn graph_with_several_goals() {
+    let text = "edge a b 1\nedge b c 1\nedge a d 5\nstart a\ngoal c d\n";
+    let problem = GraphProblem::<u32>::parse(text, None, None).unwrap();
+    assert!(problem.is_goal(&String::from("c")) && problem.is_goal(&String::from("d")));
+    assert_eq!(problem.get_goal_nodes().len(), 2);
+    assert_eq!(bibf_search(&problem).unwrap().state, "c");
+
+    let problem = GraphProblem::<u32>::parse(text, None, Some("d, b")).unwrap();
+    assert_eq!(bibf_search(&problem).unwrap().path_cost, 1);
+    assert!(GraphProblem::<u32>::parse(text, None, Some("c,x")).is_err());
+}
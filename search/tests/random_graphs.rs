@@ -0,0 +1,206 @@
+use std::collections::VecDeque;
+use std::fmt::{Debug, Formatter};
+
+use proptest::prelude::*;
+use proptest::test_runner::RngSeed;
+
+use search::{
+    astar_search,
+    bibf_search,
+    breadth_first_search,
+    ida_star_search,
+    iterative_deepening_search,
+    recursive_best_first_search,
+    uniform_cost_search,
+    GraphAction,
+    GraphProblem,
+    GraphState,
+    Node,
+    Problem,
+};
+
+const MAX_NODES: usize = 8;
+const MAX_COST: u32 = 20;
+
+/// A connected, undirected graph on the nodes 0..nodes, with one or more goals. The heuristic of
+/// a node is the given percentage of its true distance to the nearest goal, so it is admissible.
+#[derive(Clone)]
+struct RandomGraph {
+    nodes: usize,
+    edges: Vec<(usize, usize, u32)>,
+    h_percent: Vec<u32>,
+    start: usize,
+    goals: Vec<usize>,
+}
+
+impl RandomGraph {
+    /// Cheapest cost between every pair of nodes (Floyd-Warshall)
+    fn distances(&self) -> Vec<Vec<Option<u32>>> {
+        let mut d = vec![vec![None; self.nodes]; self.nodes];
+        for (i, row) in d.iter_mut().enumerate() {
+            row[i] = Some(0);
+        }
+        for &(u, v, c) in &self.edges {
+            for (a, b) in [(u, v), (v, u)] {
+                if d[a][b].is_none_or(|old| c < old) {
+                    d[a][b] = Some(c);
+                }
+            }
+        }
+        for k in 0..self.nodes {
+            for i in 0..self.nodes {
+                for j in 0..self.nodes {
+                    if let (Some(ik), Some(kj)) = (d[i][k], d[k][j]) {
+                        if d[i][j].is_none_or(|ij| ik + kj < ij) {
+                            d[i][j] = Some(ik + kj);
+                        }
+                    }
+                }
+            }
+        }
+        d
+    }
+
+    /// Cheapest cost from the start to a goal
+    fn optimal_cost(&self) -> u32 {
+        let distances = self.distances();
+        self.goals.iter().map(|g| distances[self.start][*g].unwrap()).min().unwrap()
+    }
+
+    /// Fewest edges from the start to a goal
+    fn min_edges(&self) -> usize {
+        let mut depth = vec![None; self.nodes];
+        depth[self.start] = Some(0);
+        let mut queue = VecDeque::from([self.start]);
+        while let Some(u) = queue.pop_front() {
+            for &(a, b, _) in &self.edges {
+                for (from, to) in [(a, b), (b, a)] {
+                    if from == u && depth[to].is_none() {
+                        depth[to] = Some(depth[u].unwrap() + 1);
+                        queue.push_back(to);
+                    }
+                }
+            }
+        }
+        self.goals.iter().map(|g| depth[*g].unwrap()).min().unwrap()
+    }
+
+    fn to_graph_file(&self) -> String {
+        let distances = self.distances();
+        let mut file = String::new();
+        for &(u, v, c) in &self.edges {
+            file.push_str(&format!("edge n{} n{} {}\n", u, v, c));
+        }
+        for (u, percent) in self.h_percent.iter().enumerate() {
+            let nearest = self.goals.iter().map(|g| distances[u][*g].unwrap()).min().unwrap();
+            let h = nearest * percent / 100;
+            file.push_str(&format!("h n{} {}\n", u, h));
+        }
+        let goals: Vec<String> = self.goals.iter().map(|g| format!("n{}", g)).collect();
+        file.push_str(&format!("start n{}\ngoal {}\n", self.start, goals.join(" ")));
+        file
+    }
+
+    fn problem(&self) -> GraphProblem {
+        GraphProblem::parse(&self.to_graph_file(), None, None).unwrap()
+    }
+}
+
+/* A failing case is shown as the graph file, which can be run with the command-line tool */
+impl Debug for RandomGraph {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "\n{}", self.to_graph_file())
+    }
+}
+
+fn random_graph() -> impl Strategy<Value = RandomGraph> {
+    (2..=MAX_NODES).prop_flat_map(|nodes| {
+        /* A random spanning tree keeps the graph connected, the extra edges add cycles */
+        let tree = (1..nodes)
+            .map(|v| (0..v, 1..=MAX_COST).prop_map(move |(u, c)| (u, v, c)))
+            .collect::<Vec<_>>();
+        let extra = prop::collection::vec((0..nodes, 0..nodes, 1..=MAX_COST), 0..2 * nodes);
+        let h_percent = prop::collection::vec(0..=100u32, nodes);
+        let goals = prop::collection::btree_set(0..nodes, 1..=nodes.min(3));
+        (tree, extra, h_percent, 0..nodes, goals).prop_map(move |(tree, extra, h_percent, start, goals)| {
+            let edges = tree.into_iter()
+                .chain(extra.into_iter().filter(|(u, v, _)| u != v))
+                .collect();
+            RandomGraph { nodes, edges, h_percent, start, goals: goals.into_iter().collect() }
+        })
+    })
+}
+
+fn h(problem: &GraphProblem, node: &Node<GraphState, GraphAction>) -> u32 {
+    problem.get_heuristic_cost(&node.state)
+}
+
+/// Checks that the solution is a path from the start to the goal and that its path cost is
+/// the sum of its arcs, returning that cost
+fn checked_cost(problem: &GraphProblem, solution: &Node<GraphState, GraphAction>) -> u32 {
+    assert!(problem.is_goal(&solution.state));
+    let mut cost = 0;
+    let mut node = solution;
+    while let Some(parent) = &node.parent {
+        let action = node.action.as_ref().unwrap();
+        assert!(problem.actions(&parent.state).contains(action));
+        assert_eq!(problem.result(&parent.state, action), node.state);
+        cost += problem.action_cost(&parent.state, action, &node.state);
+        node = parent;
+    }
+    assert_eq!(node.state, problem.get_initial_node().state);
+    assert_eq!(cost, solution.path_cost);
+    cost
+}
+
+fn depth(node: &Node<GraphState, GraphAction>) -> usize {
+    let mut depth = 0;
+    let mut n = node;
+    while let Some(parent) = &n.parent {
+        depth += 1;
+        n = parent;
+    }
+    depth
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 256,
+        rng_seed: RngSeed::Fixed(0x5EA2C4),
+        ..ProptestConfig::default()
+    })]
+
+    #[test]
+    fn optimal_searches_agree_on_the_cost(graph in random_graph()) {
+        let problem = graph.problem();
+        let optimal = graph.optimal_cost();
+
+        let ucs = uniform_cost_search(&problem).unwrap();
+        let astar = astar_search(&problem, h).unwrap();
+        let bibf = bibf_search(&problem).unwrap();
+        let rbfs = recursive_best_first_search(&problem, h).unwrap();
+        let ida_star = ida_star_search(&problem, h).unwrap();
+
+        prop_assert_eq!(checked_cost(&problem, &ucs), optimal, "uniform-cost search");
+        prop_assert_eq!(checked_cost(&problem, &astar), optimal, "A*");
+        prop_assert_eq!(checked_cost(&problem, &bibf), optimal, "bidirectional search");
+        prop_assert_eq!(checked_cost(&problem, &rbfs), optimal, "RBFS");
+        prop_assert_eq!(checked_cost(&problem, &ida_star), optimal, "IDA*");
+    }
+
+    #[test]
+    fn breadth_first_uses_the_fewest_edges(graph in random_graph()) {
+        let problem = graph.problem();
+        let solution = breadth_first_search(&problem).unwrap();
+        checked_cost(&problem, &solution);
+        prop_assert_eq!(depth(&solution), graph.min_edges());
+    }
+
+    #[test]
+    fn iterative_deepening_uses_the_fewest_edges(graph in random_graph()) {
+        let problem = graph.problem();
+        let solution = iterative_deepening_search(&problem).unwrap();
+        checked_cost(&problem, &solution);
+        prop_assert_eq!(depth(&solution), graph.min_edges());
+    }
+}
@@ -0,0 +1,166 @@
+use search::{
+    astar_search,
+    bibf_search,
+    breadth_first_search,
+    ida_star_search,
+    iterative_deepening_search,
+    recursive_best_first_search,
+    uniform_cost_search,
+    AradToBucharestAction,
+    AradToBucharestProblem,
+    Node,
+    Problem,
+    Statistics,
+    breadth_first_search_traced,
+};
+
+fn path<S: Clone, A>(node: &Node<S, A>) -> Vec<S> {
+    let mut states = vec![node.state.clone()];
+    let mut current = node;
+    while let Some(parent) = &current.parent {
+        states.push(parent.state.clone());
+        current = parent;
+    }
+    states.reverse();
+    states
+}
+
+fn straight_line_h(problem: &AradToBucharestProblem, node: &Node<&'static str, AradToBucharestAction>) -> u32 {
+    problem.get_heuristic_cost(&node.state)
+}
+
+#[test]
+fn breadth_first_finds_the_shallowest_route() {
+    let problem = AradToBucharestProblem::new("Arad", "Bucharest").unwrap();
+    let solution = breadth_first_search(&problem).unwrap();
+    assert_eq!(path(&solution), ["Arad", "Sibiu", "Fagaras", "Bucharest"]);
+    assert_eq!(solution.path_cost, 450);
+}
+
+#[test]
+fn rbfs_finds_the_optimal_route() {
+    let problem = AradToBucharestProblem::new("Arad", "Bucharest").unwrap();
+    let solution = recursive_best_first_search(&problem, straight_line_h).unwrap();
+    assert_eq!(path(&solution), ["Arad", "Sibiu", "Rimnicu Vilcea", "Pitesti", "Bucharest"]);
+    assert_eq!(solution.path_cost, 418);
+}
+
+#[test]
+fn optimal_searches_find_the_route_via_pitesti() {
+    let problem = AradToBucharestProblem::new("Arad", "Bucharest").unwrap();
+    let route = ["Arad", "Sibiu", "Rimnicu Vilcea", "Pitesti", "Bucharest"];
+    for solution in [
+        uniform_cost_search(&problem).unwrap(),
+        astar_search(&problem, straight_line_h).unwrap(),
+        ida_star_search(&problem, straight_line_h).unwrap(),
+        bibf_search(&problem).unwrap(),
+    ] {
+        assert_eq!(path(&solution), route);
+        assert_eq!(solution.path_cost, 418);
+    }
+}
+
+#[test]
+fn iterative_deepening_finds_the_shallowest_route() {
+    let problem = AradToBucharestProblem::new("Arad", "Bucharest").unwrap();
+    let solution = iterative_deepening_search(&problem).unwrap();
+    assert_eq!(path(&solution), ["Arad", "Sibiu", "Fagaras", "Bucharest"]);
+    assert_eq!(solution.path_cost, 450);
+
+    let problem = AradToBucharestProblem::new("Arad", "Arad").unwrap();
+    assert_eq!(path(&iterative_deepening_search(&problem).unwrap()), ["Arad"]);
+}
+
+#[test]
+fn city_names_are_case_insensitive() {
+    let problem = AradToBucharestProblem::new("arad", "TIMISOARA").unwrap();
+    let solution = breadth_first_search(&problem).unwrap();
+    assert_eq!(path(&solution), ["Arad", "Timisoara"]);
+    assert!(AradToBucharestProblem::<u32>::new("Arad", "Budapest").is_err());
+}
+
+#[test]
+fn statistics_count_the_search_tree() {
+    let problem = AradToBucharestProblem::<u32>::new("Arad", "Bucharest").unwrap();
+    let mut stats = Statistics::default();
+    breadth_first_search_traced(&problem, &mut stats).unwrap();
+    assert!(stats.expanded > 0);
+    assert!(stats.generated > stats.expanded);
+    assert_eq!(stats.max_depth, 3);
+}
+
+/// Route finding to the nearest of several depots
+struct Depots {
+    map: AradToBucharestProblem,
+    depots: Vec<&'static str>,
+}
+
+impl Depots {
+    fn new(start: &str, depots: &[&str]) -> Self {
+        let map = AradToBucharestProblem::new(start, depots[0]).unwrap();
+        let depots = depots.iter().map(|d| AradToBucharestProblem::<u32>::state(d).unwrap()).collect();
+        Depots { map, depots }
+    }
+}
+
+impl Problem<&'static str, AradToBucharestAction> for Depots {
+    fn is_goal(&self, state: &&'static str) -> bool {
+        self.depots.contains(state)
+    }
+
+    fn actions(&self, state: &&'static str) -> Vec<AradToBucharestAction> {
+        self.map.actions(state)
+    }
+
+    fn result(&self, state: &&'static str, action: &AradToBucharestAction) -> &'static str {
+        self.map.result(state, action)
+    }
+
+    fn action_cost(&self, state: &&'static str, action: &AradToBucharestAction, new_state: &&'static str) -> u32 {
+        self.map.action_cost(state, action, new_state)
+    }
+
+    fn get_initial_node(&self) -> Node<&'static str, AradToBucharestAction> {
+        Node::new(self.map.get_initial_node().state, None, None, 0, 0)
+    }
+
+    fn get_goal_node(&self) -> Node<&'static str, AradToBucharestAction> {
+        Node::new(self.depots[0], None, None, 0, 0)
+    }
+
+    fn get_goal_nodes(&self) -> Vec<Node<&'static str, AradToBucharestAction>> {
+        self.depots.iter().map(|d| Node::new(*d, None, None, 0, 0)).collect()
+    }
+
+    /* The straight-line distances are only known towards Bucharest */
+    fn get_heuristic_cost(&self, _state: &&'static str) -> u32 {
+        0
+    }
+}
+
+#[test]
+fn bidirectional_search_finds_the_nearest_depot() {
+    let problem = Depots::new("Arad", &["Craiova", "Fagaras", "Urziceni"]);
+    let solution = bibf_search(&problem).unwrap();
+    assert_eq!(path(&solution), ["Arad", "Sibiu", "Fagaras"]);
+    assert_eq!(solution.path_cost, 140 + 99);
+}
+
+#[test]
+fn searches_agree_on_the_nearest_depot_from_every_city() {
+    for depots in [vec!["Bucharest"], vec!["Craiova", "Fagaras", "Urziceni"], vec!["Neamt", "Timisoara", "Eforie", "Oradea"]] {
+        for city in AradToBucharestProblem::<u32>::states() {
+            let problem = Depots::new(city, &depots);
+            let nearest = depots.iter()
+                .map(|d| uniform_cost_search(&AradToBucharestProblem::new(city, d).unwrap()).unwrap().path_cost)
+                .min()
+                .unwrap();
+
+            for solution in [uniform_cost_search(&problem).unwrap(), bibf_search(&problem).unwrap()] {
+                assert!(problem.is_goal(&solution.state));
+                assert_eq!(path(&solution).first(), Some(&city));
+                assert_eq!(solution.path_cost, nearest, "from {} to {:?}", city, depots);
+            }
+        }
+    }
+}
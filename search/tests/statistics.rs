@@ -0,0 +1,35 @@
+use search::{
+    breadth_first_search_traced,
+    AradToBucharestProblem,
+    Statistics,
+};
+
+fn generated(n: usize) -> Statistics {
+    Statistics { generated: n, ..Statistics::default() }
+}
+
+#[test]
+fn effective_branching_factor_matches_aima() {
+    /* AIMA: a solution at depth 5 found with 52 nodes has b* = 1.92 */
+    let b = generated(52).effective_branching_factor(5).unwrap();
+    assert!((b - 1.92).abs() < 0.005, "{}", b);
+
+    /* A full binary tree of depth 3 has 2 + 4 + 8 nodes below its root */
+    let b = generated(14).effective_branching_factor(3).unwrap();
+    assert!((b - 2.0).abs() < 1e-9, "{}", b);
+
+    assert_eq!(generated(10).effective_branching_factor(0), None);
+    assert_eq!(generated(0).effective_branching_factor(3), None);
+}
+
+#[test]
+fn penetrance_is_depth_over_generated_nodes() {
+    assert_eq!(generated(20).penetrance(5), Some(0.25));
+    assert_eq!(generated(0).penetrance(5), None);
+
+    let problem: AradToBucharestProblem = AradToBucharestProblem::new("Arad", "Bucharest").unwrap();
+    let mut statistics = Statistics::default();
+    breadth_first_search_traced(&problem, &mut statistics).unwrap();
+    let penetrance = statistics.penetrance(3).unwrap();
+    assert!(0.0 < penetrance && penetrance < 1.0);
+}
@@ -0,0 +1,163 @@
+use proptest::prelude::*;
+use proptest::test_runner::RngSeed;
+
+use search::{
+    astar_search,
+    ida_star_search,
+    Node,
+    Problem,
+    TspAction,
+    TspProblem,
+    TspState,
+};
+
+const VILLAGE: &str = include_str!("../fixtures/tsp/village9.tsp");
+const FIVE: &str = include_str!("../fixtures/tsp/five.tsp");
+const FIVE_LOWER: &str = include_str!("../fixtures/tsp/five-lower.tsp");
+
+fn h(problem: &TspProblem, node: &Node<TspState, TspAction>) -> u32 {
+    problem.get_heuristic_cost(&node.state)
+}
+
+/// Cost of the shortest tour, trying every order of the cities after the first one
+fn brute_force(problem: &TspProblem) -> u32 {
+    fn shortest(problem: &TspProblem, tour: &mut Vec<usize>, left: &mut Vec<usize>) -> u32 {
+        if left.is_empty() {
+            let cost: u32 = tour.windows(2).map(|w| problem.distance(w[0], w[1])).sum();
+            return cost + problem.distance(*tour.last().unwrap(), 0);
+        }
+        let mut best = u32::MAX;
+        for i in 0..left.len() {
+            let city = left.remove(i);
+            tour.push(city);
+            best = best.min(shortest(problem, tour, left));
+            tour.pop();
+            left.insert(i, city);
+        }
+        best
+    }
+    shortest(problem, &mut vec![0], &mut (1..problem.cities()).collect())
+}
+
+/// The cities of the tour in order, checking that it visits every city once and costs what it says
+fn tour(problem: &TspProblem, solution: &Node<TspState, TspAction>) -> Vec<usize> {
+    let mut cities = Vec::new();
+    let mut cost = 0;
+    let mut node = solution;
+    while let Some(parent) = &node.parent {
+        let city = node.action.unwrap().0;
+        cost += problem.distance(parent.state.current(), city);
+        cities.push(city);
+        node = parent;
+    }
+    cities.reverse();
+    assert_eq!(cost, solution.path_cost);
+    assert_eq!(cities.last(), Some(&0));
+    let mut sorted = cities.clone();
+    sorted.sort();
+    assert_eq!(sorted, (0..problem.cities()).collect::<Vec<_>>());
+    cities
+}
+
+#[test]
+fn tsplib_files_are_parsed() {
+    let village = TspProblem::parse(VILLAGE).unwrap();
+    assert_eq!(village.name(), "village9");
+    assert_eq!(village.cities(), 9);
+    /* sqrt(30^2 + 5^2) = 30.41 */
+    assert_eq!(village.distance(0, 1), 30);
+    assert_eq!(village.distance(1, 0), 30);
+
+    let five = TspProblem::parse(FIVE).unwrap();
+    let lower = TspProblem::parse(FIVE_LOWER).unwrap();
+    for i in 0..5 {
+        for j in 0..5 {
+            assert_eq!(five.distance(i, j), lower.distance(i, j));
+        }
+    }
+}
+
+#[test]
+fn cities_keep_their_tsplib_numbers_in_any_order() {
+    let text = "TYPE : TSP\nDIMENSION : 3\nEDGE_WEIGHT_TYPE : CEIL_2D\nNODE_COORD_SECTION\n3 0 0\n1 3 4\n2 3 0.5\nEOF\n";
+    let problem = TspProblem::parse(text).unwrap();
+    assert_eq!(problem.distance(0, 2), 5);
+    assert_eq!(problem.distance(0, 1), 4);
+    assert_eq!(problem.distance(1, 2), 4);
+}
+
+#[test]
+fn search_finds_the_shortest_tour() {
+    for text in [VILLAGE, FIVE, FIVE_LOWER] {
+        let problem = TspProblem::parse(text).unwrap();
+        let optimal = brute_force(&problem);
+        for solution in [astar_search(&problem, h).unwrap(), ida_star_search(&problem, h).unwrap()] {
+            assert_eq!(solution.path_cost, optimal, "{}", problem.name());
+            tour(&problem, &solution);
+        }
+    }
+}
+
+#[test]
+fn spanning_tree_is_a_lower_bound() {
+    let problem = TspProblem::parse(VILLAGE).unwrap();
+    let root = problem.get_initial_node();
+    assert!(0 < root.f && root.f <= brute_force(&problem));
+    assert_eq!(problem.spanning_tree_cost(&[0, 1]), 30);
+    assert_eq!(problem.spanning_tree_cost(&[3]), 0);
+}
+
+#[test]
+fn single_city_is_a_tour_already() {
+    let text = "NAME : one\nTYPE : TSP\nDIMENSION : 1\nEDGE_WEIGHT_TYPE : EUC_2D\nNODE_COORD_SECTION\n1 5 5\nEOF\n";
+    let problem = TspProblem::parse(text).unwrap();
+    assert_eq!(astar_search(&problem, h).unwrap().path_cost, 0);
+    assert_eq!(ida_star_search(&problem, h).unwrap().path_cost, 0);
+}
+
+#[test]
+fn malformed_tsplib_files_are_rejected() {
+    let files = [
+        "TYPE : ATSP\nDIMENSION : 2\nEDGE_WEIGHT_TYPE : EXPLICIT\nEDGE_WEIGHT_SECTION\n0 1 2 0\n",
+        "TYPE : TSP\nEDGE_WEIGHT_TYPE : EUC_2D\nNODE_COORD_SECTION\n1 0 0\n",
+        "TYPE : TSP\nDIMENSION : 3\nEDGE_WEIGHT_TYPE : EUC_2D\nNODE_COORD_SECTION\n1 0 0\n2 1 1\n",
+        "TYPE : TSP\nDIMENSION : 2\nEDGE_WEIGHT_TYPE : EUC_3D\nNODE_COORD_SECTION\n1 0 0 0\n2 1 1 1\n",
+        "TYPE : TSP\nDIMENSION : 2\nEDGE_WEIGHT_TYPE : EXPLICIT\nEDGE_WEIGHT_FORMAT : UPPER_ROW\nEDGE_WEIGHT_SECTION\n1.5\n",
+        "TYPE : TSP\nDIMENSION : 2\nEDGE_WEIGHT_TYPE : EXPLICIT\nEDGE_WEIGHT_FORMAT : FULL_MATRIX\nEDGE_WEIGHT_SECTION\n0 1 2 0\n",
+        "TYPE : TSP\nDIMENSION : 3\nEDGE_WEIGHT_TYPE : EXPLICIT\nEDGE_WEIGHT_FORMAT : UPPER_ROW\nEDGE_WEIGHT_SECTION\n1 2\n",
+        "TYPE : TSP\nDIMENSION : 2\nEDGE_WEIGHT_TYPE : EXPLICIT\nEDGE_WEIGHT_FORMAT : UPPER_ROW\nEDGE_WEIGHT_SECTION\n-1\n",
+    ];
+    for text in files {
+        assert!(TspProblem::parse(text).is_err(), "{}", text);
+    }
+}
+
+fn random_instance() -> impl Strategy<Value = String> {
+    prop::collection::vec((0..100i32, 0..100i32), 2..=7).prop_map(|cities| {
+        let mut text = format!(
+            "NAME : random\nTYPE : TSP\nDIMENSION : {}\nEDGE_WEIGHT_TYPE : EUC_2D\nNODE_COORD_SECTION\n",
+            cities.len()
+        );
+        for (i, (x, y)) in cities.iter().enumerate() {
+            text.push_str(&format!("{} {} {}\n", i + 1, x, y));
+        }
+        text + "EOF\n"
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 64,
+        rng_seed: RngSeed::Fixed(0x7592),
+        ..ProptestConfig::default()
+    })]
+
+    #[test]
+    fn astar_and_ida_star_match_brute_force(text in random_instance()) {
+        let problem = TspProblem::parse(&text).unwrap();
+        let optimal = brute_force(&problem);
+        prop_assert!(problem.get_initial_node().f <= optimal);
+        prop_assert_eq!(astar_search(&problem, h).unwrap().path_cost, optimal);
+        prop_assert_eq!(ida_star_search(&problem, h).unwrap().path_cost, optimal);
+    }
+}
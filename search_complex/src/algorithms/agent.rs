@@ -1,12 +1,16 @@
 
-use crate::algorithms::problem::*;
+use crate::algorithms::{
+    cost::Cost,
+    problem::*,
+};
 
 
-pub trait Agent<P, S, A> 
+pub trait Agent<P, S, A, C>
 where
-    P: Problem<S, A>,
+    P: OnlineProblem<S, A, C>,
     S: Clone,
-    A: Clone
+    A: Clone,
+    C: Cost
 {
     fn new() -> Self;
     fn step(&mut self, problem: &P, s_star: S) -> A;
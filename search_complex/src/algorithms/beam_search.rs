@@ -0,0 +1,171 @@
+//!
+//! Local beam search, from section 4.1.3 of AIMA: it keeps k states instead of one. At every
+//! step it generates all the successors of the k states, returns one if it is optimal, and
+//! otherwise keeps the k best of them all. Unlike k hill climbings run apart, the states which
+//! have good successors take over the beam, and the others are dropped.
+//!
+//! That concentrates the beam on a small region quickly. Stochastic beam search keeps diversity
+//! by drawing the k successors at random instead, each with a probability growing with its value:
+//! here e^(score / T) over the sum for all the successors, so a low temperature T is close to
+//! local beam search and a high one to a random walk. Successors whose score is NaN or infinite
+//! have no such probability and are never drawn.
+//!
+//! The beam starts from k random states of the problem, or k times the initial state if it has
+//! no random states. Local beam search stops once no successor is better than the best state of
+//! the beam; stochastic beam search after `Beam::max_stagnation` steps without a better state.
+//! Both stop on an optimal state or after `Beam::max_iterations` steps, and return the best state
+//! they went through. The trajectory has the value of the best state of the beam at every step.
+//!
+
+/* External crates */
+use rand::{distributions::WeightedIndex, prelude::Distribution, seq::SliceRandom, Rng};
+
+/* Internal crates */
+use crate::algorithms::local_search::{LocalSearchProblem, LocalSearchResult};
+
+/// How the next beam is chosen from the successors of the current one
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BeamSelection {
+    /// The k best successors, ties broken at random
+    Best,
+    /// k successors drawn with probabilities proportional to e^(score / temperature)
+    Stochastic { temperature: f64 },
+}
+
+/// Settings of `beam_search_with`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Beam {
+    /// Number of states in the beam
+    pub k: usize,
+    pub selection: BeamSelection,
+    pub max_iterations: usize,
+    /// Steps without a better state after which stochastic beam search stops
+    pub max_stagnation: usize,
+}
+
+impl Default for Beam {
+    fn default() -> Self {
+        Self { k: 10, selection: BeamSelection::Best, max_iterations: 1000, max_stagnation: 100 }
+    }
+}
+
+pub fn local_beam_search<P, S, R>(problem: &P, k: usize, rng: &mut R) -> Result<LocalSearchResult<S>, String>
+where
+    P: LocalSearchProblem<S>,
+    S: Clone,
+    R: Rng + ?Sized
+{
+    beam_search_with(problem, &Beam { k, ..Beam::default() }, rng)
+}
+
+pub fn stochastic_beam_search<P, S, R>(problem: &P, k: usize, temperature: f64, rng: &mut R) -> Result<LocalSearchResult<S>, String>
+where
+    P: LocalSearchProblem<S>,
+    S: Clone,
+    R: Rng + ?Sized
+{
+    let selection = BeamSelection::Stochastic { temperature };
+    beam_search_with(problem, &Beam { k, selection, ..Beam::default() }, rng)
+}
+
+pub fn beam_search_with<P, S, R>(problem: &P, beam: &Beam, rng: &mut R) -> Result<LocalSearchResult<S>, String>
+where
+    P: LocalSearchProblem<S>,
+    S: Clone,
+    R: Rng + ?Sized
+{
+    if beam.k == 0 {
+        return Err(String::from("The beam must hold at least one state"));
+    }
+    if let BeamSelection::Stochastic { temperature } = beam.selection {
+        if temperature.is_nan() || temperature <= 0. {
+            return Err(format!("The temperature must be positive, not {}", temperature));
+        }
+    }
+
+    let mut states: Vec<(S, f64)> = (0..beam.k)
+        .map(|_| {
+            let state = problem.random_state(rng).unwrap_or_else(|| problem.initial());
+            let score = problem.score(&state);
+            (state, score)
+        })
+        .collect();
+    let (mut best, mut best_score) = best_of(&states);
+    let mut trajectory = vec![problem.value(&best)];
+    let (mut steps, mut stagnation) = (0, 0);
+
+    while steps < beam.max_iterations && !problem.is_optimal(&best) {
+        let mut successors: Vec<(S, f64)> = states.iter()
+            .flat_map(|(state, _)| problem.neighbors(state))
+            .map(|successor| {
+                let score = problem.score(&successor);
+                (successor, score)
+            })
+            .collect();
+        if successors.is_empty() {
+            break;
+        }
+        if let Some(optimal) = successors.iter().position(|(successor, _)| problem.is_optimal(successor)) {
+            best = successors.swap_remove(optimal).0;
+            steps += 1;
+            trajectory.push(problem.value(&best));
+            break;
+        }
+
+        states = match beam.selection {
+            BeamSelection::Best => {
+                successors.shuffle(rng);
+                successors.sort_by(|a, b| b.1.total_cmp(&a.1));
+                if successors[0].1 <= states.iter().map(|(_, score)| *score).fold(f64::NEG_INFINITY, f64::max) {
+                    break;
+                }
+                successors.truncate(beam.k);
+                successors
+            },
+            BeamSelection::Stochastic { temperature } => {
+                if stagnation >= beam.max_stagnation {
+                    break;
+                }
+                let Some(sample) = sample(&successors, beam.k, temperature, rng) else {
+                    break;
+                };
+                sample
+            },
+        };
+        steps += 1;
+
+        let (beam_best, beam_score) = best_of(&states);
+        trajectory.push(problem.value(&beam_best));
+        if beam_score > best_score {
+            (best, best_score) = (beam_best, beam_score);
+            stagnation = 0;
+        } else {
+            stagnation += 1;
+        }
+    }
+
+    Ok(LocalSearchResult::new(problem, best, steps, 0, trajectory))
+}
+
+/* The state of the beam with the highest score */
+fn best_of<S: Clone>(states: &[(S, f64)]) -> (S, f64) {
+    let best = states.iter()
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .expect("The beam is never empty");
+    best.clone()
+}
+
+/* k successors with a finite score drawn with replacement, with weights e^(score / temperature);
+   None if no score is finite */
+fn sample<S, R>(successors: &[(S, f64)], k: usize, temperature: f64, rng: &mut R) -> Option<Vec<(S, f64)>>
+where
+    S: Clone,
+    R: Rng + ?Sized
+{
+    let finite: Vec<&(S, f64)> = successors.iter().filter(|(_, score)| score.is_finite()).collect();
+    /* Shifted by the highest score so that the weights do not overflow */
+    let highest = finite.iter().map(|(_, score)| *score).fold(f64::NEG_INFINITY, f64::max);
+    let weights = finite.iter().map(|(_, score)| ((score - highest) / temperature).exp());
+    let index = WeightedIndex::new(weights).ok()?;
+    Some((0..k).map(|_| finite[index.sample(rng)].clone()).collect())
+}
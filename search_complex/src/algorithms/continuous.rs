@@ -0,0 +1,340 @@
+//!
+//! Local search in continuous spaces, from section 4.2 of AIMA: the state is a vector x of real
+//! variables, and the search follows the gradient of the objective function f.
+//!
+//! Gradient ascent moves to x + alpha * grad f(x), or x - alpha * grad f(x) when minimising. The
+//! step size alpha is fixed, or found by a backtracking line search: start with a large step and
+//! halve it until the value improves enough (the Armijo condition).
+//!
+//! Newton-Raphson solves grad f(x) = 0 by moving to x - H(x)^-1 grad f(x), where H is the Hessian
+//! of second derivatives. It converges much faster near a stationary point, but that point can
+//! be a maximum, a minimum or a saddle point, whatever the objective.
+//!
+//! Problems may give their gradient and Hessian. When they do not, or with `Derivatives::Empirical`,
+//! the derivatives are estimated by central finite differences: the empirical gradient of AIMA.
+//! The searches stop once the gradient or the step is shorter than the tolerance, or after the
+//! maximum number of iterations; a result with fewer steps than that has converged.
+//!
+
+/* Internal crates */
+use crate::algorithms::local_search::{LocalSearchResult, Objective};
+
+pub trait ContinuousProblem {
+    /// Number of variables
+    fn dimensions(&self) -> usize;
+
+    /// The point the search starts from
+    fn initial(&self) -> Vec<f64>;
+
+    /// The objective function
+    fn value(&self, x: &[f64]) -> f64;
+
+    /// Whether `value` is to be maximised or minimised
+    fn objective(&self) -> Objective {
+        Objective::Maximize
+    }
+
+    /// The partial derivatives of `value`, or None if they are not known
+    fn gradient(&self, _x: &[f64]) -> Option<Vec<f64>> {
+        None
+    }
+
+    /// The second partial derivatives of `value`, row by row, or None if they are not known
+    fn hessian(&self, _x: &[f64]) -> Option<Vec<Vec<f64>>> {
+        None
+    }
+}
+
+/// Where the derivatives come from
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Derivatives {
+    /// From the problem, or estimated with steps of 1e-5 when it does not know them
+    Analytic,
+    /// Estimated from the values at x +- h along every variable, even if the problem knows them
+    Empirical { h: f64 },
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum StepSize {
+    Fixed(f64),
+    /// Backtracking from `initial`, multiplying the step by `shrink` until the value improves by
+    /// at least a small fraction of what the gradient promises. `initial` must be positive and
+    /// `shrink` between 0 and 1.
+    LineSearch { initial: f64, shrink: f64 },
+}
+
+/// Settings of `gradient_ascent`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct GradientAscent {
+    pub step: StepSize,
+    pub derivatives: Derivatives,
+    pub tolerance: f64,
+    pub max_iterations: usize,
+}
+
+impl Default for GradientAscent {
+    fn default() -> Self {
+        Self {
+            step: StepSize::LineSearch { initial: 1., shrink: 0.5 },
+            derivatives: Derivatives::Analytic,
+            tolerance: 1e-6,
+            max_iterations: 10_000,
+        }
+    }
+}
+
+/// Settings of `newton_raphson`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct NewtonRaphson {
+    pub derivatives: Derivatives,
+    pub tolerance: f64,
+    pub max_iterations: usize,
+}
+
+impl Default for NewtonRaphson {
+    fn default() -> Self {
+        Self { derivatives: Derivatives::Analytic, tolerance: 1e-9, max_iterations: 100 }
+    }
+}
+
+const DEFAULT_H: f64 = 1e-5;
+/* Fraction of the improvement promised by the gradient that a line search step must make */
+const ARMIJO: f64 = 1e-4;
+
+/// Gradient ascent, or descent when the problem minimises
+pub fn gradient_ascent<P>(problem: &P, settings: &GradientAscent) -> Result<LocalSearchResult<Vec<f64>>, String>
+where
+    P: ContinuousProblem
+{
+    if let StepSize::LineSearch { initial, shrink } = settings.step {
+        if !(initial > 0. && initial.is_finite()) {
+            return Err(format!("The initial step of the line search must be positive, not {}", initial));
+        }
+        if !(shrink > 0. && shrink < 1.) {
+            return Err(format!("The line search must shrink the step by a factor between 0 and 1, not {}", shrink));
+        }
+    }
+    if settings.tolerance.is_nan() || settings.tolerance < 0. {
+        return Err(format!("The tolerance must not be negative, not {}", settings.tolerance));
+    }
+    let sign = sign(problem);
+    let mut x = initial(problem)?;
+    let mut value = problem.value(&x);
+    let mut trajectory = vec![value];
+    let mut steps = 0;
+
+    while steps < settings.max_iterations {
+        let gradient = finite_gradient(problem, &x, settings.derivatives)?;
+        let norm_squared: f64 = gradient.iter().map(|g| g * g).sum();
+        if norm_squared.sqrt() <= settings.tolerance {
+            break;
+        }
+
+        let step = match settings.step {
+            StepSize::Fixed(alpha) => {
+                let next = along(&x, &gradient, sign * alpha);
+                let next_value = problem.value(&next);
+                Some((next, next_value))
+            },
+            StepSize::LineSearch { initial, shrink } => {
+                let mut alpha = initial;
+                loop {
+                    let next = along(&x, &gradient, sign * alpha);
+                    let next_value = problem.value(&next);
+                    if sign * (next_value - value) >= ARMIJO * alpha * norm_squared {
+                        break Some((next, next_value));
+                    }
+                    alpha *= shrink;
+                    /* Even the smallest step does not improve: x is as close as it gets */
+                    if alpha * norm_squared.sqrt() <= settings.tolerance {
+                        break None;
+                    }
+                }
+            },
+        };
+        let Some((next, next_value)) = step else {
+            break;
+        };
+        if !next_value.is_finite() {
+            return Err(format!("The value diverged at {:?}", next));
+        }
+
+        let moved = distance(&x, &next);
+        x = next;
+        value = next_value;
+        steps += 1;
+        trajectory.push(value);
+        if moved <= settings.tolerance {
+            break;
+        }
+    }
+
+    Ok(LocalSearchResult { state: x, value, steps, restarts: 0, trajectory })
+}
+
+/// Newton-Raphson, which finds the stationary point nearest to the start whatever the objective
+pub fn newton_raphson<P>(problem: &P, settings: &NewtonRaphson) -> Result<LocalSearchResult<Vec<f64>>, String>
+where
+    P: ContinuousProblem
+{
+    let mut x = initial(problem)?;
+    let mut value = problem.value(&x);
+    let mut trajectory = vec![value];
+    let mut steps = 0;
+
+    while steps < settings.max_iterations {
+        let gradient = finite_gradient(problem, &x, settings.derivatives)?;
+        if gradient.iter().map(|g| g * g).sum::<f64>().sqrt() <= settings.tolerance {
+            break;
+        }
+        let hessian = hessian(problem, &x, settings.derivatives);
+        let minus_gradient: Vec<f64> = gradient.iter().map(|g| -g).collect();
+        let Some(delta) = solve(hessian, minus_gradient) else {
+            return Err(format!("The Hessian is singular at {:?}", x));
+        };
+
+        x = along(&x, &delta, 1.);
+        value = problem.value(&x);
+        if !value.is_finite() {
+            return Err(format!("The value diverged at {:?}", x));
+        }
+        steps += 1;
+        trajectory.push(value);
+        if delta.iter().map(|d| d * d).sum::<f64>().sqrt() <= settings.tolerance {
+            break;
+        }
+    }
+
+    Ok(LocalSearchResult { state: x, value, steps, restarts: 0, trajectory })
+}
+
+fn sign<P: ContinuousProblem>(problem: &P) -> f64 {
+    match problem.objective() {
+        Objective::Maximize => 1.,
+        Objective::Minimize => -1.,
+    }
+}
+
+fn initial<P: ContinuousProblem>(problem: &P) -> Result<Vec<f64>, String> {
+    let x = problem.initial();
+    if x.len() != problem.dimensions() {
+        return Err(format!("The initial point has {} variables instead of {}", x.len(), problem.dimensions()));
+    }
+    Ok(x)
+}
+
+/* x + alpha * direction */
+fn along(x: &[f64], direction: &[f64], alpha: f64) -> Vec<f64> {
+    x.iter().zip(direction).map(|(x, d)| x + alpha * d).collect()
+}
+
+fn distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| (a - b) * (a - b)).sum::<f64>().sqrt()
+}
+
+/* x moved by h along variable i */
+fn moved(x: &[f64], i: usize, h: f64) -> Vec<f64> {
+    let mut y = x.to_vec();
+    y[i] += h;
+    y
+}
+
+fn gradient<P: ContinuousProblem>(problem: &P, x: &[f64], derivatives: Derivatives) -> Vec<f64> {
+    let h = match derivatives {
+        Derivatives::Analytic => match problem.gradient(x) {
+            Some(gradient) => return gradient,
+            None => DEFAULT_H,
+        },
+        Derivatives::Empirical { h } => h,
+    };
+    empirical_gradient(problem, x, h)
+}
+
+/* The gradient, which must be finite for the searches to know where to go */
+fn finite_gradient<P: ContinuousProblem>(problem: &P, x: &[f64], derivatives: Derivatives) -> Result<Vec<f64>, String> {
+    let gradient = gradient(problem, x, derivatives);
+    if gradient.iter().any(|g| !g.is_finite()) {
+        return Err(format!("The gradient at {:?} is {:?}", x, gradient));
+    }
+    Ok(gradient)
+}
+
+/// The central differences (f(x + h e_i) - f(x - h e_i)) / 2h
+pub fn empirical_gradient<P: ContinuousProblem>(problem: &P, x: &[f64], h: f64) -> Vec<f64> {
+    (0..x.len())
+        .map(|i| (problem.value(&moved(x, i, h)) - problem.value(&moved(x, i, -h))) / (2. * h))
+        .collect()
+}
+
+fn hessian<P: ContinuousProblem>(problem: &P, x: &[f64], derivatives: Derivatives) -> Vec<Vec<f64>> {
+    let n = x.len();
+    match derivatives {
+        Derivatives::Analytic => {
+            if let Some(hessian) = problem.hessian(x) {
+                return hessian;
+            }
+            if problem.gradient(x).is_some() {
+                /* Central differences of the gradient, made symmetric */
+                let h = DEFAULT_H;
+                let columns: Vec<Vec<f64>> = (0..n)
+                    .map(|i| {
+                        let plus = problem.gradient(&moved(x, i, h)).unwrap();
+                        let minus = problem.gradient(&moved(x, i, -h)).unwrap();
+                        plus.iter().zip(&minus).map(|(p, m)| (p - m) / (2. * h)).collect()
+                    })
+                    .collect();
+                return (0..n).map(|i| (0..n).map(|j| (columns[i][j] + columns[j][i]) / 2.).collect()).collect();
+            }
+            /* Second differences of the value need a larger step than the gradient */
+            empirical_hessian(problem, x, DEFAULT_H.sqrt())
+        },
+        Derivatives::Empirical { h } => empirical_hessian(problem, x, h),
+    }
+}
+
+/* Second central differences of the value */
+fn empirical_hessian<P: ContinuousProblem>(problem: &P, x: &[f64], h: f64) -> Vec<Vec<f64>> {
+    let n = x.len();
+    let f = |y: &[f64]| problem.value(y);
+    let fx = f(x);
+    let second_difference = |i: usize, j: usize| {
+        if i == j {
+            return (f(&moved(x, i, h)) - 2. * fx + f(&moved(x, i, -h))) / (h * h);
+        }
+        let pp = f(&moved(&moved(x, i, h), j, h));
+        let pm = f(&moved(&moved(x, i, h), j, -h));
+        let mp = f(&moved(&moved(x, i, -h), j, h));
+        let mm = f(&moved(&moved(x, i, -h), j, -h));
+        (pp - pm - mp + mm) / (4. * h * h)
+    };
+    (0..n).map(|i| (0..n).map(|j| second_difference(i.max(j), i.min(j))).collect()).collect()
+}
+
+/* Solves a x = b by Gaussian elimination with partial pivoting; None if a is singular */
+fn solve(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    let scale = a.iter().flatten().fold(0f64, |m, v| m.max(v.abs()));
+    for column in 0..n {
+        let pivot = (column..n).max_by(|i, j| a[*i][column].abs().total_cmp(&a[*j][column].abs()))?;
+        if a[pivot][column].abs() <= 1e-12 * scale.max(f64::MIN_POSITIVE) {
+            return None;
+        }
+        a.swap(column, pivot);
+        b.swap(column, pivot);
+        let (upper, lower) = a.split_at_mut(column + 1);
+        let pivot_row = &upper[column];
+        for (offset, row) in lower.iter_mut().enumerate() {
+            let factor = row[column] / pivot_row[column];
+            for (value, pivot) in row[column..].iter_mut().zip(&pivot_row[column..]) {
+                *value -= factor * pivot;
+            }
+            b[column + 1 + offset] -= factor * b[column];
+        }
+    }
+    let mut x = vec![0.; n];
+    for row in (0..n).rev() {
+        let rest: f64 = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - rest) / a[row][row];
+    }
+    Some(x)
+}
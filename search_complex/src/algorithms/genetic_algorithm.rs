@@ -1,289 +1,252 @@
-///
-/// function Genetic-Algorithm(population, fitness) returns an individual
-///     repeat
-///         weights <- Weighted-By(population, fitness)
-///         population2 <- empty list
-///         for i = 1 to Size(population) do
-///             parent1, parent2 <- Weighted-Random-Choices(population, weights, 2)
-///             child <- reproduce(parent1, parent2)
-///             if (small random probability) then child <- Mutate(child)
-///             add child to population2
-///         population <- population2
-///     until some individual is fit enough, or enough time has elapsed
-///     return the best individual in population, according to fitness
-///
-/// function Reproduce(parent1, parent2) returns an individual
-///     n <- Length(parent1)
-///     c <- random number from 1 to n
-///     return Append(Substring(parent1, 1, c), Substring(parent2, c+1, n))
-///
-
-use rand::prelude::*;
-
-use crate::algorithms::{
-    node::Node,
-    problem::*,
-};
-
-type GeneticWeights = Vec<f64>;
-type Individual<A> = Node<String, A>;
-
-pub fn genetic_algorithm<P, A>(problem: &P) -> SearchResult<String, A> 
-where
-    P: Problem<&'static str, A>,
-    A: Clone
-{
-
-    let mut rng = rand::thread_rng();
-
-    let mut population: Vec<Node<String, A>> = instantiate_population(problem, 100);
-
-    loop {
-        let weights = weighted_by(problem, &population, genetic_fitness);
-        let mut population2: Vec<Node<String, A>> = Vec::new();
-        for _ in 0..population.len() {
-            let (parent1, parent2) = weighted_random_choices(problem, &population, &weights, 2);
-            let mut child = reproduce(problem, &parent1, &parent2);
-            if rng.gen_range::<i32, _>(0..100) < 50 {
-                child = mutate(problem, &child);
-            }
-            population2.push(child);
-        }
-        population.append(&mut population2.clone());
-        
-        // Filter out any NAN values
-        population = population.iter().filter_map(|f| if f.path_cost.is_nan() { None } else {Some(f.clone()) }).collect();
-        population.sort_by(|a, b| a.path_cost.partial_cmp(&b.path_cost).unwrap());
-        
-        for p in &population {
-            is_fit_enough(problem, p);
-        }
-
-        /* Remove worst half of population */
-        for _ in 0..population.len() / 2 {
-            population.pop();
-        }
-        
-        if is_fit_enough(problem, population.first().unwrap()) {
-            break;
-        }
-    }
-
-    Ok(population.first().unwrap().clone())
+//!
+//! function Genetic-Algorithm(population, fitness) returns an individual
+//!     repeat
+//!         weights <- Weighted-By(population, fitness)
+//!         population2 <- empty list
+//!         for i = 1 to Size(population) do
+//!             parent1, parent2 <- Weighted-Random-Choices(population, weights, 2)
+//!             child <- reproduce(parent1, parent2)
+//!             if (small random probability) then child <- Mutate(child)
+//!             add child to population2
+//!         population <- population2
+//!     until some individual is fit enough, or enough time has elapsed
+//!     return the best individual in population, according to fitness
+//!
+//! function Reproduce(parent1, parent2) returns an individual
+//!     n <- Length(parent1)
+//!     c <- random number from 1 to n
+//!     return Append(Substring(parent1, 1, c), Substring(parent2, c+1, n))
+//!
+//! The individuals are any `Genome`, which knows how to make a random individual, reproduce and
+//! mutate; the fitness is a function of the genome, higher being fitter. Besides the weighted
+//! (roulette wheel) choice of parents, they can be chosen by tournament or by rank, and the
+//! fittest individuals can be carried over to the next generation unchanged (elitism).
+//!
+
+/* External crates */
+use rand::Rng;
+
+/// An individual of a genetic algorithm
+pub trait Genome: Clone {
+    /// What the operators need to know about the problem, like the size of the board or the
+    /// bounds of the variables
+    type Context;
+
+    /// An individual chosen at random, for the first generation
+    fn random<R: Rng + ?Sized>(context: &Self::Context, rng: &mut R) -> Self;
+
+    /// A child of the two parents
+    fn crossover<R: Rng + ?Sized>(&self, other: &Self, context: &Self::Context, rng: &mut R) -> Self;
+
+    /// Changes the individual a little, at random
+    fn mutate<R: Rng + ?Sized>(&mut self, context: &Self::Context, rng: &mut R);
 }
 
-fn weighted_random_choices<P, A>(_problem: &P, population: &Vec<Individual<A>>, weights: &Vec<GeneticWeights>, num_parents: usize) -> (Individual<A>, Individual<A>)
-where
-    P: Problem<&'static str, A>,
-    A: Clone
-{
-    let mut rng = rand::thread_rng();
-    let mut i: usize = 0;
-    let mut parents: Vec<Individual<A>> = Vec::new();
-    let pop_len = population.len();
-
-    while parents.len() < num_parents {
-        let mut weight_sum: f64 = 0.;
+/// How the parents are chosen from the population
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Selection {
+    /// With a probability proportional to their fitness, less that of the least fit individual
+    Roulette,
+    /// The fittest of this many individuals chosen at random
+    Tournament(usize),
+    /// With a probability proportional to their rank, 1 for the least fit individual
+    Rank,
+}
 
-        let vec = weights.get(i % pop_len).unwrap();
-        for w in vec {
-            weight_sum += w;
-        }
+/// Settings of `genetic_algorithm`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Genetic {
+    pub population_size: usize,
+    pub selection: Selection,
+    /// Number of the fittest individuals copied unchanged into the next generation
+    pub elitism: usize,
+    /// Probability of mutating each child
+    pub mutation_rate: f64,
+    /// Stops after this many generations
+    pub max_generations: usize,
+    /// Stops once an individual is at least this fit
+    pub target_fitness: Option<f64>,
+    /// Stops after this many generations in a row without a fitter individual
+    pub max_stagnation: Option<usize>,
+}
 
-        if rng.gen_bool(weight_sum) {
-            parents.push(population.get(i % pop_len).unwrap().clone());
+impl Default for Genetic {
+    fn default() -> Self {
+        Self {
+            population_size: 100,
+            selection: Selection::Tournament(3),
+            elitism: 1,
+            mutation_rate: 0.1,
+            max_generations: 1000,
+            target_fitness: None,
+            max_stagnation: None,
         }
-        i += 1;
     }
-
-    (parents.get(0).unwrap().clone(), parents.get(1).unwrap().clone())
 }
 
-fn weighted_by<P, A>(problem: &P, population: &Vec<Node<String, A>>, fitness: fn(&P, &Individual<A>) -> GeneticWeights) -> Vec<GeneticWeights>
-where
-    P: Problem<&'static str, A>,
-    A: Clone
-{
-    let mut weights: Vec<GeneticWeights> = Vec::new();
-    for individual in population {
-        weights.push(fitness(problem, &individual));
-    }
-
-    return weights;
+/// Why the algorithm stopped
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Termination {
+    Generations,
+    TargetFitness,
+    Stagnation,
 }
 
-fn reproduce<P, A>(problem: &P, parent1: &Node<String, A>, parent2: &Node<String, A>) -> Node<String, A>
-where
-    P: Problem<&'static str, A>,
-    A: Clone
-{
-    let n = parent1.state.len();
-    let c = rand::thread_rng().gen_range(0..n-1);
-    let mut node: Node<String, A> = Node {
-        state: parent1.state.get(0..c).unwrap().to_string(),
-        parent: None,
-        action: None,
-        path_cost: 0.,
-        f: 0.
-    };
-    node.state.push_str(parent2.state.get(c..n).unwrap());
-    node.path_cost = get_path_cost(problem, &node);
-    return node;
+#[derive(Clone, Debug)]
+pub struct GeneticResult<G> {
+    /// The fittest individual of any generation
+    pub best: G,
+    pub fitness: f64,
+    /// Number of generations bred after the first one
+    pub generations: usize,
+    pub termination: Termination,
+    /// The fitness of the fittest individual of every generation, the first one included
+    pub history: Vec<f64>,
 }
 
-fn is_fit_enough<P, A>(problem: &P, individual: &Individual<A>) -> bool 
+pub fn genetic_algorithm<G, F, R>(context: &G::Context, fitness: F, genetic: &Genetic, rng: &mut R) -> Result<GeneticResult<G>, String>
 where
-    P: Problem<&'static str, A>,
-    A: Clone
+    G: Genome,
+    F: Fn(&G) -> f64,
+    R: Rng + ?Sized
 {
-    const FITNESS: f64 = 1500.;
+    validate(genetic)?;
 
-    let total_cost = get_path_cost(problem, individual);
-    
-    total_cost < FITNESS
-}
+    let mut population = first_generation(context, &fitness, genetic, rng);
+    let (mut best, mut best_fitness) = population[0].clone();
+    let mut history = vec![best_fitness];
+    let (mut generations, mut stagnation) = (0, 0);
 
-fn mutate<P, A>(problem: &P, child: &Individual<A>) -> Individual<A>
-where
-    P: Problem<&'static str, A>,
-    A: Clone
-{
-    let mut rng = rand::thread_rng();
-    // Mutate a single part of the individuals dna
-    let index: usize = rng.gen_range(0..child.state.len() / 2);
-    let mut mutated_child = child.clone();
-
-    // Move temporary state to the strand that is going to be changed
-    let mut node = problem.get_initial_node();
-    let mut n = 0;
-    for c in child.state.chars() {
-        if let Some(i) = c.to_digit(10) {
-            let nodes = expand(problem, node.clone());
-            if let Some(n) = nodes.get(i.clamp(0, nodes.len() as u32) as usize) {
-                node = n.clone();
-            }
+    let termination = loop {
+        if genetic.target_fitness.is_some_and(|target| best_fitness >= target) {
+            break Termination::TargetFitness;
         }
-        n += 1;
-        if n == index {
-            break;
+        if genetic.max_stagnation.is_some_and(|max| stagnation >= max) {
+            break Termination::Stagnation;
+        }
+        if generations >= genetic.max_generations {
+            break Termination::Generations;
         }
-    }
 
-    let actions_size = problem.actions(&node.state).len();
-    let mut new_val = rng.gen_range(0..actions_size);
-    
-    while new_val == index {
-        new_val = rng.gen_range(0..actions_size);
-    }
+        population = next_generation(&population, context, &fitness, genetic, rng);
+        generations += 1;
 
-    mutated_child.state.replace_range(index as usize..index as usize + 1, new_val.to_string().as_str());
+        history.push(population[0].1);
+        if population[0].1 > best_fitness {
+            (best, best_fitness) = population[0].clone();
+            stagnation = 0;
+        } else {
+            stagnation += 1;
+        }
+    };
 
-    mutated_child
+    Ok(GeneticResult { best, fitness: best_fitness, generations, termination, history })
 }
 
-fn genetic_fitness<P, A>(problem: &P, individual: &Individual<A>) -> GeneticWeights 
-where
-    P: Problem<&'static str, A>,
-    A: Clone
-{
-    let mut weights: GeneticWeights = GeneticWeights::new();
-    
-    let mut node = problem.get_initial_node();
-
-    /*
-     * Our DNA string described a choice at each node
-     */
-    for c in individual.state.chars() {
-        if let Some(index) = c.to_digit(10) {
-            let choices = expand(problem, node.clone());
-            if let Some(tmp) = choices.get(index as usize) {
-                node = tmp.clone();
-                weights.push(1. / node.path_cost);
-                if problem.is_goal(&tmp.state) {
-                    break;
-                }
-            }
-        }
+pub(crate) fn validate(genetic: &Genetic) -> Result<(), String> {
+    if genetic.population_size == 0 {
+        return Err(String::from("The population must not be empty"));
     }
-
-    return weights;
+    if genetic.elitism > genetic.population_size {
+        return Err(format!("Cannot keep {} elites in a population of {}", genetic.elitism, genetic.population_size));
+    }
+    if genetic.selection == Selection::Tournament(0) {
+        return Err(String::from("A tournament needs at least one individual"));
+    }
+    if !(0. ..=1.).contains(&genetic.mutation_rate) {
+        return Err(format!("The mutation rate {} is not a probability", genetic.mutation_rate));
+    }
+    Ok(())
 }
 
-fn instantiate_population<P, A>(problem: &P, size: usize) -> Vec<Individual<A>>
+/// Random individuals with their fitness, fittest first
+pub(crate) fn first_generation<G, F, R>(context: &G::Context, fitness: &F, genetic: &Genetic, rng: &mut R) -> Vec<(G, f64)>
 where
-    P: Problem<&'static str, A>,
-    A: Clone
+    G: Genome,
+    F: Fn(&G) -> f64,
+    R: Rng + ?Sized
 {
-    let mut pop = Vec::new();
-
-    for _ in 0..size {
-        pop.push(rand_individual(problem)); 
-    }
-
-    pop
+    let mut population: Vec<(G, f64)> = (0..genetic.population_size)
+        .map(|_| {
+            let individual = G::random(context, rng);
+            let f = fitness(&individual);
+            (individual, f)
+        })
+        .collect();
+    sort_by_fitness(&mut population);
+    population
 }
 
-fn rand_individual<P, A>(problem: &P) -> Individual<A>
+/// The elites and the children of a population sorted fittest first, sorted in turn
+pub(crate) fn next_generation<G, F, R>(population: &[(G, f64)], context: &G::Context, fitness: &F, genetic: &Genetic, rng: &mut R) -> Vec<(G, f64)>
 where
-    P: Problem<&'static str, A>,
-    A: Clone
+    G: Genome,
+    F: Fn(&G) -> f64,
+    R: Rng + ?Sized
 {
-    let mut rng = rand::thread_rng();
-    const MAX_MOVES: usize = 10;
-    let mut dna = String::new();
-    let mut node = problem.get_initial_node();
-
-    for _ in 0..MAX_MOVES {
-        
-        let nodes = expand(problem, node.clone());
-        
-        let choice = rng.gen_range(0..nodes.len()) as u8;
-        dna.push_str(format!("{}", choice).as_str());
-        node = nodes.get(choice as usize).unwrap().clone();
+    let weights = weighted_by(population, genetic.selection);
+    let mut population2: Vec<(G, f64)> = population[..genetic.elitism].to_vec();
+    while population2.len() < genetic.population_size {
+        let parent1 = choose(population, &weights, genetic.selection, rng);
+        let parent2 = choose(population, &weights, genetic.selection, rng);
+        let mut child = parent1.crossover(parent2, context, rng);
+        if rng.gen_bool(genetic.mutation_rate) {
+            child.mutate(context, rng);
+        }
+        let f = fitness(&child);
+        population2.push((child, f));
     }
-
-    let mut new_individual = Individual::new(dna, None, None, 0., 0.);
-    new_individual.path_cost = get_path_cost(problem, &new_individual);
-
-    new_individual
+    sort_by_fitness(&mut population2);
+    population2
 }
 
-fn get_path_cost<P, A>(problem: &P, individual: &Individual<A>) -> f64 
-where
-    P: Problem<&'static str, A>,
-    A: Clone
-{
-    let mut node = problem.get_initial_node();
-    let mut total_cost = 0.;
+/* Fittest first; NaN fitness counts as the least fit */
+pub(crate) fn sort_by_fitness<G>(population: &mut [(G, f64)]) {
+    population.sort_by(|a, b| {
+        let key = |f: f64| if f.is_nan() { f64::NEG_INFINITY } else { f };
+        key(b.1).total_cmp(&key(a.1))
+    });
+}
 
-    for c in individual.state.chars() {
-        if let Some(index) = c.to_digit(10) {
-            let choices = expand(problem, node.clone());
-            if let Some(tmp) = choices.get(index as usize) {
-                node = tmp.clone();
-                total_cost += node.path_cost + problem.get_heuristic_cost(&node.state);
-            }
-        }
+/* The weights of the population, sorted fittest first, for roulette and rank selection */
+fn weighted_by<G>(population: &[(G, f64)], selection: Selection) -> Vec<f64> {
+    let n = population.len();
+    match selection {
+        Selection::Roulette => {
+            let least = population.iter().map(|(_, f)| *f).filter(|f| f.is_finite()).fold(f64::INFINITY, f64::min);
+            population.iter()
+                .map(|(_, f)| if f.is_finite() { f - least } else { 0. })
+                .collect()
+        },
+        Selection::Rank => (0..n).map(|i| (n - i) as f64).collect(),
+        Selection::Tournament(_) => Vec::new(),
     }
-    
-    total_cost
 }
 
-pub fn iterate_over_dna<P, S, A, T>(problem: &P, individual: &Individual<A>, callback: fn(Node<S, A>, &mut T), param: &mut T)
+fn choose<'a, G, R>(population: &'a [(G, f64)], weights: &[f64], selection: Selection, rng: &mut R) -> &'a G
 where
-    P: Problem<S, A>,
-    S: Clone,
-    A: Clone
+    R: Rng + ?Sized
 {
-    let mut node = problem.get_initial_node();
+    let index = match selection {
+        /* The population is sorted, so the fittest of the tournament has the lowest index */
+        Selection::Tournament(size) => (0..size).map(|_| rng.gen_range(0..population.len())).min().unwrap(),
+        Selection::Roulette | Selection::Rank => weighted_random_choice(weights, rng),
+    };
+    &population[index].0
+}
 
-    for c in individual.state.chars() {
-        if let Some(index) = c.to_digit(10) {
-            let choices = expand(problem, node.clone());
-            if let Some(tmp) = choices.get(index as usize) {
-                (callback)(tmp.clone(), param);
-                node = tmp.clone();
-            }
+/* An index chosen with a probability proportional to its weight, or uniformly if they are all 0 */
+fn weighted_random_choice<R: Rng + ?Sized>(weights: &[f64], rng: &mut R) -> usize {
+    let total: f64 = weights.iter().sum();
+    if total <= 0. || !total.is_finite() {
+        return rng.gen_range(0..weights.len());
+    }
+    let mut x = rng.gen_range(0. ..total);
+    for (i, w) in weights.iter().enumerate() {
+        if x < *w {
+            return i;
         }
+        x -= w;
     }
+    weights.len() - 1
 }
@@ -0,0 +1,396 @@
+//!
+//! Genomes for the genetic algorithm, with the usual operators for each encoding:
+//!     - bit strings: one-point, two-point and uniform crossover, bit-flip mutation
+//!     - permutations, like tours or queens on distinct rows: partially mapped (PMX), order (OX)
+//!       and cycle crossover, which all give a permutation again, and swap or inversion mutation
+//!     - real vectors within bounds: blend (BLX-alpha) and simulated binary (SBX) crossover,
+//!       Gaussian mutation
+//! The operators to use are part of the context, with the length of the genome or the bounds.
+//! The contexts of bit strings and real vectors check their rates and bounds when they are made,
+//! so that the operators never see a probability or an interval they cannot use.
+//!
+
+/* External crates */
+use rand::Rng;
+
+/* Internal crates */
+use crate::algorithms::genetic_algorithm::Genome;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BitCrossover {
+    /// The bits before a random point from one parent, the others from the other one
+    OnePoint,
+    /// The bits between two random points from one parent, the others from the other one
+    TwoPoint,
+    /// Every bit from either parent with the same probability
+    Uniform,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct BitStringOperators {
+    length: usize,
+    crossover: BitCrossover,
+    flip_rate: f64,
+}
+
+impl BitStringOperators {
+    /// One-point crossover, and mutations flipping one bit on average
+    pub fn new(length: usize) -> Self {
+        Self { length, crossover: BitCrossover::OnePoint, flip_rate: 1. / length.max(1) as f64 }
+    }
+
+    pub fn with_crossover(mut self, crossover: BitCrossover) -> Self {
+        self.crossover = crossover;
+        self
+    }
+
+    /// Sets the probability of flipping each bit of a mutated genome, between 0 and 1
+    pub fn with_flip_rate(mut self, flip_rate: f64) -> Result<Self, String> {
+        if !(0. ..=1.).contains(&flip_rate) {
+            return Err(format!("The flip rate must be between 0 and 1, not {}", flip_rate));
+        }
+        self.flip_rate = flip_rate;
+        Ok(self)
+    }
+
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    pub fn crossover(&self) -> BitCrossover {
+        self.crossover
+    }
+
+    pub fn flip_rate(&self) -> f64 {
+        self.flip_rate
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BitString(pub Vec<bool>);
+
+impl Genome for BitString {
+    type Context = BitStringOperators;
+
+    fn random<R: Rng + ?Sized>(context: &BitStringOperators, rng: &mut R) -> Self {
+        BitString((0..context.length).map(|_| rng.gen()).collect())
+    }
+
+    fn crossover<R: Rng + ?Sized>(&self, other: &Self, context: &BitStringOperators, rng: &mut R) -> Self {
+        let n = self.0.len();
+        let genes = match context.crossover {
+            BitCrossover::OnePoint => {
+                let c = rng.gen_range(0..=n);
+                self.0[..c].iter().chain(&other.0[c..]).copied().collect()
+            },
+            BitCrossover::TwoPoint => {
+                let (a, b) = cut_points(n, rng);
+                (0..n).map(|i| if (a..b).contains(&i) { self.0[i] } else { other.0[i] }).collect()
+            },
+            BitCrossover::Uniform => (0..n).map(|i| if rng.gen() { self.0[i] } else { other.0[i] }).collect(),
+        };
+        BitString(genes)
+    }
+
+    fn mutate<R: Rng + ?Sized>(&mut self, context: &BitStringOperators, rng: &mut R) {
+        for bit in self.0.iter_mut() {
+            if rng.gen_bool(context.flip_rate) {
+                *bit = !*bit;
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PermutationCrossover {
+    /// Partially mapped: a segment of one parent, the rest placed where the mapping between
+    /// the segments of the two parents leads
+    Pmx,
+    /// Order: a segment of one parent, the rest in the order of the other parent
+    Ox,
+    /// Cycle: every element in the position it has in one of the parents, taking the cycles of
+    /// positions from each parent in turn
+    Cycle,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PermutationMutation {
+    /// Swaps two random elements
+    Swap,
+    /// Reverses a random segment, like a 2-opt move of a tour
+    Inversion,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PermutationOperators {
+    length: usize,
+    crossover: PermutationCrossover,
+    mutation: PermutationMutation,
+}
+
+impl PermutationOperators {
+    /// Order crossover and inversion mutation
+    pub fn new(length: usize) -> Self {
+        Self { length, crossover: PermutationCrossover::Ox, mutation: PermutationMutation::Inversion }
+    }
+
+    pub fn with_crossover(mut self, crossover: PermutationCrossover) -> Self {
+        self.crossover = crossover;
+        self
+    }
+
+    pub fn with_mutation(mut self, mutation: PermutationMutation) -> Self {
+        self.mutation = mutation;
+        self
+    }
+
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    pub fn crossover(&self) -> PermutationCrossover {
+        self.crossover
+    }
+
+    pub fn mutation(&self) -> PermutationMutation {
+        self.mutation
+    }
+}
+
+/// A permutation of 0..n
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Permutation(pub Vec<usize>);
+
+impl Genome for Permutation {
+    type Context = PermutationOperators;
+
+    fn random<R: Rng + ?Sized>(context: &PermutationOperators, rng: &mut R) -> Self {
+        let mut elements: Vec<usize> = (0..context.length).collect();
+        /* Fisher-Yates */
+        for i in (1..elements.len()).rev() {
+            elements.swap(i, rng.gen_range(0..=i));
+        }
+        Permutation(elements)
+    }
+
+    fn crossover<R: Rng + ?Sized>(&self, other: &Self, context: &PermutationOperators, rng: &mut R) -> Self {
+        match context.crossover {
+            PermutationCrossover::Pmx => {
+                let (a, b) = cut_points(self.0.len(), rng);
+                Permutation(pmx(&self.0, &other.0, a, b))
+            },
+            PermutationCrossover::Ox => {
+                let (a, b) = cut_points(self.0.len(), rng);
+                Permutation(ox(&self.0, &other.0, a, b))
+            },
+            PermutationCrossover::Cycle => Permutation(cycle(&self.0, &other.0)),
+        }
+    }
+
+    fn mutate<R: Rng + ?Sized>(&mut self, context: &PermutationOperators, rng: &mut R) {
+        let n = self.0.len();
+        if n < 2 {
+            return;
+        }
+        match context.mutation {
+            PermutationMutation::Swap => {
+                let (i, j) = (rng.gen_range(0..n), rng.gen_range(0..n));
+                self.0.swap(i, j);
+            },
+            PermutationMutation::Inversion => {
+                let (a, b) = cut_points(n, rng);
+                self.0[a..b].reverse();
+            },
+        }
+    }
+}
+
+/// Positions of the elements of a permutation
+fn positions(permutation: &[usize]) -> Vec<usize> {
+    let mut positions = vec![0; permutation.len()];
+    for (i, element) in permutation.iter().enumerate() {
+        positions[*element] = i;
+    }
+    positions
+}
+
+/// The child of PMX keeping the segment a..b of the first parent
+pub fn pmx(parent1: &[usize], parent2: &[usize], a: usize, b: usize) -> Vec<usize> {
+    let n = parent1.len();
+    let mut child: Vec<Option<usize>> = vec![None; n];
+    let mut in_segment = vec![false; n];
+    for (slot, element) in child[a..b].iter_mut().zip(&parent1[a..b]) {
+        *slot = Some(*element);
+        in_segment[*element] = true;
+    }
+    let positions2 = positions(parent2);
+    for (i, element) in parent2.iter().enumerate().take(b).skip(a) {
+        if in_segment[*element] {
+            continue;
+        }
+        /* Follow the mapping until a position outside the segment */
+        let mut j = i;
+        while (a..b).contains(&j) {
+            j = positions2[parent1[j]];
+        }
+        child[j] = Some(*element);
+    }
+    child.iter().zip(parent2).map(|(c, p)| c.unwrap_or(*p)).collect()
+}
+
+/// The child of OX keeping the segment a..b of the first parent
+pub fn ox(parent1: &[usize], parent2: &[usize], a: usize, b: usize) -> Vec<usize> {
+    let n = parent1.len();
+    let mut in_segment = vec![false; n];
+    for element in &parent1[a..b] {
+        in_segment[*element] = true;
+    }
+    /* The others, in the order of the second parent starting after the segment */
+    let mut rest = (0..n).map(|i| parent2[(b + i) % n]).filter(|e| !in_segment[*e]);
+    let mut child = parent1.to_vec();
+    for i in (0..n).map(|i| (b + i) % n).filter(|i| !(a..b).contains(i)) {
+        child[i] = rest.next().unwrap();
+    }
+    child
+}
+
+/// The child of cycle crossover taking the first cycle from the first parent
+pub fn cycle(parent1: &[usize], parent2: &[usize]) -> Vec<usize> {
+    let n = parent1.len();
+    let positions1 = positions(parent1);
+    let mut child: Vec<Option<usize>> = vec![None; n];
+    let mut from_first = true;
+    for start in 0..n {
+        if child[start].is_some() {
+            continue;
+        }
+        let mut i = start;
+        while child[i].is_none() {
+            child[i] = Some(if from_first { parent1[i] } else { parent2[i] });
+            i = positions1[parent2[i]];
+        }
+        from_first = !from_first;
+    }
+    child.into_iter().map(Option::unwrap).collect()
+}
+
+/* Two points 0 <= a <= b <= n */
+fn cut_points<R: Rng + ?Sized>(n: usize, rng: &mut R) -> (usize, usize) {
+    let (a, b) = (rng.gen_range(0..=n), rng.gen_range(0..=n));
+    (a.min(b), a.max(b))
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum RealCrossover {
+    /// Every variable uniform in the interval between the parents, widened by alpha times its
+    /// length on both sides
+    Blx { alpha: f64 },
+    /// Simulated binary crossover, which spreads the children around the parents like one-point
+    /// crossover of binary coded reals; the larger eta, the closer to the parents
+    Sbx { eta: f64 },
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct RealVectorOperators {
+    bounds: Vec<(f64, f64)>,
+    crossover: RealCrossover,
+    sigma: f64,
+}
+
+impl RealVectorOperators {
+    /// BLX-0.5 crossover and mutations of a tenth of the width of the bounds, which are the
+    /// lowest and highest value of each variable
+    pub fn new(bounds: Vec<(f64, f64)>) -> Result<Self, String> {
+        if let Some((i, (low, high))) = bounds.iter().enumerate().find(|(_, (low, high))| !(low.is_finite() && high.is_finite() && low <= high)) {
+            return Err(format!("The bounds of variable {} go from {} to {}", i, low, high));
+        }
+        Ok(Self { bounds, crossover: RealCrossover::Blx { alpha: 0.5 }, sigma: 0.1 })
+    }
+
+    pub fn with_crossover(mut self, crossover: RealCrossover) -> Result<Self, String> {
+        let parameter = match crossover {
+            RealCrossover::Blx { alpha } => alpha,
+            RealCrossover::Sbx { eta } => eta,
+        };
+        if !(parameter.is_finite() && parameter >= 0.) {
+            return Err(format!("The parameter of {:?} must not be negative", crossover));
+        }
+        self.crossover = crossover;
+        Ok(self)
+    }
+
+    /// Sets the standard deviation of the Gaussian noise added to each variable by a mutation, as
+    /// a fraction of the width of its bounds
+    pub fn with_sigma(mut self, sigma: f64) -> Result<Self, String> {
+        if !(sigma.is_finite() && sigma >= 0.) {
+            return Err(format!("The standard deviation of mutations must not be negative, not {}", sigma));
+        }
+        self.sigma = sigma;
+        Ok(self)
+    }
+
+    pub fn bounds(&self) -> &[(f64, f64)] {
+        &self.bounds
+    }
+
+    pub fn crossover(&self) -> RealCrossover {
+        self.crossover
+    }
+
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+}
+
+/// A point within the bounds of the context
+#[derive(Clone, PartialEq, Debug)]
+pub struct RealVector(pub Vec<f64>);
+
+impl Genome for RealVector {
+    type Context = RealVectorOperators;
+
+    fn random<R: Rng + ?Sized>(context: &RealVectorOperators, rng: &mut R) -> Self {
+        RealVector(context.bounds.iter().map(|(low, high)| uniform(*low, *high, rng)).collect())
+    }
+
+    fn crossover<R: Rng + ?Sized>(&self, other: &Self, context: &RealVectorOperators, rng: &mut R) -> Self {
+        let genes = self.0.iter().zip(&other.0).zip(&context.bounds).map(|((x1, x2), (low, high))| {
+            let x = match context.crossover {
+                RealCrossover::Blx { alpha } => {
+                    let (min, max) = (x1.min(*x2), x1.max(*x2));
+                    let d = alpha * (max - min);
+                    uniform(min - d, max + d, rng)
+                },
+                RealCrossover::Sbx { eta } => {
+                    let u: f64 = rng.gen();
+                    let beta = if u <= 0.5 {
+                        (2. * u).powf(1. / (eta + 1.))
+                    } else {
+                        (1. / (2. * (1. - u))).powf(1. / (eta + 1.))
+                    };
+                    0.5 * ((1. + beta) * x1 + (1. - beta) * x2)
+                },
+            };
+            x.clamp(*low, *high)
+        });
+        RealVector(genes.collect())
+    }
+
+    fn mutate<R: Rng + ?Sized>(&mut self, context: &RealVectorOperators, rng: &mut R) {
+        for (x, (low, high)) in self.0.iter_mut().zip(&context.bounds) {
+            *x = (*x + context.sigma * (high - low) * standard_normal(rng)).clamp(*low, *high);
+        }
+    }
+}
+
+/* Uniform in [low, high], which may be the same */
+fn uniform<R: Rng + ?Sized>(low: f64, high: f64, rng: &mut R) -> f64 {
+    low + (high - low) * rng.gen::<f64>()
+}
+
+/* Box-Muller transform */
+fn standard_normal<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    let u1: f64 = 1. - rng.gen::<f64>();
+    let u2: f64 = rng.gen();
+    (-2. * u1.ln()).sqrt() * (2. * std::f64::consts::PI * u2).cos()
+}
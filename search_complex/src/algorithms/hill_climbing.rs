@@ -1,47 +1,264 @@
-///
-/// function Hill-Climbing(problem) returns a state that is a local maximum
-///     current <- problem.Initial
-///     while true do
-///         neighbor <- a highest-valued successor state of current
-///         if Value(neighbor) <= Value(current) then return current
-///         current <- neighbor
-///
-
-use std::fmt::Debug;
-
-use crate::algorithms::{
-    node::Node,
-    problem::*
-};
-
-pub fn hill_climbing<P, S, A>(problem: &P) -> SearchResult<S, A>
-where
-    P: Problem<S, A>,
-    S: Clone + Debug,
-    A: Clone + Debug
-{
-    let mut current = problem.get_initial_node();
-
-    loop {
-        let neighbor = highest_valued_successor(problem, current.clone())?;
-        if neighbor.path_cost <= current.path_cost {
-            return Ok(current);
+//!
+//! function Hill-Climbing(problem) returns a state that is a local maximum
+//!     current <- problem.Initial
+//!     while true do
+//!         neighbor <- a highest-valued successor state of current
+//!         if Value(neighbor) <= Value(current) then return current
+//!         current <- neighbor
+//!
+//! Values are compared by `LocalSearchProblem::score`, so a problem that minimises its objective
+//! descends to a local minimum instead. Ties between the highest-valued successors are broken
+//! at random, or sideways moves would go back and forth between the same two states.
+//!
+//! The variants from section 4.1.1 of AIMA:
+//!     - sideways moves: also moves to a successor of equal value, to cross shoulders, but
+//!       at most a given number of times in a row
+//!     - stochastic: moves to a random uphill successor instead of the steepest one
+//!     - first-choice: generates random successors until one is better than the current state,
+//!       for neighbourhoods too large to generate whole; with sideways moves, it also moves to a
+//!       random successor of equal value, at most a given number of times in a row
+//!     - random restart: climbs again from random states until an optimal state is found or
+//!       the restarts run out, and keeps the best state of all the climbs
+//!
+
+/* External crates */
+use rand::Rng;
+
+/* Internal crates */
+use crate::algorithms::local_search::{LocalSearchProblem, LocalSearchResult};
+
+/// How each climb of `random_restart_hill_climbing` chooses its moves
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HillClimbing {
+    SteepestAscent,
+    /// Steepest ascent allowing this many sideways moves in a row
+    Sideways(usize),
+    Stochastic,
+    /// First-choice giving up after this many worse random successors in a row
+    FirstChoice(usize),
+    /// First-choice allowing `max_sideways` moves in a row that do not improve the value
+    FirstChoiceSideways { max_tries: usize, max_sideways: usize },
+}
+
+impl HillClimbing {
+    fn climb<P, S, R>(self, problem: &P, start: S, rng: &mut R, trajectory: &mut Vec<f64>) -> (S, usize)
+    where
+        P: LocalSearchProblem<S>,
+        R: Rng + ?Sized
+    {
+        match self {
+            HillClimbing::SteepestAscent => steepest_ascent(problem, start, 0, rng, trajectory),
+            HillClimbing::Sideways(max_sideways) => steepest_ascent(problem, start, max_sideways, rng, trajectory),
+            HillClimbing::Stochastic => stochastic(problem, start, rng, trajectory),
+            HillClimbing::FirstChoice(max_tries) => first_choice(problem, start, max_tries, 0, rng, trajectory),
+            HillClimbing::FirstChoiceSideways { max_tries, max_sideways } => {
+                first_choice(problem, start, max_tries, max_sideways, rng, trajectory)
+            },
+        }
+    }
+}
+
+/// Steepest ascent from the initial state, breaking ties with `rng`
+pub fn hill_climbing<P, S, R>(problem: &P, rng: &mut R) -> LocalSearchResult<S>
+where
+    P: LocalSearchProblem<S>,
+    R: Rng + ?Sized
+{
+    let mut trajectory = Vec::new();
+    let (state, steps) = steepest_ascent(problem, problem.initial(), 0, rng, &mut trajectory);
+    LocalSearchResult::new(problem, state, steps, 0, trajectory)
+}
+
+/// Steepest ascent which may make up to `max_sideways` moves in a row that do not improve the value
+pub fn hill_climbing_with_sideways_moves<P, S, R>(problem: &P, max_sideways: usize, rng: &mut R) -> LocalSearchResult<S>
+where
+    P: LocalSearchProblem<S>,
+    R: Rng + ?Sized
+{
+    let mut trajectory = Vec::new();
+    let (state, steps) = steepest_ascent(problem, problem.initial(), max_sideways, rng, &mut trajectory);
+    LocalSearchResult::new(problem, state, steps, 0, trajectory)
+}
+
+/// Moves to an uphill successor chosen at random, until there is none
+pub fn stochastic_hill_climbing<P, S, R>(problem: &P, rng: &mut R) -> LocalSearchResult<S>
+where
+    P: LocalSearchProblem<S>,
+    R: Rng + ?Sized
+{
+    let mut trajectory = Vec::new();
+    let (state, steps) = stochastic(problem, problem.initial(), rng, &mut trajectory);
+    LocalSearchResult::new(problem, state, steps, 0, trajectory)
+}
+
+/// Moves to the first random successor better than the current state, and stops after
+/// `max_tries` random successors in a row which are not
+pub fn first_choice_hill_climbing<P, S, R>(problem: &P, max_tries: usize, rng: &mut R) -> LocalSearchResult<S>
+where
+    P: LocalSearchProblem<S>,
+    R: Rng + ?Sized
+{
+    let mut trajectory = Vec::new();
+    let (state, steps) = first_choice(problem, problem.initial(), max_tries, 0, rng, &mut trajectory);
+    LocalSearchResult::new(problem, state, steps, 0, trajectory)
+}
+
+/// First-choice which also moves to a random successor of equal value, up to `max_sideways`
+/// times in a row. Those moves do not improve, so they count towards the `max_tries`.
+pub fn first_choice_hill_climbing_with_sideways_moves<P, S, R>(problem: &P, max_tries: usize, max_sideways: usize, rng: &mut R) -> LocalSearchResult<S>
+where
+    P: LocalSearchProblem<S>,
+    R: Rng + ?Sized
+{
+    let mut trajectory = Vec::new();
+    let (state, steps) = first_choice(problem, problem.initial(), max_tries, max_sideways, rng, &mut trajectory);
+    LocalSearchResult::new(problem, state, steps, 0, trajectory)
+}
+
+/// Climbs from the initial state, then from up to `max_restarts` random states, until a climb
+/// ends on an optimal state. Returns the best state found by any climb.
+/// Problems without `random_state` restart from their initial state, which only helps the
+/// stochastic variants.
+pub fn random_restart_hill_climbing<P, S, R>(problem: &P, variant: HillClimbing, max_restarts: usize, rng: &mut R) -> LocalSearchResult<S>
+where
+    P: LocalSearchProblem<S>,
+    R: Rng + ?Sized
+{
+    let mut trajectory = Vec::new();
+    let (mut best, mut steps) = variant.climb(problem, problem.initial(), rng, &mut trajectory);
+    let mut best_score = problem.score(&best);
+    let mut restarts = 0;
+
+    while restarts < max_restarts && !problem.is_optimal(&best) {
+        restarts += 1;
+        let start = problem.random_state(rng).unwrap_or_else(|| problem.initial());
+        let (state, climb_steps) = variant.climb(problem, start, rng, &mut trajectory);
+        steps += climb_steps;
+        let score = problem.score(&state);
+        if score > best_score {
+            best = state;
+            best_score = score;
+        }
+    }
+
+    LocalSearchResult::new(problem, best, steps, restarts, trajectory)
+}
+
+/* Returns the state it stopped on and the number of moves, and adds the values to the trajectory */
+fn steepest_ascent<P, S, R>(problem: &P, start: S, max_sideways: usize, rng: &mut R, trajectory: &mut Vec<f64>) -> (S, usize)
+where
+    P: LocalSearchProblem<S>,
+    R: Rng + ?Sized
+{
+    let mut current = start;
+    trajectory.push(problem.value(&current));
+    let mut current_score = problem.score(&current);
+    let (mut steps, mut sideways) = (0, 0);
+
+    while !problem.is_optimal(&current) {
+        let Some((neighbor, score)) = highest_valued_successor(problem, &current, rng) else {
+            break;
+        };
+        if score > current_score {
+            sideways = 0;
+        } else if score == current_score && sideways < max_sideways {
+            sideways += 1;
+        } else {
+            break;
         }
         current = neighbor;
+        current_score = score;
+        steps += 1;
+        trajectory.push(problem.value(&current));
+    }
+    (current, steps)
+}
+
+/* One of the best neighbours, chosen at random, and its score, or None if there are none */
+fn highest_valued_successor<P, S, R>(problem: &P, state: &S, rng: &mut R) -> Option<(S, f64)>
+where
+    P: LocalSearchProblem<S>,
+    R: Rng + ?Sized
+{
+    let mut best: Option<(S, f64)> = None;
+    let mut ties = 0;
+    for neighbor in problem.neighbors(state) {
+        let score = problem.score(&neighbor);
+        /* A NaN would win the match below and then never lose a comparison */
+        if !score.is_finite() {
+            continue;
+        }
+        match &best {
+            Some((_, best_score)) if score < *best_score => {},
+            /* Each of the k best so far is kept with probability 1/k */
+            Some((_, best_score)) if score == *best_score => {
+                ties += 1;
+                if rng.gen_range(0..ties) == 0 {
+                    best = Some((neighbor, score));
+                }
+            },
+            _ => {
+                best = Some((neighbor, score));
+                ties = 1;
+            },
+        }
     }
+    best
 }
 
+fn stochastic<P, S, R>(problem: &P, start: S, rng: &mut R, trajectory: &mut Vec<f64>) -> (S, usize)
+where
+    P: LocalSearchProblem<S>,
+    R: Rng + ?Sized
+{
+    let mut current = start;
+    trajectory.push(problem.value(&current));
+    let mut steps = 0;
+
+    while !problem.is_optimal(&current) {
+        let current_score = problem.score(&current);
+        let mut uphill: Vec<S> = problem.neighbors(&current)
+            .into_iter()
+            .filter(|neighbor| problem.score(neighbor) > current_score)
+            .collect();
+        if uphill.is_empty() {
+            break;
+        }
+        current = uphill.swap_remove(rng.gen_range(0..uphill.len()));
+        steps += 1;
+        trajectory.push(problem.value(&current));
+    }
+    (current, steps)
+}
 
-fn highest_valued_successor<P, S, A>(problem: &P, node: Node<S, A>) -> SearchResult<S, A>
+fn first_choice<P, S, R>(problem: &P, start: S, max_tries: usize, max_sideways: usize, rng: &mut R, trajectory: &mut Vec<f64>) -> (S, usize)
 where
-    P: Problem<S, A>,
-    S: Clone + Debug,
-    A: Clone + Debug
+    P: LocalSearchProblem<S>,
+    R: Rng + ?Sized
 {
-    let mut neighbors = expand(problem, node.clone());
-    
-    /* Sort descending */
-    neighbors.reverse();
+    let mut current = start;
+    trajectory.push(problem.value(&current));
+    let mut current_score = problem.score(&current);
+    let (mut steps, mut tries, mut sideways) = (0, 0, 0);
 
-    return Ok(neighbors.get(0).unwrap().clone());
+    while tries < max_tries && !problem.is_optimal(&current) {
+        let Some(neighbor) = problem.random_neighbor(&current, rng) else {
+            break;
+        };
+        let score = problem.score(&neighbor);
+        if score > current_score {
+            (tries, sideways) = (0, 0);
+        } else if score == current_score && sideways < max_sideways {
+            tries += 1;
+            sideways += 1;
+        } else {
+            tries += 1;
+            continue;
+        }
+        current = neighbor;
+        current_score = score;
+        steps += 1;
+        trajectory.push(problem.value(&current));
+    }
+    (current, steps)
 }
@@ -0,0 +1,225 @@
+//!
+//! The island model of the genetic algorithm: several populations (islands) evolve apart, each on
+//! its own thread, and every few generations the fittest individuals of each island migrate to
+//! its neighbours, where they replace the least fit. Islands drift towards different solutions,
+//! which keeps diversity longer than one large population, and migration spreads the good ones.
+//!
+//! The islands evolve in epochs of `Islands::migration_interval` generations; the threads meet
+//! after each epoch for the migration. Every island has its own random number generator, seeded
+//! from `Islands::seed`, so a seeded run gives the same result whatever the threads do.
+//!
+//! The search stops when an island reaches the target fitness, when the fittest individual of
+//! all islands has not improved for the maximum stagnation, or after the maximum generations of
+//! the `Genetic` settings, which apply to every island alike.
+//!
+
+/* Std library */
+use std::thread;
+
+/* External crates */
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/* Internal crates */
+use crate::algorithms::genetic_algorithm::{
+    first_generation,
+    next_generation,
+    sort_by_fitness,
+    validate,
+    Genetic,
+    Genome,
+    Termination,
+};
+
+/// Which islands the migrants of an island go to
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Topology {
+    /// Island i sends its migrants to island i + 1, and the last island to the first one
+    Ring,
+    /// Every island sends its migrants to all the others
+    FullyConnected,
+}
+
+/// Settings of `island_genetic_algorithm`, besides those of each island
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Islands {
+    pub islands: usize,
+    pub topology: Topology,
+    /// Number of generations between migrations
+    pub migration_interval: usize,
+    /// Number of the fittest individuals an island sends to each of its neighbours
+    pub migrants: usize,
+    /// Seed of the random number generators of the islands, or None to seed them at random
+    pub seed: Option<u64>,
+}
+
+impl Default for Islands {
+    fn default() -> Self {
+        Self { islands: 4, topology: Topology::Ring, migration_interval: 10, migrants: 2, seed: None }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct IslandStatistics {
+    /// The fitness of the fittest individual the island ever had
+    pub fitness: f64,
+    /// The fitness of the fittest individual of every generation, the first one included
+    pub history: Vec<f64>,
+    /// Number of individuals which migrated to the island
+    pub immigrants: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct IslandResult<G> {
+    /// The fittest individual of any island and generation
+    pub best: G,
+    pub fitness: f64,
+    /// The island it evolved on (or migrated to first)
+    pub island: usize,
+    /// Number of generations bred on every island after the first one
+    pub generations: usize,
+    pub termination: Termination,
+    pub islands: Vec<IslandStatistics>,
+}
+
+struct Island<G> {
+    population: Vec<(G, f64)>,
+    rng: StdRng,
+    statistics: IslandStatistics,
+}
+
+impl<G: Genome> Island<G> {
+    /* Breeds up to the given number of generations, and stops early on the target fitness */
+    fn evolve<F>(&mut self, generations: usize, context: &G::Context, fitness: &F, genetic: &Genetic)
+    where
+        F: Fn(&G) -> f64
+    {
+        for _ in 0..generations {
+            if genetic.target_fitness.is_some_and(|target| self.statistics.fitness >= target) {
+                break;
+            }
+            self.population = next_generation(&self.population, context, fitness, genetic, &mut self.rng);
+            let fittest = self.population[0].1;
+            self.statistics.history.push(fittest);
+            if fittest > self.statistics.fitness {
+                self.statistics.fitness = fittest;
+            }
+        }
+    }
+}
+
+pub fn island_genetic_algorithm<G, F>(context: &G::Context, fitness: F, genetic: &Genetic, islands: &Islands) -> Result<IslandResult<G>, String>
+where
+    G: Genome + Send,
+    G::Context: Sync,
+    F: Fn(&G) -> f64 + Sync
+{
+    validate(genetic)?;
+    if islands.islands == 0 {
+        return Err(String::from("There must be at least one island"));
+    }
+    if islands.migration_interval == 0 {
+        return Err(String::from("The migration interval must be at least one generation"));
+    }
+    if islands.migrants > genetic.population_size {
+        return Err(format!("Cannot send {} migrants from a population of {}", islands.migrants, genetic.population_size));
+    }
+
+    let seed = islands.seed.unwrap_or_else(|| rand::thread_rng().gen());
+    let mut seeds = StdRng::seed_from_u64(seed);
+    let mut population: Vec<Island<G>> = (0..islands.islands)
+        .map(|_| {
+            let mut rng = StdRng::seed_from_u64(seeds.gen());
+            let population = first_generation(context, &fitness, genetic, &mut rng);
+            let fittest = population[0].1;
+            let statistics = IslandStatistics { fitness: fittest, history: vec![fittest], immigrants: 0 };
+            Island { population, rng, statistics }
+        })
+        .collect();
+
+    let (mut best, mut best_fitness, mut best_island) = fittest(&population);
+    let (mut generations, mut stagnation) = (0, 0);
+
+    let termination = loop {
+        if let Some(termination) = termination(genetic, best_fitness, stagnation, generations) {
+            break termination;
+        }
+
+        let mut epoch = islands.migration_interval.min(genetic.max_generations - generations);
+        if let Some(max) = genetic.max_stagnation {
+            epoch = epoch.min(max - stagnation);
+        }
+        thread::scope(|scope| {
+            for island in population.iter_mut() {
+                let fitness = &fitness;
+                scope.spawn(move || island.evolve(epoch, context, fitness, genetic));
+            }
+        });
+        generations += epoch;
+
+        let (fittest, fittest_fitness, island) = fittest(&population);
+        if fittest_fitness > best_fitness {
+            (best, best_fitness, best_island) = (fittest, fittest_fitness, island);
+            stagnation = 0;
+        } else {
+            stagnation += epoch;
+        }
+        /* Migrants sent after the last epoch would never breed, so there is no migration then */
+        if termination(genetic, best_fitness, stagnation, generations).is_none() {
+            migrate(&mut population, islands, genetic);
+        }
+    };
+
+    let islands = population.into_iter().map(|island| island.statistics).collect();
+    Ok(IslandResult { best, fitness: best_fitness, island: best_island, generations, termination, islands })
+}
+
+/* Why the search stops after the given generations, if it does */
+fn termination(genetic: &Genetic, best_fitness: f64, stagnation: usize, generations: usize) -> Option<Termination> {
+    if genetic.target_fitness.is_some_and(|target| best_fitness >= target) {
+        Some(Termination::TargetFitness)
+    } else if genetic.max_stagnation.is_some_and(|max| stagnation >= max) {
+        Some(Termination::Stagnation)
+    } else if generations >= genetic.max_generations {
+        Some(Termination::Generations)
+    } else {
+        None
+    }
+}
+
+/* The fittest individual of all islands, its fitness and its island */
+fn fittest<G: Clone>(islands: &[Island<G>]) -> (G, f64, usize) {
+    let mut best = 0;
+    for (i, island) in islands.iter().enumerate() {
+        if island.population[0].1 > islands[best].population[0].1 {
+            best = i;
+        }
+    }
+    let (individual, fitness) = islands[best].population[0].clone();
+    (individual, fitness, best)
+}
+
+/* The migrants replace the least fit individuals, but never the elites of the island */
+fn migrate<G: Clone>(population: &mut [Island<G>], islands: &Islands, genetic: &Genetic) {
+    let n = population.len();
+    let emigrants: Vec<Vec<(G, f64)>> = population.iter()
+        .map(|island| island.population[..islands.migrants].to_vec())
+        .collect();
+
+    for (i, island) in population.iter_mut().enumerate() {
+        let sources: Vec<usize> = match islands.topology {
+            Topology::Ring => vec![(i + n - 1) % n],
+            Topology::FullyConnected => (0..n).collect(),
+        };
+        let free = island.population.len() - genetic.elitism;
+        let immigrants: Vec<(G, f64)> = sources.into_iter()
+            .filter(|source| *source != i)
+            .flat_map(|source| emigrants[source].iter().cloned())
+            .take(free)
+            .collect();
+        let keep = island.population.len() - immigrants.len();
+        island.statistics.immigrants += immigrants.len();
+        island.population.truncate(keep);
+        island.population.extend(immigrants);
+        sort_by_fitness(&mut island.population);
+    }
+}
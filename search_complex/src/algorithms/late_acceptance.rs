@@ -0,0 +1,86 @@
+//!
+//! Late acceptance hill climbing (Burke and Bykov): a random neighbour is accepted if it is no
+//! worse than the current state, or no worse than the state the search was in `history` iterations
+//! ago. Comparing with the past lets it walk downhill a little, and as the past gets better it
+//! accepts less and less, like annealing, but with a single setting which does not depend on the
+//! scale of the values.
+//!
+//!     function Late-Acceptance-Hill-Climbing(problem, L) returns a state
+//!         current <- problem.Initial
+//!         f[k] <- Value(current) for k = 0 .. L - 1
+//!         for i = 0 to infty do
+//!             next <- a randomly selected successor of current
+//!             v <- i mod L
+//!             if Value(next) >= f[v] or Value(next) >= Value(current) then current <- next
+//!             f[v] <- Value(current)
+//!
+//! The search returns the best state it went through. It stops on an optimal state, after
+//! `LateAcceptance::max_iterations` or after `LateAcceptance::max_stagnation` iterations without a
+//! better state.
+//!
+
+/* External crates */
+use rand::Rng;
+
+/* Internal crates */
+use crate::algorithms::local_search::{LocalSearchProblem, LocalSearchResult};
+
+/// Settings of `late_acceptance_hill_climbing`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LateAcceptance {
+    /// Number of iterations L the search looks back; 1 is plain hill climbing with sideways moves
+    pub history: usize,
+    pub max_iterations: usize,
+    pub max_stagnation: Option<usize>,
+}
+
+impl Default for LateAcceptance {
+    fn default() -> Self {
+        Self { history: 50, max_iterations: 100_000, max_stagnation: None }
+    }
+}
+
+pub fn late_acceptance_hill_climbing<P, S, R>(problem: &P, late: &LateAcceptance, rng: &mut R) -> Result<LocalSearchResult<S>, String>
+where
+    P: LocalSearchProblem<S>,
+    S: Clone,
+    R: Rng + ?Sized
+{
+    if late.history == 0 {
+        return Err(String::from("The history must be at least one iteration long"));
+    }
+
+    let mut current = problem.initial();
+    let mut current_score = problem.score(&current);
+    let (mut best, mut best_score) = (current.clone(), current_score);
+    let mut history = vec![current_score; late.history];
+    let mut trajectory = vec![problem.value(&current)];
+    let (mut steps, mut stagnation) = (0, 0);
+
+    while steps < late.max_iterations
+        && !problem.is_optimal(&current)
+        && late.max_stagnation.is_none_or(|max| stagnation < max)
+    {
+        let Some(next) = problem.random_neighbor(&current, rng) else {
+            break;
+        };
+        let score = problem.score(&next);
+        let v = steps % late.history;
+        if score >= history[v] || score >= current_score {
+            current = next;
+            current_score = score;
+        }
+        history[v] = current_score;
+        steps += 1;
+        trajectory.push(problem.value(&current));
+
+        if current_score > best_score {
+            (best, best_score) = (current.clone(), current_score);
+            stagnation = 0;
+        } else {
+            stagnation += 1;
+        }
+    }
+
+    Ok(LocalSearchResult::new(problem, best, steps, 0, trajectory))
+}
@@ -0,0 +1,99 @@
+//!
+//! Local search problems, from section 4.1 of AIMA.
+//!
+//! Local search keeps only a current state and moves to neighbouring states, looking for the
+//! state with the best value of an objective function. There is no path, no action and no path
+//! cost: the objective is a function of the state alone, and the problem says whether it is to
+//! be maximised (like the height of a landscape) or minimised (like the length of a tour or the
+//! number of attacking queens).
+//!
+//! The algorithms compare states by their `score`, which is the value when maximising and the
+//! negated value when minimising, so that a higher score is always better.
+//!
+
+/* External crates */
+use rand::Rng;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Objective {
+    Maximize,
+    Minimize,
+}
+
+impl Objective {
+    /// Whether the value `a` is strictly better than the value `b`
+    pub fn is_better(self, a: f64, b: f64) -> bool {
+        match self {
+            Objective::Maximize => a > b,
+            Objective::Minimize => a < b,
+        }
+    }
+}
+
+pub trait LocalSearchProblem<S> {
+    /// The state the search starts from
+    fn initial(&self) -> S;
+
+    /// Every state one local move away from `state`
+    fn neighbors(&self, state: &S) -> Vec<S>;
+
+    /// The objective function
+    fn value(&self, state: &S) -> f64;
+
+    /// Whether `value` is to be maximised or minimised
+    fn objective(&self) -> Objective {
+        Objective::Maximize
+    }
+
+    /// A state chosen at random, to restart the search from. None if the problem can only
+    /// start from its initial state.
+    fn random_state<R: Rng + ?Sized>(&self, _rng: &mut R) -> Option<S> {
+        None
+    }
+
+    /// Whether the state is known to be a global optimum, like a board without attacking
+    /// queens. Searches stop early on such a state; by default none is known.
+    fn is_optimal(&self, _state: &S) -> bool {
+        false
+    }
+
+    /// A neighbour chosen uniformly at random, or None if the state has no neighbours.
+    /// Problems with many neighbours should generate a single one instead of all of them.
+    fn random_neighbor<R: Rng + ?Sized>(&self, state: &S, rng: &mut R) -> Option<S> {
+        let mut neighbors = self.neighbors(state);
+        if neighbors.is_empty() {
+            return None;
+        }
+        Some(neighbors.swap_remove(rng.gen_range(0..neighbors.len())))
+    }
+
+    /// The value, negated when minimising: higher scores are always better
+    fn score(&self, state: &S) -> f64 {
+        match self.objective() {
+            Objective::Maximize => self.value(state),
+            Objective::Minimize => -self.value(state),
+        }
+    }
+}
+
+/// The state a local search stopped on, and how it got there
+#[derive(Clone, Debug)]
+pub struct LocalSearchResult<S> {
+    pub state: S,
+    /// The objective value of `state`
+    pub value: f64,
+    /// Number of steps made, over all restarts
+    pub steps: usize,
+    /// Number of times the search started again, from a random state or at a higher temperature
+    pub restarts: usize,
+    /// The value of the current state when the search started, started again, and after every
+    /// step, to plot how it converged
+    pub trajectory: Vec<f64>,
+}
+
+impl<S> LocalSearchResult<S> {
+    pub(crate) fn new<P: LocalSearchProblem<S>>(problem: &P, state: S, steps: usize, restarts: usize, trajectory: Vec<f64>) -> Self {
+        let value = problem.value(&state);
+        Self { state, value, steps, restarts, trajectory }
+    }
+}
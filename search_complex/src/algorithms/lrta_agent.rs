@@ -1,23 +1,23 @@
-/// function LRTA*-Agent(problem, s', h) returns an action
-///             s, a, the previous state and action, initially null
-///     persistent: result, a table mapping (s, a) to s', initially empty
-///                 H, a table mapping s to a cost estimate, initially empty
-///
-///     if Is-Goal(s') then return stop
-///     if s' is a new state (not in H) then H[s'] <- h(s')
-///     if s is not null then
-///         result[s,a] <- s'
-///         H[s] <- min_{b in Actions(s)} LRTA*-Cost(s,b,result[s,b],H)
-///
-///     a <- argmin_{b in Actions(s)} LRTA*-Cost(problem,s',b,result[s',b],H)
-///     s <- s'
-///
-///     return a
-///
-/// function LRTA*-Cost(problem,s,a,s',H) returns a cost estimate
-///     if s' is undefined then return h(s)
-///     else return problem.Action-Cost(s,a,s') + H[s']
-///
+//! function LRTA*-Agent(problem, s', h) returns an action
+//!             s, a, the previous state and action, initially null
+//!     persistent: result, a table mapping (s, a) to s', initially empty
+//!                 H, a table mapping s to a cost estimate, initially empty
+//!
+//!     if Is-Goal(s') then return stop
+//!     if s' is a new state (not in H) then H[s'] <- h(s')
+//!     if s is not null then
+//!         result[s,a] <- s'
+//!         H[s] <- min_{b in Actions(s)} LRTA*-Cost(s,b,result[s,b],H)
+//!
+//!     a <- argmin_{b in Actions(s)} LRTA*-Cost(problem,s',b,result[s',b],H)
+//!     s <- s'
+//!
+//!     return a
+//!
+//! function LRTA*-Cost(problem,s,a,s',H) returns a cost estimate
+//!     if s' is undefined then return h(s)
+//!     else return problem.Action-Cost(s,a,s') + H[s']
+//!
 
 use std::{
     collections::HashMap,
@@ -25,23 +25,26 @@ use std::{
 };
 
 use crate::algorithms::{
+    cost::Cost,
     problem::*,
     agent::Agent
 };
 
-pub struct LrtaAgent<P, S, A> {
+pub struct LrtaAgent<P, S, A, C> {
     s: Option<S>,
     a: Option<A>,
     result: HashMap<(S, A), S>,
-    H: HashMap<S, f64>,
-    h: fn(&P, &S) -> f64,
+    /* H in AIMA: the current cost estimate of each visited state */
+    estimates: HashMap<S, C>,
+    h: fn(&P, &S) -> C,
 }
 
-impl<P, S, A> Agent<P, S, A> for LrtaAgent<P, S, A> 
+impl<P, S, A, C> Agent<P, S, A, C> for LrtaAgent<P, S, A, C>
 where
-    P: Problem<S, A>,
+    P: OnlineProblem<S, A, C>,
     S: Clone + Eq + Hash,
-    A: Clone + Eq + Hash
+    A: Clone + Eq + Hash,
+    C: Cost
 {
 
     fn new() -> Self {
@@ -49,7 +52,7 @@ where
             s: None,
             a: None,
             result: HashMap::new(),
-            H: HashMap::new(),
+            estimates: HashMap::new(),
             h: heuristic_function
         }
     }
@@ -59,43 +62,44 @@ where
         if problem.is_goal(&s_star) {
             return problem.stop();
         }
-        if !self.H.contains_key(&s_star) {
-            self.H.insert(s_star.clone(), (self.h)(problem, &s_star));
+        if !self.estimates.contains_key(&s_star) {
+            self.estimates.insert(s_star.clone(), (self.h)(problem, &s_star));
         }
 
-        if self.s.is_some() {
-           self.result.insert((self.s.as_ref().unwrap().clone(), self.a.as_ref().unwrap().clone()), s_star.clone());
-           self.H.insert(self.s.as_ref().unwrap().clone(), self.min_cost(problem));
+        if let Some(s) = &self.s {
+            self.result.insert((s.clone(), self.a.as_ref().unwrap().clone()), s_star.clone());
+            self.estimates.insert(s.clone(), self.min_cost(problem));
         }
 
         self.a = Some(self.argmin_cost(problem, &s_star));
         self.s = Some(s_star.clone());
 
-        return self.a.as_ref().unwrap().clone();   
+        self.a.as_ref().unwrap().clone()
     }
 }
 
-impl<P, S, A> LrtaAgent<P, S, A>
+impl<P, S, A, C> LrtaAgent<P, S, A, C>
 where
-    P: Problem<S, A>,
+    P: OnlineProblem<S, A, C>,
     S: Clone + Eq + Hash,
-    A: Clone + Eq + Hash
+    A: Clone + Eq + Hash,
+    C: Cost
 {
 
-    fn min_cost(&self, problem: &P) -> f64 
+    fn min_cost(&self, problem: &P) -> C
     {
-        let mut minimum = f64::MAX;
+        let mut minimum = C::max_value();
         for action in problem.actions(self.s.as_ref().unwrap()) {
             let cost = self.lrta_cost(problem, self.s.clone(), &action, Some(problem.result(self.s.as_ref().unwrap(), &action)));
             minimum = if cost < minimum { cost } else { minimum };
         }
 
-        return minimum;
+        minimum
     }
 
     fn argmin_cost(&self, problem: &P, s_star: &S) -> A
     {
-        let mut tmp_vec: Vec<(f64, A)> = Vec::new();
+        let mut tmp_vec: Vec<(C, A)> = Vec::new();
 
         for action in problem.actions(s_star) {
             tmp_vec.push((self.lrta_cost(problem, Some(s_star.clone()), &action, Some(problem.result(s_star, &action))), action.clone()));
@@ -103,25 +107,25 @@ where
 
         tmp_vec.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
 
-        return tmp_vec.first().unwrap().clone().1;
+        tmp_vec.first().unwrap().clone().1
     } 
 
-    fn lrta_cost(&self, problem: &P, s: Option<S>, a: &A, s_star: Option<S>) -> f64
+    fn lrta_cost(&self, problem: &P, s: Option<S>, a: &A, s_star: Option<S>) -> C
     {
-        if s_star.is_none() {
-            return (self.h)(problem, &s.unwrap());
-        } else {
-            return problem.action_cost(s.as_ref().unwrap(), a, s_star.as_ref().unwrap()) + (self.h)(problem, s_star.as_ref().unwrap());
+        match s_star {
+            None => (self.h)(problem, &s.unwrap()),
+            Some(s_star) => problem.action_cost(s.as_ref().unwrap(), a, &s_star) + (self.h)(problem, &s_star),
         }
     }
 }
 
 
-fn heuristic_function<P, S, A>(problem: &P, state: &S) -> f64 
+fn heuristic_function<P, S, A, C>(problem: &P, state: &S) -> C
 where
-    P: Problem<S, A>,
+    P: Problem<S, A, C>,
     S: Clone,
-    A: Clone
+    A: Clone,
+    C: Cost
 {
-    return problem.get_heuristic_cost(state);
+    problem.get_heuristic_cost(state)
 }
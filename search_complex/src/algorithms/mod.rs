@@ -1,9 +1,16 @@
-pub mod node;
-pub mod problem;
+pub use search_core::{cost, node, problem};
+pub mod problems;
 pub mod agent;
+pub mod beam_search;
+pub mod continuous;
 pub mod genetic_algorithm;
+pub mod genomes;
 pub mod hill_climbing;
+pub mod island_model;
+pub mod late_acceptance;
+pub mod local_search;
 pub mod lrta_agent;
 pub mod online_dfs_agent;
 pub mod or_search;
 pub mod simulated_annealing;
+pub mod tabu_search;
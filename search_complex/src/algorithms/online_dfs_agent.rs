@@ -1,23 +1,23 @@
-/// function Online-DFS-Agent(problem, s') returns an action
-///             s, a, the previous state and action initially null
-///     persistent: result, a table mapping (s, a) to s', initially empty
-///                 untried, a table mapping s to a list of untried actions
-///                 unbacktracked, a table mapping s to a list of states never backtracked to
-///
-///     if problem.Is-Goal(s') then return stop
-///     if s' is a new state (not in untried) then untried[s'] <- problem.Actions(s')
-///     if s is not null then
-///         result[s,a] <- s'
-///         add s to the front of unbacktracked[s']
-///
-///     if untried[s'] is empty then
-///         if unbacktracked[s'] is empty then return stop
-///         else a <- an action b such that result[s',b] = Pop(unbakctracked[s'])
-///
-///     else a <- Pop(untried[s'])
-///     s <- s'
-///     return a
-///
+//! function Online-DFS-Agent(problem, s') returns an action
+//!             s, a, the previous state and action initially null
+//!     persistent: result, a table mapping (s, a) to s', initially empty
+//!                 untried, a table mapping s to a list of untried actions
+//!                 unbacktracked, a table mapping s to a list of states never backtracked to
+//!
+//!     if problem.Is-Goal(s') then return stop
+//!     if s' is a new state (not in untried) then untried[s'] <- problem.Actions(s')
+//!     if s is not null then
+//!         result[s,a] <- s'
+//!         add s to the front of unbacktracked[s']
+//!
+//!     if untried[s'] is empty then
+//!         if unbacktracked[s'] is empty then return stop
+//!         else a <- an action b such that result[s',b] = Pop(unbakctracked[s'])
+//!
+//!     else a <- Pop(untried[s'])
+//!     s <- s'
+//!     return a
+//!
 
 use std::{
     collections::HashMap,
@@ -25,6 +25,7 @@ use std::{
 };
 
 use crate::algorithms::{
+    cost::Cost,
     problem::*,
     agent::Agent,
 };
@@ -41,11 +42,12 @@ where
     unbacktracked: HashMap<S, Vec<S>>, 
 }
 
-impl<P, S, A> Agent<P, S, A> for OnlineDFSAgent<S, A>
+impl<P, S, A, C> Agent<P, S, A, C> for OnlineDFSAgent<S, A>
 where
-    P: Problem<S, A>,
+    P: OnlineProblem<S, A, C>,
     S: Clone + Eq + Hash,
-    A: Clone + Eq + Hash
+    A: Clone + Eq + Hash,
+    C: Cost
 {
     fn new() -> Self {
         Self {
@@ -67,12 +69,9 @@ where
             self.untried.insert(s_star.clone(), problem.actions(&s_star));
         }
         
-        if self.s.is_some() {
-            self.result.insert((self.s.as_ref().unwrap().clone(), self.a.as_ref().unwrap().clone()), s_star.clone());
-            if !self.unbacktracked.contains_key(&s_star) {
-                self.unbacktracked.insert(s_star.clone(), Vec::new());
-            }
-            self.unbacktracked.get_mut(&s_star).unwrap().push(self.s.as_ref().unwrap().clone());
+        if let Some(s) = &self.s {
+            self.result.insert((s.clone(), self.a.as_ref().unwrap().clone()), s_star.clone());
+            self.unbacktracked.entry(s_star.clone()).or_default().push(s.clone());
         }
 
         if self.untried.get(&s_star).unwrap().is_empty() {
@@ -93,7 +92,7 @@ where
 
         self.s = Some(s_star);
 
-        return self.a.as_ref().unwrap().clone();
+        self.a.as_ref().unwrap().clone()
     }
 
 }
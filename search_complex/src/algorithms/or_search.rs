@@ -1,87 +1,350 @@
-/// function And-Or-Search(problem) returns a conditional plan, or failure
-///     return Or-Search(problem, problem.initial, [])
-///
-/// function Or-Search(problem, state, path) returns a conditional plan, or failure
-///     if problem.is_goal(state) then return the empty plan
-///     if Is-Cycle(path) then return failure
-///     for each action in problem.Actions(state) do
-///         plan <- And-Search(problem, Results(state, action), [state] + path])
-///         if plan != failure then return [action] + plan]
-///     return failure
-///
-/// function And-Search(problem, states, path) returns a conditional plan, or failure
-///     for each s_i in states do
-///         plan_i <- Or-Search(problem, s_i, path)
-///         if plan_i = failure then return failure
-///     return [if s_1 then plan_1 else if s_2 then plan_2 else ... if s_n-1 then plan_n-1 else
-///     plan_n]
-///
-
-use std::fmt::Debug;
-
-use crate::algorithms::problem::*;
-
-pub type Plan<A> = Result<Vec<A>, SearchError>;
-
-pub fn and_or_search<P, S, A>(problem: &P) -> Plan<A>
+//! function And-Or-Search(problem) returns a conditional plan, or failure
+//!     return Or-Search(problem, problem.initial, [])
+//!
+//! function Or-Search(problem, state, path) returns a conditional plan, or failure
+//!     if problem.is_goal(state) then return the empty plan
+//!     if Is-Cycle(path) then return failure
+//!     for each action in problem.Actions(state) do
+//!         plan <- And-Search(problem, Results(state, action), [state] + path])
+//!         if plan != failure then return [action] + plan]
+//!     return failure
+//!
+//! function And-Search(problem, states, path) returns a conditional plan, or failure
+//!     for each s_i in states do
+//!         plan_i <- Or-Search(problem, s_i, path)
+//!         if plan_i = failure then return failure
+//!     return [if s_1 then plan_1 else if s_2 then plan_2 else ... if s_n-1 then plan_n-1 else
+//!     plan_n]
+//!
+//! The plan is a `ConditionalPlan` tree: an action, then for every state the action may lead
+//! to, the plan to follow from there. `ConditionalPlan::check` verifies that a plan reaches the
+//! goal whatever the outcomes, and `ConditionalPlan::execute` follows it in an environment
+//! which picks the outcomes.
+//!
+//! Some problems, like the slippery vacuum world, have no such plan, since an action may fail
+//! any number of times. `cyclic_and_or_search` finds cyclic plans instead: where a state repeats
+//! one on the path, the plan loops back to the step of that state, which is labelled, as in
+//! `[Suck, L1: Right, if State5 then L1 else [Suck]]`. Such a plan reaches the goal as long as
+//! every outcome of an action happens now and then, and every step must have an outcome which
+//! does not loop back, or the plan could loop forever.
+//!
+
+/* Std library */
+use std::fmt::{self, Debug, Display, Formatter};
+
+/* Internal crates */
+use crate::algorithms::{
+    cost::Cost,
+    problem::*,
+};
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum ConditionalPlan<S, A> {
+    /// Nothing to do: the state is a goal
+    Empty,
+    /// Do the action, then follow the plan of the state it led to
+    Step { action: A, branches: Branches<S, A> },
+    /// A plan which loops may come back to
+    Labelled { label: usize, plan: Box<ConditionalPlan<S, A>> },
+    /// Go back to the enclosing plan with the label, in a state its step was planned for
+    Loop(usize),
+}
+
+/// The plan to follow from each state an action may lead to
+pub type Branches<S, A> = Vec<(S, ConditionalPlan<S, A>)>;
+
+impl<S, A> ConditionalPlan<S, A> {
+    /* Whether the plan goes back to an enclosing one without doing anything */
+    fn is_loop(&self) -> bool {
+        match self {
+            ConditionalPlan::Labelled { plan, .. } => plan.is_loop(),
+            ConditionalPlan::Loop(_) => true,
+            _ => false,
+        }
+    }
+}
+
+pub type Plan<S, A> = Result<ConditionalPlan<S, A>, SearchError>;
+
+impl<S, A> ConditionalPlan<S, A>
+where
+    S: Clone + PartialEq + Debug,
+    A: Clone + PartialEq + Debug
+{
+    /// The plan to follow once the action of this step led to the state, if it has one
+    pub fn branch(&self, state: &S) -> Option<&ConditionalPlan<S, A>> {
+        match self {
+            ConditionalPlan::Step { branches, .. } => branches.iter().find(|(s, _)| s == state).map(|(_, plan)| plan),
+            ConditionalPlan::Labelled { plan, .. } => plan.branch(state),
+            ConditionalPlan::Empty | ConditionalPlan::Loop(_) => None,
+        }
+    }
+
+    /// Number of actions on the longest way through the plan, not going round its loops
+    pub fn depth(&self) -> usize {
+        match self {
+            ConditionalPlan::Step { branches, .. } => 1 + branches.iter().map(|(_, plan)| plan.depth()).max().unwrap_or(0),
+            ConditionalPlan::Labelled { plan, .. } => plan.depth(),
+            ConditionalPlan::Empty | ConditionalPlan::Loop(_) => 0,
+        }
+    }
+
+    /// Whether the plan has loops
+    pub fn is_cyclic(&self) -> bool {
+        match self {
+            ConditionalPlan::Step { branches, .. } => branches.iter().any(|(_, plan)| plan.is_cyclic()),
+            ConditionalPlan::Labelled { plan, .. } => plan.is_cyclic(),
+            ConditionalPlan::Loop(_) => true,
+            ConditionalPlan::Empty => false,
+        }
+    }
+
+    /// Checks that following the plan from the state reaches a goal whatever the outcomes of
+    /// the actions: every action must be possible where it is done, every outcome must have a
+    /// branch, and every branch must end in a goal. A loop must go back to an enclosing label
+    /// planned for the state it is in, and every step must have an outcome which does not loop,
+    /// so that the goal is reached as long as no outcome keeps being avoided.
+    pub fn check<P, C>(&self, problem: &P, state: &S) -> Result<(), String>
+    where
+        P: NondeterministicProblem<S, A, C>,
+        C: Cost
+    {
+        self.check_from(problem, state, &mut Vec::new())
+    }
+
+    /* `labels` holds the enclosing labels and the state of each */
+    fn check_from<P, C>(&self, problem: &P, state: &S, labels: &mut Vec<(usize, S)>) -> Result<(), String>
+    where
+        P: NondeterministicProblem<S, A, C>,
+        C: Cost
+    {
+        match self {
+            ConditionalPlan::Empty if problem.is_goal(state) => Ok(()),
+            ConditionalPlan::Empty => Err(format!("The plan ends in {:?}, which is not a goal", state)),
+            ConditionalPlan::Step { action, branches } => {
+                if !problem.actions(state).contains(action) {
+                    return Err(format!("{:?} can not be done in {:?}", action, state));
+                }
+                let mut progress = false;
+                for outcome in problem.results(state, action) {
+                    let Some((_, plan)) = branches.iter().find(|(s, _)| *s == outcome) else {
+                        return Err(format!("The plan does not say what to do when {:?} in {:?} leads to {:?}", action, state, outcome));
+                    };
+                    plan.check_from(problem, &outcome, labels)?;
+                    progress |= !plan.is_loop();
+                }
+                if !progress {
+                    return Err(format!("Every outcome of {:?} in {:?} loops back", action, state));
+                }
+                Ok(())
+            },
+            ConditionalPlan::Labelled { label, plan } => {
+                labels.push((*label, state.clone()));
+                let checked = plan.check_from(problem, state, labels);
+                labels.pop();
+                checked
+            },
+            ConditionalPlan::Loop(label) => match labels.iter().rev().find(|(l, _)| l == label) {
+                Some((_, labelled)) if labelled == state => Ok(()),
+                Some((_, labelled)) => Err(format!("L{} is planned for {:?}, not {:?}", label, labelled, state)),
+                None => Err(format!("There is no L{} to loop back to", label)),
+            },
+        }
+    }
+
+    /// Follows the plan from the state, with `environment` giving the state each action leads
+    /// to. Returns the actions done and the state the plan ends in, or an error if an outcome has
+    /// no branch.
+    pub fn execute<F>(&self, state: S, environment: F) -> Result<(Vec<A>, S), String>
+    where
+        F: FnMut(&S, &A) -> S
+    {
+        self.execute_within(state, usize::MAX, environment)
+    }
+
+    /// Like `execute`, but fails once `max_actions` actions are done without reaching the end
+    /// of the plan, as a cyclic plan may never do if the environment keeps making actions fail
+    pub fn execute_within<F>(&self, state: S, max_actions: usize, mut environment: F) -> Result<(Vec<A>, S), String>
+    where
+        F: FnMut(&S, &A) -> S
+    {
+        let (mut plan, mut state, mut actions) = (self, state, Vec::new());
+        let mut labels: Vec<(usize, &ConditionalPlan<S, A>)> = Vec::new();
+        /* Loops between labels which do nothing would go round forever */
+        let mut jumps = 0;
+        loop {
+            match plan {
+                ConditionalPlan::Empty => return Ok((actions, state)),
+                ConditionalPlan::Step { action, .. } => {
+                    if actions.len() == max_actions {
+                        return Err(format!("The plan did not end within {} actions", max_actions));
+                    }
+                    let outcome = environment(&state, action);
+                    actions.push(action.clone());
+                    plan = plan.branch(&outcome)
+                        .ok_or_else(|| format!("The plan does not say what to do when {:?} in {:?} leads to {:?}", action, state, outcome))?;
+                    state = outcome;
+                    jumps = 0;
+                },
+                ConditionalPlan::Labelled { label, plan: labelled } => {
+                    if !labels.iter().any(|(l, _)| l == label) {
+                        labels.push((*label, labelled));
+                    }
+                    plan = labelled;
+                },
+                ConditionalPlan::Loop(label) => {
+                    jumps += 1;
+                    if jumps > labels.len() {
+                        return Err(format!("L{} loops without doing anything", label));
+                    }
+                    plan = labels.iter().rev().find(|(l, _)| l == label).map(|(_, p)| *p)
+                        .ok_or_else(|| format!("There is no L{} to loop back to", label))?;
+                },
+            }
+        }
+    }
+}
+
+/// In the notation of AIMA: [Suck, if State5 then [Right, Suck] else []], with loops as in
+/// [Suck, L1: Right, if State5 then L1 else [Suck]]
+impl<S: Debug, A: Debug> Display for ConditionalPlan<S, A> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if let ConditionalPlan::Loop(label) = self {
+            return write!(f, "L{}", label);
+        }
+        write!(f, "[")?;
+        let mut plan = self;
+        let mut separator = "";
+        /* A single outcome needs no condition, so the steps of a deterministic plan are listed */
+        loop {
+            match plan {
+                ConditionalPlan::Empty => break,
+                ConditionalPlan::Loop(label) => {
+                    write!(f, "{}L{}", separator, label)?;
+                    break;
+                },
+                ConditionalPlan::Labelled { label, plan: labelled } => {
+                    write!(f, "{}L{}: ", separator, label)?;
+                    separator = "";
+                    plan = labelled;
+                },
+                ConditionalPlan::Step { action, branches } => {
+                    write!(f, "{}{:?}", separator, action)?;
+                    separator = ", ";
+                    match &branches[..] {
+                        [(_, next)] => plan = next,
+                        _ => {
+                            write!(f, ", ")?;
+                            for (i, (state, branch)) in branches.iter().enumerate() {
+                                if i > 0 {
+                                    write!(f, " else ")?;
+                                }
+                                write!(f, "if {:?} then {}", state, branch)?;
+                            }
+                            break;
+                        },
+                    }
+                },
+            }
+        }
+        write!(f, "]")
+    }
+}
+
+/* The states from the initial one to the one being planned for, whether each has been looped
+   back to and with which label, and the number of labels given so far */
+struct Path<S> {
+    states: Vec<S>,
+    labels: Vec<Option<usize>>,
+    labelled: usize,
+    cyclic: bool,
+}
+
+/// An acyclic plan, which reaches the goal in a bounded number of actions whatever happens
+pub fn and_or_search<P, S, A, C>(problem: &P) -> Plan<S, A>
+where
+    P: NondeterministicProblem<S, A, C>,
+    S: Clone + PartialEq + Debug,
+    A: Clone + Debug,
+    C: Cost
+{
+    let mut path = Path { states: Vec::new(), labels: Vec::new(), labelled: 0, cyclic: false };
+    or_search(problem, problem.get_initial_node().state, &mut path)
+}
+
+/// A plan which may loop back to earlier steps, for problems where an action can fail any number
+/// of times
+pub fn cyclic_and_or_search<P, S, A, C>(problem: &P) -> Plan<S, A>
 where
-    P: Problem<S, A>,
+    P: NondeterministicProblem<S, A, C>,
     S: Clone + PartialEq + Debug,
-    A: Clone + Debug
+    A: Clone + Debug,
+    C: Cost
 {
-    let mut path: Vec<S> = Vec::new();
-    let mut plan: Vec<A> = Vec::new();
-    or_search(problem, problem.get_initial_node().state, &mut path, &mut plan)?;
-    Ok(plan)
+    let mut path = Path { states: Vec::new(), labels: Vec::new(), labelled: 0, cyclic: true };
+    or_search(problem, problem.get_initial_node().state, &mut path)
 }
 
-fn or_search<P, S, A>(problem: &P, state: S, path: &mut Vec<S>, plan: &mut Vec<A>) -> Plan<A>
+fn or_search<P, S, A, C>(problem: &P, state: S, path: &mut Path<S>) -> Plan<S, A>
 where
-    P: Problem<S, A>,
+    P: NondeterministicProblem<S, A, C>,
     S: Clone + PartialEq + Debug,
-    A: Clone + Debug
+    A: Clone + Debug,
+    C: Cost
 {
     if problem.is_goal(&state) {
-        return Ok(Vec::new());
+        return Ok(ConditionalPlan::Empty);
     }
-    
-    if path.contains(&state) {
-        return Err(SearchError::Failure);
+    if let Some(i) = path.states.iter().position(|s| *s == state) {
+        if !path.cyclic {
+            return Err(SearchError::Failure);
+        }
+        let label = match path.labels[i] {
+            Some(label) => label,
+            None => {
+                path.labelled += 1;
+                path.labels[i] = Some(path.labelled);
+                path.labelled
+            },
+        };
+        return Ok(ConditionalPlan::Loop(label));
     }
 
     for action in problem.actions(&state) {
-        path.push(state.clone());
-        if let Ok(mut p) = and_search(problem, &problem.results(&state), path, plan) {
-            plan.push(action.clone());
-            p.push(action);
-            return Ok(p);
+        /* The labels given while trying an action which fails are not used */
+        let saved = (path.labels.clone(), path.labelled);
+        path.states.push(state.clone());
+        path.labels.push(None);
+        let branches = and_search(problem, problem.results(&state, &action), path);
+        path.states.pop();
+        let label = path.labels.pop().flatten();
+        match branches {
+            /* In a cyclic plan, some outcome must make progress rather than loop back */
+            Ok(branches) if !path.cyclic || branches.iter().any(|(_, plan)| !plan.is_loop()) => {
+                let step = ConditionalPlan::Step { action, branches };
+                return Ok(match label {
+                    Some(label) => ConditionalPlan::Labelled { label, plan: Box::new(step) },
+                    None => step,
+                });
+            },
+            _ => (path.labels, path.labelled) = saved,
         }
-        path.pop();
     }
-     
-    return Err(SearchError::Failure);
+    Err(SearchError::Failure)
 }
 
-fn and_search<P, S, A>(problem: &P, states: &Vec<S>, path: &mut Vec<S>, plan: &mut Vec<A>) -> Plan<A> 
+/* A plan for every state, or failure as soon as one of them has none */
+fn and_search<P, S, A, C>(problem: &P, states: Vec<S>, path: &mut Path<S>) -> Result<Branches<S, A>, SearchError>
 where
-    P: Problem<S, A>,
+    P: NondeterministicProblem<S, A, C>,
     S: Clone + PartialEq + Debug,
-    A: Clone + Debug
+    A: Clone + Debug,
+    C: Cost
 {
-    let mut plans: Vec<Plan<A>> = Vec::new();
+    let mut branches = Vec::with_capacity(states.len());
     for s in states {
-        let plan_i = or_search(problem, s.clone(), path, plan);
-        if plan_i.is_err() {
-            //return Err(SearchError::Failure);
-        } else if plan_i.is_ok() {
-            plans.push(plan_i); 
-        }
-    }
-
-    if plans.is_empty() {
-        return Err(SearchError::Failure);
+        let plan = or_search(problem, s.clone(), path)?;
+        branches.push((s, plan));
     }
-
-    return plans.last().unwrap().clone();
+    Ok(branches)
 }
-
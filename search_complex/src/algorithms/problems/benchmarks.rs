@@ -0,0 +1,276 @@
+//!
+//! Standard test functions of continuous optimisation, all to be minimised, with their bounds,
+//! their global minima and their gradients:
+//!     Rastrigin    10n + sum(x_i^2 - 10 cos(2 pi x_i)), a bowl covered in regular local minima;
+//!                  minimum 0 at the origin, within [-5.12, 5.12]
+//!     Rosenbrock   sum(100 (x_i+1 - x_i^2)^2 + (1 - x_i)^2), a narrow curved valley;
+//!                  minimum 0 at (1, ..., 1), within [-5, 5]
+//!     Ackley       -20 exp(-0.2 sqrt(mean(x_i^2))) - exp(mean(cos(2 pi x_i))) + 20 + e, nearly
+//!                  flat with many local minima and a deep hole; minimum 0 at the origin, within
+//!                  [-32.768, 32.768]
+//!     Himmelblau   (x^2 + y - 11)^2 + (x + y^2 - 7)^2, in two dimensions only; four minima of 0,
+//!                  within [-5, 5]
+//!     Schwefel     418.9829n - sum(x_i sin(sqrt|x_i|)), whose best local minima are far apart;
+//!                  minimum close to 0 at (420.9687, ..., 420.9687), within [-500, 500]
+//!
+//! `BenchmarkProblem` is a problem of each kind of local search: a continuous problem with the
+//! gradient, and a local search problem whose neighbours move one variable by a step, staying
+//! within the bounds. For the genetic algorithm, `RealVectorOperators::new(problem.bounds())`
+//! gives real-vector genomes within the bounds, whose fitness is the negated value.
+//!
+
+/* Std library */
+use std::f64::consts::{E, PI};
+
+/* External crates */
+use rand::Rng;
+
+/* Internal crates */
+use crate::algorithms::{
+    continuous::ContinuousProblem,
+    local_search::{LocalSearchProblem, Objective},
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Benchmark {
+    Rastrigin,
+    Rosenbrock,
+    Ackley,
+    Himmelblau,
+    Schwefel,
+}
+
+pub const BENCHMARKS: [Benchmark; 5] = [
+    Benchmark::Rastrigin,
+    Benchmark::Rosenbrock,
+    Benchmark::Ackley,
+    Benchmark::Himmelblau,
+    Benchmark::Schwefel,
+];
+
+/* Where the minimum of the Schwefel function is, in every variable */
+const SCHWEFEL_OPTIMUM: f64 = 420.968_746;
+
+impl Benchmark {
+    /// The same bounds for every variable
+    pub fn bounds(&self) -> (f64, f64) {
+        match self {
+            Benchmark::Rastrigin => (-5.12, 5.12),
+            Benchmark::Rosenbrock | Benchmark::Himmelblau => (-5., 5.),
+            Benchmark::Ackley => (-32.768, 32.768),
+            Benchmark::Schwefel => (-500., 500.),
+        }
+    }
+
+    /// Whether the function is defined in this many dimensions
+    pub fn accepts(&self, dimensions: usize) -> bool {
+        match self {
+            Benchmark::Himmelblau => dimensions == 2,
+            Benchmark::Rosenbrock => dimensions >= 2,
+            _ => dimensions >= 1,
+        }
+    }
+
+    /// Every global minimum in this many dimensions
+    pub fn minima(&self, dimensions: usize) -> Vec<Vec<f64>> {
+        match self {
+            Benchmark::Rastrigin | Benchmark::Ackley => vec![vec![0.; dimensions]],
+            Benchmark::Rosenbrock => vec![vec![1.; dimensions]],
+            Benchmark::Schwefel => vec![vec![SCHWEFEL_OPTIMUM; dimensions]],
+            Benchmark::Himmelblau => vec![
+                vec![3., 2.],
+                vec![-2.805_118_086_952_745, 3.131_312_518_250_573],
+                vec![-3.779_310_253_377_747, -3.283_185_991_286_17],
+                vec![3.584_428_340_330_492, -1.848_126_526_964_404],
+            ],
+        }
+    }
+
+    pub fn value(&self, x: &[f64]) -> f64 {
+        let n = x.len() as f64;
+        match self {
+            Benchmark::Rastrigin => 10. * n + x.iter().map(|x| x * x - 10. * (2. * PI * x).cos()).sum::<f64>(),
+            Benchmark::Rosenbrock => x.windows(2)
+                .map(|w| 100. * (w[1] - w[0] * w[0]).powi(2) + (1. - w[0]).powi(2))
+                .sum(),
+            Benchmark::Ackley => {
+                let squares = x.iter().map(|x| x * x).sum::<f64>() / n;
+                let cosines = x.iter().map(|x| (2. * PI * x).cos()).sum::<f64>() / n;
+                -20. * (-0.2 * squares.sqrt()).exp() - cosines.exp() + 20. + E
+            },
+            Benchmark::Himmelblau => (x[0] * x[0] + x[1] - 11.).powi(2) + (x[0] + x[1] * x[1] - 7.).powi(2),
+            Benchmark::Schwefel => 418.982_9 * n - x.iter().map(|x| x * x.abs().sqrt().sin()).sum::<f64>(),
+        }
+    }
+
+    pub fn gradient(&self, x: &[f64]) -> Vec<f64> {
+        let n = x.len();
+        match self {
+            Benchmark::Rastrigin => x.iter().map(|x| 2. * x + 20. * PI * (2. * PI * x).sin()).collect(),
+            Benchmark::Rosenbrock => (0..n)
+                .map(|i| {
+                    let mut d = 0.;
+                    if i + 1 < n {
+                        d += -400. * x[i] * (x[i + 1] - x[i] * x[i]) - 2. * (1. - x[i]);
+                    }
+                    if i > 0 {
+                        d += 200. * (x[i] - x[i - 1] * x[i - 1]);
+                    }
+                    d
+                })
+                .collect(),
+            Benchmark::Ackley => {
+                let r = (x.iter().map(|x| x * x).sum::<f64>() / n as f64).sqrt();
+                let cosines = (x.iter().map(|x| (2. * PI * x).cos()).sum::<f64>() / n as f64).exp();
+                x.iter()
+                    .map(|x| {
+                        /* The first term has no derivative at the origin; 0 is its subgradient */
+                        let first = if r > 0. { 4. * (-0.2 * r).exp() * x / (n as f64 * r) } else { 0. };
+                        first + cosines * 2. * PI * (2. * PI * x).sin() / n as f64
+                    })
+                    .collect()
+            },
+            Benchmark::Himmelblau => {
+                let (a, b) = (x[0] * x[0] + x[1] - 11., x[0] + x[1] * x[1] - 7.);
+                vec![4. * x[0] * a + 2. * b, 2. * a + 4. * x[1] * b]
+            },
+            Benchmark::Schwefel => x.iter()
+                .map(|x| {
+                    let s = x.abs().sqrt();
+                    -(s.sin() + 0.5 * s * s.cos())
+                })
+                .collect(),
+        }
+    }
+}
+
+pub struct BenchmarkProblem {
+    benchmark: Benchmark,
+    initial: Vec<f64>,
+    step: f64,
+}
+
+impl BenchmarkProblem {
+    /// Neighbours are a hundredth of the width of the bounds away
+    pub fn new(benchmark: Benchmark, initial: Vec<f64>) -> Result<Self, String> {
+        if !benchmark.accepts(initial.len()) {
+            return Err(format!("{:?} is not defined in {} dimensions", benchmark, initial.len()));
+        }
+        let (low, high) = benchmark.bounds();
+        if let Some(x) = initial.iter().find(|x| !(low..=high).contains(*x)) {
+            return Err(format!("{} is outside the bounds [{}, {}] of {:?}", x, low, high, benchmark));
+        }
+        Ok(Self { benchmark, initial, step: (high - low) / 100. })
+    }
+
+    /// A problem starting from a random point within the bounds
+    pub fn random<R: Rng + ?Sized>(benchmark: Benchmark, dimensions: usize, rng: &mut R) -> Result<Self, String> {
+        let (low, high) = benchmark.bounds();
+        Self::new(benchmark, (0..dimensions).map(|_| rng.gen_range(low..=high)).collect())
+    }
+
+    /// Sets the distance of the neighbours of the local search, which must be positive
+    pub fn with_step(mut self, step: f64) -> Result<Self, String> {
+        if !(step.is_finite() && step > 0.) {
+            return Err(format!("The step must be positive, not {}", step));
+        }
+        self.step = step;
+        Ok(self)
+    }
+
+    pub fn benchmark(&self) -> Benchmark {
+        self.benchmark
+    }
+
+    /// The bounds of every variable
+    pub fn bounds(&self) -> Vec<(f64, f64)> {
+        vec![self.benchmark.bounds(); self.initial.len()]
+    }
+
+    pub fn minima(&self) -> Vec<Vec<f64>> {
+        self.benchmark.minima(self.initial.len())
+    }
+
+    /// The value of the global minima
+    pub fn minimum(&self) -> f64 {
+        self.benchmark.value(&self.minima()[0])
+    }
+
+    fn clamp(&self, x: f64) -> f64 {
+        let (low, high) = self.benchmark.bounds();
+        x.clamp(low, high)
+    }
+}
+
+impl ContinuousProblem for BenchmarkProblem {
+    fn dimensions(&self) -> usize {
+        self.initial.len()
+    }
+
+    fn initial(&self) -> Vec<f64> {
+        self.initial.clone()
+    }
+
+    fn value(&self, x: &[f64]) -> f64 {
+        self.benchmark.value(x)
+    }
+
+    fn objective(&self) -> Objective {
+        Objective::Minimize
+    }
+
+    fn gradient(&self, x: &[f64]) -> Option<Vec<f64>> {
+        Some(self.benchmark.gradient(x))
+    }
+}
+
+impl LocalSearchProblem<Vec<f64>> for BenchmarkProblem {
+    fn initial(&self) -> Vec<f64> {
+        self.initial.clone()
+    }
+
+    /// One step up or down along every variable, without leaving the bounds
+    fn neighbors(&self, state: &Vec<f64>) -> Vec<Vec<f64>> {
+        let mut neighbors = Vec::with_capacity(2 * state.len());
+        for i in 0..state.len() {
+            for step in [self.step, -self.step] {
+                let x = self.clamp(state[i] + step);
+                if x != state[i] {
+                    let mut neighbor = state.clone();
+                    neighbor[i] = x;
+                    neighbors.push(neighbor);
+                }
+            }
+        }
+        neighbors
+    }
+
+    fn value(&self, state: &Vec<f64>) -> f64 {
+        self.benchmark.value(state)
+    }
+
+    fn objective(&self) -> Objective {
+        Objective::Minimize
+    }
+
+    /// Moves a random variable by up to a step either way
+    fn random_neighbor<R: Rng + ?Sized>(&self, state: &Vec<f64>, rng: &mut R) -> Option<Vec<f64>> {
+        if state.is_empty() {
+            return None;
+        }
+        let mut neighbor = state.clone();
+        let i = rng.gen_range(0..state.len());
+        neighbor[i] = self.clamp(state[i] + rng.gen_range(-self.step..=self.step));
+        Some(neighbor)
+    }
+
+    fn random_state<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Vec<f64>> {
+        let (low, high) = self.benchmark.bounds();
+        Some((0..self.initial.len()).map(|_| rng.gen_range(low..=high)).collect())
+    }
+
+    /// Within 1e-6 of the value of the global minima
+    fn is_optimal(&self, state: &Vec<f64>) -> bool {
+        self.benchmark.value(state) <= self.minimum() + 1e-6
+    }
+}
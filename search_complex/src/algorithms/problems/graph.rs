@@ -0,0 +1,81 @@
+//!
+//! Local search on a one dimensional landscape: the state is a point x, the neighbours are the
+//! points 0.1 left and right of it and the value of a state is the height of the curve at x,
+//! which is to be maximised.
+//!
+//! It is also a continuous problem of one variable, whose derivatives are known.
+//!
+
+/* Internal crates */
+use crate::algorithms::{
+    continuous::ContinuousProblem,
+    local_search::LocalSearchProblem,
+};
+
+pub type GraphState = f64;
+
+pub struct GraphProblem {
+    initial: GraphState,
+    graph_fn: fn(f64) -> f64,
+    graph_dfn: fn(f64) -> f64,
+    graph_d2fn: fn(f64) -> f64,
+}
+
+pub const GRAPH_PROBLEM: GraphProblem = GraphProblem {
+    initial: 0.,
+    // cos(2x * sin(x)) * 5sin(4x)+cos(3x)+sin(4x)
+    graph_fn: noise_fn,
+    graph_dfn: noise_dfn,
+    graph_d2fn: noise_d2fn,
+};
+
+fn noise_fn(x: f64) -> f64 {
+    10. * (0.2 * x + 2.).cos()
+    //(2. * x * x.sin()).cos() * 5. * (4. * x).sin() + (3. * x).cos() + (4. * x).sin()
+}
+
+fn noise_dfn(x: f64) -> f64 {
+    -2. * (0.2 * x + 2.).sin()
+}
+
+fn noise_d2fn(x: f64) -> f64 {
+    -0.4 * (0.2 * x + 2.).cos()
+}
+
+const STEP: f64 = 0.1;
+
+impl LocalSearchProblem<GraphState> for GraphProblem {
+    fn initial(&self) -> GraphState {
+        self.initial
+    }
+
+    fn neighbors(&self, state: &GraphState) -> Vec<GraphState> {
+        vec![state + STEP, state - STEP]
+    }
+
+    fn value(&self, state: &GraphState) -> f64 {
+        (self.graph_fn)(*state)
+    }
+}
+
+impl ContinuousProblem for GraphProblem {
+    fn dimensions(&self) -> usize {
+        1
+    }
+
+    fn initial(&self) -> Vec<f64> {
+        vec![self.initial]
+    }
+
+    fn value(&self, x: &[f64]) -> f64 {
+        (self.graph_fn)(x[0])
+    }
+
+    fn gradient(&self, x: &[f64]) -> Option<Vec<f64>> {
+        Some(vec![(self.graph_dfn)(x[0])])
+    }
+
+    fn hessian(&self, x: &[f64]) -> Option<Vec<Vec<f64>>> {
+        Some(vec![vec![(self.graph_d2fn)(x[0])]])
+    }
+}
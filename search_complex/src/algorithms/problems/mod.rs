@@ -0,0 +1,7 @@
+pub use search_core::romania;
+pub mod benchmarks;
+pub mod graph;
+pub mod queens;
+pub mod tour;
+pub mod tsplib;
+pub mod vacuum;
@@ -0,0 +1,358 @@
+//!
+//! The n-queens problem as local search, from section 4.1.1 of AIMA: every column has one queen,
+//! and a state gives the row of the queen of each column. A move puts one queen on another row
+//! of its column, so every state has n(n - 1) neighbours. The value of a state is the number of
+//! pairs of queens attacking each other, to be minimised; a solution has none.
+//!
+//! A state counts the queens on every row and diagonal, from which the attacking pairs follow.
+//! Moving a queen updates the counts in constant time, and `QueensState::move_delta` tells how
+//! a move changes the attacking pairs without making it, so the value of a neighbour costs no
+//! more than copying the board, and boards of a thousand queens and more are practical.
+//!
+//! A random neighbour moves an attacked queen, to a random row, or with `QueensMove::MinConflicts`
+//! to the row where the fewest queens would attack it. Random rows suit annealing; on large boards
+//! they almost never make things better near a solution, while min-conflicts moves solve them with
+//! hill climbing, given sideways moves (late acceptance with a history of one).
+//!
+//! A state is also a `Genome`, as in figure 4.6 of AIMA: a child takes the queens of the first
+//! columns from one parent and the others from the other. A mutation moves a random queen to a
+//! random row, or with `QueensMove::MinConflicts`, makes a min-conflicts move, which repairs
+//! boards far larger than random mutations can. Its fitness is the number of pairs of queens not
+//! attacking each other.
+//!
+
+/* Std library */
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+/* External crates */
+use rand::Rng;
+
+/* Internal crates */
+use crate::algorithms::{
+    genetic_algorithm::Genome,
+    local_search::{LocalSearchProblem, Objective},
+};
+
+#[derive(Clone, Debug)]
+pub struct QueensState {
+    rows: Vec<usize>,
+    /* Number of queens on every row, diagonal (row + column) and antidiagonal (row + n - 1 - column) */
+    on_row: Vec<usize>,
+    on_diagonal: Vec<usize>,
+    on_antidiagonal: Vec<usize>,
+    attacking: usize,
+}
+
+/* Two queens on the same lines attack each other */
+fn pairs(queens: usize) -> usize {
+    queens * queens.saturating_sub(1) / 2
+}
+
+impl QueensState {
+    /// The board with the queen of every column on the given row, which must be on the board
+    pub fn new(rows: Vec<usize>) -> Result<Self, String> {
+        let n = rows.len();
+        if let Some(row) = rows.iter().find(|r| **r >= n) {
+            return Err(format!("Row {} is off a board of size {}", row, n));
+        }
+        let lines = (2 * n).saturating_sub(1);
+        let mut state = Self { rows, on_row: vec![0; n], on_diagonal: vec![0; lines], on_antidiagonal: vec![0; lines], attacking: 0 };
+        for column in 0..n {
+            let row = state.rows[column];
+            state.on_row[row] += 1;
+            state.on_diagonal[row + column] += 1;
+            state.on_antidiagonal[row + n - 1 - column] += 1;
+        }
+        state.attacking = [&state.on_row, &state.on_diagonal, &state.on_antidiagonal]
+            .iter()
+            .flat_map(|line| line.iter())
+            .map(|queens| pairs(*queens))
+            .sum();
+        Ok(state)
+    }
+
+    pub fn size(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// The row of the queen of every column
+    pub fn rows(&self) -> &[usize] {
+        &self.rows
+    }
+
+    pub fn row(&self, column: usize) -> usize {
+        self.rows[column]
+    }
+
+    /// Number of pairs of queens on the same row or diagonal
+    pub fn attacking_pairs(&self) -> usize {
+        self.attacking
+    }
+
+    /// The fitness of the genetic algorithm: n(n - 1)/2 for a solution
+    pub fn non_attacking_pairs(&self) -> usize {
+        pairs(self.size()) - self.attacking
+    }
+
+    /// Number of queens attacking the queen of the column
+    pub fn conflicts(&self, column: usize) -> usize {
+        let n = self.size();
+        let row = self.rows[column];
+        self.on_row[row] + self.on_diagonal[row + column] + self.on_antidiagonal[row + n - 1 - column] - 3
+    }
+
+    /* Number of queens which would attack the queen of the column on another row */
+    fn conflicts_on(&self, column: usize, row: usize) -> usize {
+        let n = self.size();
+        self.on_row[row] + self.on_diagonal[row + column] + self.on_antidiagonal[row + n - 1 - column]
+    }
+
+    /// How many more attacking pairs there would be with the queen of the column on the row
+    pub fn move_delta(&self, column: usize, row: usize) -> isize {
+        if row == self.rows[column] {
+            return 0;
+        }
+        /* The old and the new square share no line, so removing the queen and adding it back
+           change the pairs independently */
+        self.conflicts_on(column, row) as isize - self.conflicts(column) as isize
+    }
+
+    /// Puts the queen of the column on the row
+    pub fn move_queen(&mut self, column: usize, row: usize) {
+        let n = self.size();
+        let old = self.rows[column];
+        if row == old {
+            return;
+        }
+        self.attacking = (self.attacking as isize + self.move_delta(column, row)) as usize;
+        self.on_row[old] -= 1;
+        self.on_diagonal[old + column] -= 1;
+        self.on_antidiagonal[old + n - 1 - column] -= 1;
+        self.on_row[row] += 1;
+        self.on_diagonal[row + column] += 1;
+        self.on_antidiagonal[row + n - 1 - column] += 1;
+        self.rows[column] = row;
+    }
+
+    /// The board with the queen of the column moved to the row
+    pub fn moved(&self, column: usize, row: usize) -> Self {
+        let mut state = self.clone();
+        state.move_queen(column, row);
+        state
+    }
+
+    /// The columns whose queen is attacked
+    pub fn attacked(&self) -> Vec<usize> {
+        (0..self.size()).filter(|column| self.conflicts(*column) > 0).collect()
+    }
+
+    /// The row of the column, other than the one of its queen, where the fewest queens would
+    /// attack it, ties broken at random
+    pub fn min_conflicts_row<R: Rng + ?Sized>(&self, column: usize, rng: &mut R) -> usize {
+        let current = self.rows[column];
+        let (mut best, mut fewest, mut ties) = (current, usize::MAX, 0);
+        for row in (0..self.size()).filter(|r| *r != current) {
+            let conflicts = self.conflicts_on(column, row);
+            if conflicts < fewest {
+                (best, fewest, ties) = (row, conflicts, 1);
+            } else if conflicts == fewest {
+                /* Reservoir sampling among the rows with the fewest conflicts */
+                ties += 1;
+                if rng.gen_range(0..ties) == 0 {
+                    best = row;
+                }
+            }
+        }
+        best
+    }
+}
+
+/* The counts follow from the rows */
+impl PartialEq for QueensState {
+    fn eq(&self, other: &Self) -> bool {
+        self.rows == other.rows
+    }
+}
+
+impl Eq for QueensState {}
+
+impl Hash for QueensState {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rows.hash(state);
+    }
+}
+
+/// Prints the board, with the first row at the top
+impl Display for QueensState {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        for row in 0..self.size() {
+            let line: Vec<&str> = self.rows.iter().map(|r| if *r == row { "Q" } else { "." }).collect();
+            writeln!(f, "{}", line.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Where a random move puts a queen
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum QueensMove {
+    /// A random row
+    Random,
+    /// The row where the fewest queens attack it
+    MinConflicts,
+}
+
+pub struct QueensProblem {
+    initial: QueensState,
+    moves: QueensMove,
+}
+
+impl QueensProblem {
+    pub fn new(initial: QueensState) -> Result<Self, String> {
+        let n = initial.size();
+        if n == 0 {
+            return Err(String::from("The board must have at least one column"));
+        }
+        Ok(Self { initial, moves: QueensMove::Random })
+    }
+
+    /// A problem starting from a board with one queen on a random row of every column
+    pub fn random<R: Rng + ?Sized>(n: usize, rng: &mut R) -> Result<Self, String> {
+        Self::new(random_board(n, rng))
+    }
+
+    /// Sets where random neighbours put the queen they move, a random row by default
+    pub fn with_moves(mut self, moves: QueensMove) -> Self {
+        self.moves = moves;
+        self
+    }
+
+    pub fn size(&self) -> usize {
+        self.initial.size()
+    }
+}
+
+fn random_board<R: Rng + ?Sized>(n: usize, rng: &mut R) -> QueensState {
+    QueensState::new((0..n).map(|_| rng.gen_range(0..n)).collect()).expect("The rows are on the board")
+}
+
+/* A row of the column other than the one of its queen */
+fn other_row<R: Rng + ?Sized>(state: &QueensState, column: usize, rng: &mut R) -> usize {
+    let row = rng.gen_range(0..state.size() - 1);
+    if row >= state.row(column) { row + 1 } else { row }
+}
+
+impl LocalSearchProblem<QueensState> for QueensProblem {
+    fn initial(&self) -> QueensState {
+        self.initial.clone()
+    }
+
+    fn neighbors(&self, state: &QueensState) -> Vec<QueensState> {
+        let n = state.size();
+        let mut neighbors = Vec::with_capacity(n * (n - 1));
+        for column in 0..n {
+            for row in (0..n).filter(|r| *r != state.row(column)) {
+                neighbors.push(state.moved(column, row));
+            }
+        }
+        neighbors
+    }
+
+    fn value(&self, state: &QueensState) -> f64 {
+        state.attacking_pairs() as f64
+    }
+
+    fn objective(&self) -> Objective {
+        Objective::Minimize
+    }
+
+    /// Moves an attacked queen, if there is one, since moving any other queen cannot remove an
+    /// attacking pair
+    fn random_neighbor<R: Rng + ?Sized>(&self, state: &QueensState, rng: &mut R) -> Option<QueensState> {
+        let n = state.size();
+        if n < 2 {
+            return None;
+        }
+        let attacked = state.attacked();
+        let column = if attacked.is_empty() { rng.gen_range(0..n) } else { attacked[rng.gen_range(0..attacked.len())] };
+        let row = match self.moves {
+            QueensMove::Random => other_row(state, column, rng),
+            QueensMove::MinConflicts => state.min_conflicts_row(column, rng),
+        };
+        Some(state.moved(column, row))
+    }
+
+    fn random_state<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<QueensState> {
+        Some(random_board(self.size(), rng))
+    }
+
+    fn is_optimal(&self, state: &QueensState) -> bool {
+        state.attacking_pairs() == 0
+    }
+}
+
+/// The context of queens genomes
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct QueensOperators {
+    n: usize,
+    mutation: QueensMove,
+}
+
+impl QueensOperators {
+    /// Random mutations, as in AIMA
+    pub fn new(n: usize) -> Self {
+        Self { n, mutation: QueensMove::Random }
+    }
+
+    /// Sets where a mutation puts the queen it moves
+    pub fn with_mutation(mut self, mutation: QueensMove) -> Self {
+        self.mutation = mutation;
+        self
+    }
+
+    /// The size of the boards
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    pub fn mutation(&self) -> QueensMove {
+        self.mutation
+    }
+}
+
+impl Genome for QueensState {
+    type Context = QueensOperators;
+
+    fn random<R: Rng + ?Sized>(operators: &QueensOperators, rng: &mut R) -> Self {
+        random_board(operators.n, rng)
+    }
+
+    fn crossover<R: Rng + ?Sized>(&self, other: &Self, _operators: &QueensOperators, rng: &mut R) -> Self {
+        let c = rng.gen_range(0..=self.size());
+        let rows = self.rows[..c].iter().chain(&other.rows[c..]).copied().collect();
+        QueensState::new(rows).expect("The parents have the same size")
+    }
+
+    fn mutate<R: Rng + ?Sized>(&mut self, operators: &QueensOperators, rng: &mut R) {
+        let n = self.size();
+        if n < 2 {
+            return;
+        }
+        match operators.mutation {
+            QueensMove::Random => {
+                let column = rng.gen_range(0..n);
+                self.move_queen(column, rng.gen_range(0..n));
+            },
+            QueensMove::MinConflicts => {
+                let attacked = self.attacked();
+                if attacked.is_empty() {
+                    return;
+                }
+                let column = attacked[rng.gen_range(0..attacked.len())];
+                let row = self.min_conflicts_row(column, rng);
+                self.move_queen(column, row);
+            },
+        }
+    }
+}
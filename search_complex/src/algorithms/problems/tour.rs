@@ -0,0 +1,296 @@
+//!
+//! The travelling salesperson problem with complete tours, for local search and genetic
+//! algorithms: a tour is a permutation of the cities, and its length includes the way back from
+//! the last city to the first. Distances are straight-line distances between points in the plane,
+//! or any symmetric matrix.
+//!
+//! As a local search problem, the tour starts in the order of the cities, and every move leaves
+//! the first city in place. The neighbours are those of one of the neighbourhoods:
+//!     2-opt    one segment reversed, which replaces two edges with two others; a tour has
+//!              (n - 1)(n - 2) / 2 - 1 neighbours
+//!     3-opt    the tour cut into three segments, put back together in one of the four ways
+//!              which replace three edges (A B C D becomes A B' C' D, A C B D, A C B' D or
+//!              A C' B D, with ' a reversed segment); O(n^3) neighbours, none below 5 cities
+//!     Or-opt   a segment of one to three cities moved elsewhere in the tour; O(n^2) neighbours
+//! All three make a random neighbour in constant time besides copying the tour, which is what
+//! large tours need: their full neighbourhoods are too many to go through at every step.
+//!
+//! Tours load from the TSPLIB format, see `tsplib`.
+//!
+
+/* Std library */
+use std::collections::HashSet;
+
+/* External crates */
+use rand::{seq::SliceRandom, Rng};
+
+/* Internal crates */
+use crate::algorithms::local_search::{LocalSearchProblem, Objective};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Neighborhood {
+    TwoOpt,
+    ThreeOpt,
+    OrOpt,
+}
+
+pub struct TourProblem {
+    distances: Vec<Vec<f64>>,
+    initial: Vec<usize>,
+    neighborhood: Neighborhood,
+}
+
+impl TourProblem {
+    /// The distances must be a symmetric square matrix
+    pub fn new(distances: Vec<Vec<f64>>) -> Result<Self, String> {
+        let n = distances.len();
+        if n == 0 {
+            return Err(String::from("There must be at least one city"));
+        }
+        if let Some(row) = distances.iter().position(|row| row.len() != n) {
+            return Err(format!("Row {} of the distances does not have {} columns", row, n));
+        }
+        for (i, row) in distances.iter().enumerate() {
+            for (j, distance) in row.iter().enumerate().take(i) {
+                if *distance != distances[j][i] {
+                    return Err(format!("The distance from {} to {} is not the one back", i, j));
+                }
+            }
+        }
+        Ok(Self { distances, initial: (0..n).collect(), neighborhood: Neighborhood::TwoOpt })
+    }
+
+    /// Sets the tour local search starts from, which must visit every city once
+    pub fn with_tour(mut self, tour: Vec<usize>) -> Result<Self, String> {
+        let mut sorted = tour.clone();
+        sorted.sort_unstable();
+        if sorted != (0..self.cities()).collect::<Vec<usize>>() {
+            return Err(format!("{:?} does not visit each of the {} cities once", tour, self.cities()));
+        }
+        self.initial = tour;
+        Ok(self)
+    }
+
+    /// Sets the neighbourhood of local search, 2-opt by default
+    pub fn with_neighborhood(mut self, neighborhood: Neighborhood) -> Self {
+        self.neighborhood = neighborhood;
+        self
+    }
+
+    pub fn neighborhood(&self) -> Neighborhood {
+        self.neighborhood
+    }
+
+    pub fn from_points(points: &[(f64, f64)]) -> Result<Self, String> {
+        let distances = points.iter()
+            .map(|(x1, y1)| points.iter().map(|(x2, y2)| (x1 - x2).hypot(y1 - y2)).collect())
+            .collect();
+        Self::new(distances)
+    }
+
+    /// Cities at random points of the unit square
+    pub fn random<R: Rng + ?Sized>(cities: usize, rng: &mut R) -> Result<Self, String> {
+        let points: Vec<(f64, f64)> = (0..cities).map(|_| (rng.gen(), rng.gen())).collect();
+        Self::from_points(&points)
+    }
+
+    pub fn cities(&self) -> usize {
+        self.distances.len()
+    }
+
+    pub fn distance(&self, from: usize, to: usize) -> f64 {
+        self.distances[from][to]
+    }
+
+    /// Length of the closed tour through the cities in this order
+    pub fn tour_length(&self, tour: &[usize]) -> f64 {
+        if tour.is_empty() {
+            return 0.;
+        }
+        let back = self.distance(tour[tour.len() - 1], tour[0]);
+        tour.windows(2).map(|w| self.distance(w[0], w[1])).sum::<f64>() + back
+    }
+
+    /// The edges of the tour `to` which the tour `from` does not have, each as (lower, higher)
+    /// city; with tabu search, they forbid putting back the edges a move took away
+    pub fn added_edges(from: &[usize], to: &[usize]) -> Vec<(usize, usize)> {
+        let existing: HashSet<(usize, usize)> = edges(from).collect();
+        edges(to).filter(|edge| !existing.contains(edge)).collect()
+    }
+}
+
+fn edges(tour: &[usize]) -> impl Iterator<Item = (usize, usize)> + '_ {
+    (0..tour.len()).map(|i| {
+        let (a, b) = (tour[i], tour[(i + 1) % tour.len()]);
+        (a.min(b), a.max(b))
+    })
+}
+
+/* Longest segment an Or-opt move takes */
+const OR_OPT_SEGMENT: usize = 3;
+
+/* The tour with the cities from i to j reversed */
+fn reversed(tour: &[usize], i: usize, j: usize) -> Vec<usize> {
+    let mut neighbor = tour.to_vec();
+    neighbor[i..=j].reverse();
+    neighbor
+}
+
+/* The ways of putting A B C D back together which replace three edges */
+const THREE_OPT_RECONNECTIONS: usize = 4;
+
+/* Whether the reconnection of the cuts before i, j and k replaces three edges: reversing a single
+   city, or swapping two, would put back an edge it took away. X = D A is the segment of the
+   first city, which wraps around */
+fn replaces_three_edges(n: usize, i: usize, j: usize, k: usize, reconnection: usize) -> bool {
+    let (x, b, c) = (n - k + i, j - i, k - j);
+    match reconnection {
+        0 => b > 1 && c > 1,
+        1 => [x, b, c].iter().filter(|length| **length == 1).count() < 2,
+        2 => c > 1 && x > 1,
+        _ => b > 1 && x > 1,
+    }
+}
+
+/* The tour cut before i, j and k into A = [..i], B = [i..j], C = [j..k] and D = [k..], with B and C
+   put back in one of the reconnections */
+fn reconnected(tour: &[usize], i: usize, j: usize, k: usize, reconnection: usize) -> Vec<usize> {
+    let (b, c) = (&tour[i..j], &tour[j..k]);
+    let mut neighbor = Vec::with_capacity(tour.len());
+    neighbor.extend_from_slice(&tour[..i]);
+    match reconnection {
+        /* A B' C' D */
+        0 => {
+            neighbor.extend(b.iter().rev());
+            neighbor.extend(c.iter().rev());
+        },
+        /* A C B D */
+        1 => {
+            neighbor.extend_from_slice(c);
+            neighbor.extend_from_slice(b);
+        },
+        /* A C B' D */
+        2 => {
+            neighbor.extend_from_slice(c);
+            neighbor.extend(b.iter().rev());
+        },
+        /* A C' B D */
+        _ => {
+            neighbor.extend(c.iter().rev());
+            neighbor.extend_from_slice(b);
+        },
+    }
+    neighbor.extend_from_slice(&tour[k..]);
+    neighbor
+}
+
+/* The tour with the segment of the given length from i moved to position p of the other cities */
+fn segment_moved(tour: &[usize], i: usize, length: usize, p: usize) -> Vec<usize> {
+    let mut rest = tour.to_vec();
+    let segment: Vec<usize> = rest.drain(i..i + length).collect();
+    rest.splice(p..p, segment);
+    rest
+}
+
+impl LocalSearchProblem<Vec<usize>> for TourProblem {
+    fn initial(&self) -> Vec<usize> {
+        self.initial.clone()
+    }
+
+    fn neighbors(&self, state: &Vec<usize>) -> Vec<Vec<usize>> {
+        let n = state.len();
+        let mut neighbors = Vec::new();
+        if n < 4 {
+            return neighbors;
+        }
+        match self.neighborhood {
+            /* Every segment reversed, except the whole tour after the first city, which is the same tour */
+            Neighborhood::TwoOpt => {
+                for i in 1..n {
+                    for j in i + 1..n {
+                        if i > 1 || j < n - 1 {
+                            neighbors.push(reversed(state, i, j));
+                        }
+                    }
+                }
+            },
+            Neighborhood::ThreeOpt => {
+                for i in 1..n {
+                    for j in i + 1..n {
+                        for k in j + 1..=n {
+                            for reconnection in 0..THREE_OPT_RECONNECTIONS {
+                                if replaces_three_edges(n, i, j, k, reconnection) {
+                                    neighbors.push(reconnected(state, i, j, k, reconnection));
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            /* Moving a segment to where it is gives the same tour */
+            Neighborhood::OrOpt => {
+                for length in 1..=OR_OPT_SEGMENT.min(n - 2) {
+                    for i in 1..=n - length {
+                        for p in (1..=n - length).filter(|p| *p != i) {
+                            neighbors.push(segment_moved(state, i, length, p));
+                        }
+                    }
+                }
+            },
+        }
+        neighbors
+    }
+
+    fn value(&self, state: &Vec<usize>) -> f64 {
+        self.tour_length(state)
+    }
+
+    fn objective(&self) -> Objective {
+        Objective::Minimize
+    }
+
+    fn random_neighbor<R: Rng + ?Sized>(&self, state: &Vec<usize>, rng: &mut R) -> Option<Vec<usize>> {
+        let n = state.len();
+        if n < 4 {
+            return None;
+        }
+        let neighbor = match self.neighborhood {
+            Neighborhood::TwoOpt => loop {
+                let i = rng.gen_range(1..n - 1);
+                let j = rng.gen_range(i + 1..n);
+                if i > 1 || j < n - 1 {
+                    break reversed(state, i, j);
+                }
+            },
+            /* Four cities have no three edges to replace */
+            Neighborhood::ThreeOpt if n < 5 => return None,
+            Neighborhood::ThreeOpt => loop {
+                let mut cuts = rand::seq::index::sample(rng, n, 3).into_vec();
+                cuts.sort_unstable();
+                /* Cuts before the cities 1 to n */
+                let (i, j, k) = (cuts[0] + 1, cuts[1] + 1, cuts[2] + 1);
+                let reconnection = rng.gen_range(0..THREE_OPT_RECONNECTIONS);
+                if replaces_three_edges(n, i, j, k, reconnection) {
+                    break reconnected(state, i, j, k, reconnection);
+                }
+            },
+            Neighborhood::OrOpt => {
+                let length = rng.gen_range(1..=OR_OPT_SEGMENT.min(n - 2));
+                let i = rng.gen_range(1..=n - length);
+                /* Any position of the other cities but the one it comes from */
+                let mut p = rng.gen_range(1..n - length);
+                if p >= i {
+                    p += 1;
+                }
+                segment_moved(state, i, length, p)
+            },
+        };
+        Some(neighbor)
+    }
+
+    fn random_state<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Vec<usize>> {
+        let mut tour = self.initial.clone();
+        tour[1..].shuffle(rng);
+        Some(tour)
+    }
+}
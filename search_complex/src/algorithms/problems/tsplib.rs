@@ -0,0 +1,22 @@
+//!
+//! Tours from TSPLIB files, read by `search_core::tsplib` like the tours of the `search` crate.
+//!
+
+/* Std library */
+use std::path::Path;
+
+/* Internal crates */
+use crate::algorithms::problems::tour::TourProblem;
+use search_core::Tsplib;
+
+impl TourProblem {
+    /// Reads the TSPLIB file at the path
+    pub fn read_tsplib<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        Self::new(Tsplib::read(path)?.distances)
+    }
+
+    /// Parses a problem in the TSPLIB format
+    pub fn from_tsplib(text: &str) -> Result<Self, String> {
+        Self::new(Tsplib::parse(text)?.distances)
+    }
+}
@@ -0,0 +1,188 @@
+//!
+//! The nondeterministic vacuum worlds of section 4.3 of AIMA: a vacuum cleaner on a row of
+//! squares, some of them dirty, which must clean them all. It can move `Left` or `Right`, which
+//! does nothing at the end of the row, and `Suck` the dirt of its square.
+//!
+//! - In the erratic vacuum world, sucking a dirty square sometimes cleans an adjacent square
+//!   too, and sucking a clean square sometimes deposits dirt on it.
+//! - In the slippery vacuum world, moves sometimes fail and leave the cleaner where it was.
+//!   There is no acyclic plan for it, only cyclic ones which try a move until it works.
+//!
+//! AIMA's worlds have two squares, A and B; `VacuumWorld::new` takes a row of any length.
+//!
+
+/* Std library */
+use std::fmt::{Display, Formatter};
+
+/* Internal crates */
+use crate::algorithms::{
+    node::Node,
+    problem::{NondeterministicProblem, Problem},
+};
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct VacuumState {
+    location: usize,
+    dirty: Vec<bool>,
+}
+
+impl VacuumState {
+    /// The cleaner on square `location`, from 0 on the left, of a row with the given dirty
+    /// squares, which has at least one square
+    pub fn new(location: usize, dirty: Vec<bool>) -> Result<Self, String> {
+        if location >= dirty.len() {
+            return Err(format!("Square {} is off a row of {}", location, dirty.len()));
+        }
+        Ok(Self { location, dirty })
+    }
+
+    /// The square of the cleaner, from 0 on the left
+    pub fn location(&self) -> usize {
+        self.location
+    }
+
+    pub fn squares(&self) -> usize {
+        self.dirty.len()
+    }
+
+    pub fn is_dirty(&self, square: usize) -> bool {
+        self.dirty[square]
+    }
+
+    pub fn is_clean(&self) -> bool {
+        !self.dirty.contains(&true)
+    }
+
+    fn with_location(&self, location: usize) -> Self {
+        Self { location, dirty: self.dirty.clone() }
+    }
+
+    fn with_dirt(&self, square: usize, dirty: bool) -> Self {
+        let mut state = self.clone();
+        state.dirty[square] = dirty;
+        state
+    }
+}
+
+/// The squares in a row, `*` for dirt and `<>` around the one of the cleaner: `<A*> B*`
+impl Display for VacuumState {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let squares: Vec<String> = self.dirty.iter().enumerate().map(|(square, dirty)| {
+            let name = if square < 26 { ((b'A' + square as u8) as char).to_string() } else { square.to_string() };
+            let dirt = if *dirty { "*" } else { "" };
+            if square == self.location { format!("<{}{}>", name, dirt) } else { format!("{}{}", name, dirt) }
+        }).collect();
+        write!(f, "{}", squares.join(" "))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum VacuumAction {
+    Left,
+    Right,
+    Suck,
+}
+
+/// How the actions of the cleaner may go wrong
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VacuumDynamics {
+    /// Sucking may clean an adjacent square too, or deposit dirt on a clean square
+    Erratic,
+    /// Moves may fail
+    Slippery,
+}
+
+pub struct VacuumWorld {
+    initial: VacuumState,
+    dynamics: VacuumDynamics,
+}
+
+impl VacuumWorld {
+    pub fn new(initial: VacuumState, dynamics: VacuumDynamics) -> Self {
+        Self { initial, dynamics }
+    }
+
+    /// The erratic world of AIMA, two dirty squares with the cleaner on the left one
+    pub fn erratic() -> Self {
+        Self::new(VacuumState { location: 0, dirty: vec![true, true] }, VacuumDynamics::Erratic)
+    }
+
+    /// The slippery world of AIMA, with the left square clean and the cleaner on it
+    pub fn slippery() -> Self {
+        Self::new(VacuumState { location: 0, dirty: vec![false, true] }, VacuumDynamics::Slippery)
+    }
+
+    pub fn dynamics(&self) -> VacuumDynamics {
+        self.dynamics
+    }
+
+    /* The state the action was meant to lead to */
+    fn intended(state: &VacuumState, action: &VacuumAction) -> VacuumState {
+        match action {
+            VacuumAction::Left => state.with_location(state.location.saturating_sub(1)),
+            VacuumAction::Right => state.with_location((state.location + 1).min(state.dirty.len() - 1)),
+            VacuumAction::Suck => state.with_dirt(state.location, false),
+        }
+    }
+}
+
+impl Problem<VacuumState, VacuumAction> for VacuumWorld {
+    fn is_goal(&self, state: &VacuumState) -> bool {
+        state.is_clean()
+    }
+
+    fn actions(&self, _state: &VacuumState) -> Vec<VacuumAction> {
+        vec![VacuumAction::Suck, VacuumAction::Right, VacuumAction::Left]
+    }
+
+    fn result(&self, state: &VacuumState, action: &VacuumAction) -> VacuumState {
+        Self::intended(state, action)
+    }
+
+    fn action_cost(&self, _state: &VacuumState, _action: &VacuumAction, _new_state: &VacuumState) -> u32 {
+        1
+    }
+
+    fn get_initial_node(&self) -> Node<VacuumState, VacuumAction> {
+        let cost = self.get_heuristic_cost(&self.initial);
+        Node::new(self.initial.clone(), None, None, 0, cost)
+    }
+
+    fn get_goal_node(&self) -> Node<VacuumState, VacuumAction> {
+        let goal = VacuumState { location: self.initial.location, dirty: vec![false; self.initial.dirty.len()] };
+        Node::new(goal, None, None, 0, 0)
+    }
+
+    /// The number of dirty squares, each of which needs to be sucked
+    fn get_heuristic_cost(&self, state: &VacuumState) -> u32 {
+        state.dirty.iter().filter(|dirty| **dirty).count() as u32
+    }
+
+    /// The row is clean wherever the cleaner is
+    fn get_goal_nodes(&self) -> Vec<Node<VacuumState, VacuumAction>> {
+        let clean = vec![false; self.initial.dirty.len()];
+        (0..clean.len()).map(|location| Node::new(VacuumState { location, dirty: clean.clone() }, None, None, 0, 0)).collect()
+    }
+}
+
+impl NondeterministicProblem<VacuumState, VacuumAction> for VacuumWorld {
+    fn results(&self, state: &VacuumState, action: &VacuumAction) -> Vec<VacuumState> {
+        let intended = Self::intended(state, action);
+        let mut results = vec![intended.clone()];
+        match (self.dynamics, action) {
+            (VacuumDynamics::Erratic, VacuumAction::Suck) if state.dirty[state.location] => {
+                let location = state.location;
+                let neighbors = [location.checked_sub(1), Some(location + 1).filter(|s| *s < state.dirty.len())];
+                for square in neighbors.into_iter().flatten().filter(|s| state.dirty[*s]) {
+                    results.push(intended.with_dirt(square, false));
+                }
+            },
+            (VacuumDynamics::Erratic, VacuumAction::Suck) => results.push(state.with_dirt(state.location, true)),
+            (VacuumDynamics::Slippery, VacuumAction::Left | VacuumAction::Right) if intended != *state => {
+                results.push(state.clone());
+            },
+            _ => {},
+        }
+        results
+    }
+}
@@ -1,76 +1,195 @@
-///
-/// function Simulated-Annealing(problem, schedule) returns a solution state
-///     current <- problem.initial
-///     for t = 1 to infty do
-///         T <- schedule(t)
-///         if T = 0 then return current
-///         next <- a randomly selected successor of current
-///         del_E <- Value(current) - Value(next)
-///         if del_E > 0 then current <- next
-///         else current <- next only with probability e^(-del_E/T)
-///
-
-use rand::prelude::*;
-
-use crate::algorithms::{
-    node::Node,
-    problem::*
-};
-
-
-pub fn simulated_annealing<P, S, A>(problem: &P) -> SearchResult<S, A>
-where
-    P: Problem<S, A>,
-    S: Clone,
-    A: Clone
-{
-    let mut rng = rand::thread_rng();
-    let mut current = problem.get_initial_node();
+//!
+//! function Simulated-Annealing(problem, schedule) returns a solution state
+//!     current <- problem.initial
+//!     for t = 1 to infty do
+//!         T <- schedule(t)
+//!         if T = 0 then return current
+//!         next <- a randomly selected successor of current
+//!         del_E <- Value(next) - Value(current)
+//!         if del_E > 0 then current <- next
+//!         else current <- next only with probability e^(del_E/T)
+//!
+//! Values are compared by `LocalSearchProblem::score`, so a problem that minimises its objective
+//! is annealed towards its minimum.
+//!
+//! The search stops once the temperature falls below `Annealing::min_temperature` or 0, after
+//! `Annealing::max_steps` steps or on an optimal state. With `Annealing::reheat_after`, the
+//! schedule starts again from the best state found whenever that many steps went by without
+//! improving on it. The random number generator is passed in, so that a seeded one gives the
+//! same run every time.
+//!
 
-    for t in 1..usize::MAX {
-        let T = schedule(t);
-        if T == 0. {
-            return Ok(current);
-        }
-        let next = random_successor(problem, &current);
-        let del_e: f64 = -value(problem, &current) + value(problem, &next);
-        let boltzmann_dist = (del_e / T).exp();
-        if del_e > 0. || rng.gen_bool(boltzmann_dist) {
-            current = next;
+/* External crates */
+use rand::Rng;
+
+/* Internal crates */
+use crate::algorithms::local_search::{LocalSearchProblem, LocalSearchResult};
+
+/// How the temperature goes down with the number of steps t since the start (or the last reheat)
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Schedule {
+    /// T = t0 * alpha^t, for 0 < alpha < 1
+    Exponential { t0: f64, alpha: f64 },
+    /// T = t0 - rate * t
+    Linear { t0: f64, rate: f64 },
+    /// T = c / ln(t + 1), which finds a global optimum given enough steps, but cools very slowly
+    Logarithmic { c: f64 },
+    /// The adaptive schedule of Lam and Delosme, as modified by Swartz: the temperature starts at
+    /// t0 and is lowered or raised to keep the share of accepted moves near a target, which
+    /// falls from 1 to 0.44 over the first 15% of the `steps`, stays there until 65% and then
+    /// falls to 0. The schedule ends after `steps` steps.
+    Lam { t0: f64, steps: usize },
+}
+
+/// Settings of `simulated_annealing_with`
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Annealing {
+    pub schedule: Schedule,
+    /// The search stops once the temperature is at or below this, or at or below 0 when this is
+    /// negative
+    pub min_temperature: f64,
+    /// The search stops after this many steps, whatever the temperature
+    pub max_steps: usize,
+    /// Starts the schedule again from the best state after this many steps without a better one
+    pub reheat_after: Option<usize>,
+}
+
+impl Default for Annealing {
+    fn default() -> Self {
+        Self {
+            schedule: Schedule::Exponential { t0: 1., alpha: 0.995 },
+            min_temperature: 1e-3,
+            max_steps: 100_000,
+            reheat_after: None,
         }
     }
+}
 
-    return Err(SearchError::Failure);
+/* Share of accepted moves targeted by the Lam schedule after the given fraction of its steps */
+fn lam_target(progress: f64) -> f64 {
+    if progress < 0.15 {
+        0.44 + 0.56 * 560f64.powf(-progress / 0.15)
+    } else if progress < 0.65 {
+        0.44
+    } else {
+        0.44 * 440f64.powf(-(progress - 0.65) / 0.35)
+    }
 }
 
+/* The schedule, with what the adaptive one remembers */
+struct Cooling {
+    schedule: Schedule,
+    t: usize,
+    temperature: f64,
+    accepted: f64,
+}
 
-fn schedule(t: usize) -> f64 {
-    if t % 1000 == 0 {
-        return 0.
-    } else {
-        1. / t as f64
+impl Cooling {
+    fn new(schedule: Schedule) -> Self {
+        let mut cooling = Self { schedule, t: 0, temperature: 0., accepted: 0.5 };
+        cooling.reheat();
+        cooling
+    }
+
+    fn reheat(&mut self) {
+        self.t = 0;
+        self.accepted = 0.5;
+        self.temperature = match self.schedule {
+            Schedule::Lam { t0, .. } => t0,
+            _ => 0.,
+        };
+    }
+
+    /* The temperature of the next step, given whether the last move was accepted */
+    fn next(&mut self, last_accepted: bool) -> f64 {
+        self.t += 1;
+        let t = self.t as f64;
+        match self.schedule {
+            Schedule::Exponential { t0, alpha } => t0 * alpha.powf(t),
+            Schedule::Linear { t0, rate } => (t0 - rate * t).max(0.),
+            Schedule::Logarithmic { c } => c / (t + 1.).ln(),
+            Schedule::Lam { steps, .. } => {
+                if self.t >= steps {
+                    return 0.;
+                }
+                if self.t > 1 {
+                    self.accepted = (499. * self.accepted + if last_accepted { 1. } else { 0. }) / 500.;
+                    if self.accepted > lam_target(t / steps as f64) {
+                        self.temperature *= 0.999;
+                    } else {
+                        self.temperature /= 0.999;
+                    }
+                }
+                self.temperature
+            },
+        }
     }
 }
 
-fn random_successor<P, S, A>(problem: &P, node: &Node<S, A>) -> Node<S, A>
+/// Anneals with the default settings: an exponential schedule from 1 to 0.001
+pub fn simulated_annealing<P, S, R>(problem: &P, rng: &mut R) -> LocalSearchResult<S>
 where
-    P: Problem<S, A>,
+    P: LocalSearchProblem<S>,
     S: Clone,
-    A: Clone
+    R: Rng + ?Sized
 {
-    let mut rng = rand::thread_rng();
-    
-    let candidates = expand(problem, node.clone());
-
-    let successor = candidates.get(rng.gen_range(0..candidates.len())).unwrap().clone();
-    return successor;
+    simulated_annealing_with(problem, &Annealing::default(), rng)
 }
 
-fn value<P, S, A>(problem: &P, node: &Node<S, A>) -> f64
+/// Returns the best state it went through. The steps are the iterations, whether or not their
+/// move was accepted, and the restarts are the reheats.
+pub fn simulated_annealing_with<P, S, R>(problem: &P, annealing: &Annealing, rng: &mut R) -> LocalSearchResult<S>
 where
-    P: Problem<S, A>,
+    P: LocalSearchProblem<S>,
     S: Clone,
-    A: Clone
+    R: Rng + ?Sized
 {
-    problem.get_heuristic_cost(&node.state)
+    let mut current = problem.initial();
+    let mut current_score = problem.score(&current);
+    let (mut best, mut best_score) = (current.clone(), current_score);
+    let mut trajectory = vec![problem.value(&current)];
+    let mut cooling = Cooling::new(annealing.schedule);
+    let (mut steps, mut reheats, mut since_best) = (0, 0, 0);
+    let mut accepted = false;
+
+    while steps < annealing.max_steps && !problem.is_optimal(&current) {
+        if annealing.reheat_after.is_some_and(|limit| since_best >= limit) {
+            cooling.reheat();
+            current = best.clone();
+            current_score = best_score;
+            trajectory.push(problem.value(&current));
+            reheats += 1;
+            since_best = 0;
+        }
+
+        let temperature = cooling.next(accepted);
+        /* A schedule that goes below 0, or to NaN, is over: no probability comes from it */
+        if temperature.is_nan() || temperature <= annealing.min_temperature.max(0.) {
+            break;
+        }
+        let Some(next) = problem.random_neighbor(&current, rng) else {
+            break;
+        };
+        let score = problem.score(&next);
+        let del_e = score - current_score;
+        /* A NaN value gives a NaN probability, which rejects the move */
+        let probability = (del_e / temperature).exp();
+        accepted = del_e > 0. || (!probability.is_nan() && rng.gen_bool(probability));
+        if accepted {
+            current = next;
+            current_score = score;
+        }
+        steps += 1;
+        trajectory.push(problem.value(&current));
+
+        if current_score > best_score {
+            best = current.clone();
+            best_score = current_score;
+            since_best = 0;
+        } else {
+            since_best += 1;
+        }
+    }
+
+    LocalSearchResult::new(problem, best, steps, reheats, trajectory)
 }
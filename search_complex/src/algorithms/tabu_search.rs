@@ -0,0 +1,114 @@
+//!
+//! Tabu search (Glover): like steepest ascent, it moves to the best neighbour, but also when that
+//! neighbour is worse than the current state, so it walks out of local maxima. To keep it from
+//! walking straight back, the moves undoing recent moves are tabu for a number of iterations,
+//! the tenure.
+//!
+//! What is tabu are attributes of the states. `attributes(from, to)` gives the attributes `to` has
+//! and `from` has not, like the rows of the queens which moved or the edges of a tour which were
+//! added. A move is tabu if it would add an attribute which is tabu, and after a move from s to
+//! s', the attributes it took away, `attributes(s', s)`, are tabu for `Tabu::tenure` iterations.
+//! `tabu_search` uses the states themselves as attributes, which only forbids going back to the
+//! states visited most recently.
+//!
+//! A tabu move is allowed anyway if it satisfies the aspiration criterion: with
+//! `Aspiration::BestSoFar`, if it leads to a state better than any found so far.
+//!
+//! The search returns the best state it went through. It stops on an optimal state, when every
+//! neighbour is tabu, after `Tabu::max_iterations` or after `Tabu::max_stagnation` iterations
+//! without a better state.
+//!
+
+/* Std library */
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/* Internal crates */
+use crate::algorithms::local_search::{LocalSearchProblem, LocalSearchResult};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Aspiration {
+    /// Tabu moves are never made
+    Never,
+    /// A tabu move is made if it leads to the best state found so far
+    BestSoFar,
+}
+
+/// Settings of `tabu_search`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Tabu {
+    /// Number of iterations an attribute stays tabu
+    pub tenure: usize,
+    pub aspiration: Aspiration,
+    pub max_iterations: usize,
+    pub max_stagnation: Option<usize>,
+}
+
+impl Default for Tabu {
+    fn default() -> Self {
+        Self { tenure: 10, aspiration: Aspiration::BestSoFar, max_iterations: 1000, max_stagnation: None }
+    }
+}
+
+/// Tabu search forbidding the states visited in the last `tenure` iterations
+pub fn tabu_search<P, S>(problem: &P, tabu: &Tabu) -> LocalSearchResult<S>
+where
+    P: LocalSearchProblem<S>,
+    S: Clone + Eq + Hash
+{
+    tabu_search_with_attributes(problem, tabu, |_, to: &S| vec![to.clone()])
+}
+
+/// Tabu search with a tabu list of the attributes of the states
+pub fn tabu_search_with_attributes<P, S, T, F>(problem: &P, tabu: &Tabu, attributes: F) -> LocalSearchResult<S>
+where
+    P: LocalSearchProblem<S>,
+    S: Clone,
+    T: Eq + Hash,
+    F: Fn(&S, &S) -> Vec<T>
+{
+    let mut current = problem.initial();
+    let (mut best, mut best_score) = (current.clone(), problem.score(&current));
+    let mut trajectory = vec![problem.value(&current)];
+    /* The iteration until which each attribute is tabu */
+    let mut tabu_until: HashMap<T, usize> = HashMap::new();
+    let (mut steps, mut stagnation) = (0, 0);
+
+    while steps < tabu.max_iterations
+        && !problem.is_optimal(&current)
+        && tabu.max_stagnation.is_none_or(|max| stagnation < max)
+    {
+        let mut chosen: Option<(S, f64)> = None;
+        for neighbor in problem.neighbors(&current) {
+            let score = problem.score(&neighbor);
+            if chosen.as_ref().is_some_and(|(_, chosen_score)| score <= *chosen_score) {
+                continue;
+            }
+            let is_tabu = attributes(&current, &neighbor)
+                .iter()
+                .any(|attribute| tabu_until.get(attribute).is_some_and(|until| *until > steps));
+            let aspires = tabu.aspiration == Aspiration::BestSoFar && score > best_score;
+            if !is_tabu || aspires {
+                chosen = Some((neighbor, score));
+            }
+        }
+        let Some((next, score)) = chosen else {
+            break;
+        };
+
+        steps += 1;
+        for attribute in attributes(&next, &current) {
+            tabu_until.insert(attribute, steps + tabu.tenure);
+        }
+        current = next;
+        trajectory.push(problem.value(&current));
+        if score > best_score {
+            (best, best_score) = (current.clone(), score);
+            stagnation = 0;
+        } else {
+            stagnation += 1;
+        }
+    }
+
+    LocalSearchResult::new(problem, best, steps, 0, trajectory)
+}
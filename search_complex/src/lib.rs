@@ -0,0 +1,72 @@
+//!
+//! Search in complex environments from chapter 4 of AIMA: local search, nondeterministic
+//! actions (AND-OR search) and online search agents.
+//!
+
+pub mod algorithms;
+
+/// The `problem!` macro, which defines a problem from its transition table
+pub use search_core::problem;
+
+pub use algorithms::{
+    cost::Cost,
+    node::Node,
+    problem::{
+        expand,
+        NondeterministicProblem,
+        OnlineProblem,
+        Problem,
+        SearchError,
+        SearchResult,
+    },
+    problems::{
+        romania::{AradToBucharestAction, AradToBucharestProblem, ARAD_TO_BUCHAREST_PROBLEM},
+        benchmarks::{Benchmark, BenchmarkProblem, BENCHMARKS},
+        graph::{GraphProblem, GraphState, GRAPH_PROBLEM},
+        queens::{QueensMove, QueensOperators, QueensProblem, QueensState},
+        tour::{Neighborhood, TourProblem},
+        vacuum::{VacuumAction, VacuumDynamics, VacuumState, VacuumWorld},
+    },
+    agent::Agent,
+    beam_search::{beam_search_with, local_beam_search, stochastic_beam_search, Beam, BeamSelection},
+    continuous::{
+        empirical_gradient,
+        gradient_ascent,
+        newton_raphson,
+        ContinuousProblem,
+        Derivatives,
+        GradientAscent,
+        NewtonRaphson,
+        StepSize,
+    },
+    genetic_algorithm::{genetic_algorithm, Genetic, GeneticResult, Genome, Selection, Termination},
+    genomes::{
+        BitCrossover,
+        BitString,
+        BitStringOperators,
+        Permutation,
+        PermutationCrossover,
+        PermutationMutation,
+        PermutationOperators,
+        RealCrossover,
+        RealVector,
+        RealVectorOperators,
+    },
+    hill_climbing::{
+        first_choice_hill_climbing,
+        first_choice_hill_climbing_with_sideways_moves,
+        hill_climbing,
+        hill_climbing_with_sideways_moves,
+        random_restart_hill_climbing,
+        stochastic_hill_climbing,
+        HillClimbing,
+    },
+    island_model::{island_genetic_algorithm, IslandResult, IslandStatistics, Islands, Topology},
+    late_acceptance::{late_acceptance_hill_climbing, LateAcceptance},
+    local_search::{LocalSearchProblem, LocalSearchResult, Objective},
+    lrta_agent::LrtaAgent,
+    online_dfs_agent::OnlineDFSAgent,
+    or_search::{and_or_search, cyclic_and_or_search, Branches, ConditionalPlan, Plan},
+    simulated_annealing::{simulated_annealing, simulated_annealing_with, Annealing, Schedule},
+    tabu_search::{tabu_search, tabu_search_with_attributes, Aspiration, Tabu},
+};
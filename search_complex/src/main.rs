@@ -6,117 +6,85 @@ use std::{
 };
 
 
-mod algorithms;
-
-use crate::algorithms::{
-    node::Node,
-    problem::*,
-    agent::*,
+use search_complex::{
+    and_or_search,
+    cyclic_and_or_search,
+    genetic_algorithm,
     hill_climbing,
     simulated_annealing,
-    genetic_algorithm,
-    or_search::{self, Plan},
-    online_dfs_agent::OnlineDFSAgent,
-    lrta_agent::LrtaAgent
+    Agent,
+    Cost,
+    Genetic,
+    LocalSearchProblem,
+    LocalSearchResult,
+    LrtaAgent,
+    NondeterministicProblem,
+    OnlineDFSAgent,
+    OnlineProblem,
+    Plan,
+    QueensOperators,
+    QueensState,
+    VacuumWorld,
+    ARAD_TO_BUCHAREST_PROBLEM,
+    GRAPH_PROBLEM,
 };
 
-type SearchAlgorithm<P, S, A> = fn(&P) -> SearchResult<S, A>;
-type SearchAlgorithmHeuristic<P, S, A> = fn(&P, fn(&P, &Node<S, A>) -> f64) -> SearchResult<S, A>;
+type LocalSearchAlgorithm<P, S> = fn(&P) -> LocalSearchResult<S>;
 
 fn main() {
-    try_algorithm(&GRAPH_PROBLEM, "hill_climbing", hill_climbing::hill_climbing);
-    try_algorithm(&GRAPH_PROBLEM, "Simulated Annealing", simulated_annealing::simulated_annealing);
-    try_genetic(&ARAD_TO_BUCHAREST_PROBLEM, "Genetic Algorithm", genetic_algorithm::genetic_algorithm);
-    try_or_search(&ARAD_TO_BUCHAREST_PROBLEM, "And-Or Search", or_search::and_or_search);
-    try_agent::<OnlineDFSAgent<_, _>, _, _, _>(&ARAD_TO_BUCHAREST_PROBLEM, "Online DFS Agent");
-    try_agent::<LrtaAgent<_,_,_>,_,_,_>(&ARAD_TO_BUCHAREST_PROBLEM, "LRTA* Agent");
+    try_local_search(&GRAPH_PROBLEM, "Hill Climbing", |problem| hill_climbing(problem, &mut rand::thread_rng()));
+    try_local_search(&GRAPH_PROBLEM, "Simulated Annealing", |problem| simulated_annealing(problem, &mut rand::thread_rng()));
+    try_genetic(8, "Genetic Algorithm");
+    try_or_search(&ARAD_TO_BUCHAREST_PROBLEM, "And-Or Search", and_or_search);
+    try_or_search(&VacuumWorld::erratic(), "And-Or Search, Erratic Vacuum World", and_or_search);
+    try_or_search(&VacuumWorld::slippery(), "Cyclic And-Or Search, Slippery Vacuum World", cyclic_and_or_search);
+    try_agent::<OnlineDFSAgent<_, _>, _, _, _, _>(&ARAD_TO_BUCHAREST_PROBLEM, "Online DFS Agent");
+    try_agent::<LrtaAgent<_, _, _, _>, _, _, _, _>(&ARAD_TO_BUCHAREST_PROBLEM, "LRTA* Agent");
 }
 
-fn try_algorithm<P, S, A>(problem: &P, name: &str, algorithm: SearchAlgorithm<P, S, A>) 
+fn try_local_search<P, S>(problem: &P, name: &str, algorithm: LocalSearchAlgorithm<P, S>)
 where
-    P: Problem<S, A>,
-    S: Clone + Display,
-    A: Clone
+    P: LocalSearchProblem<S>,
+    S: Display
 {
-    let res = algorithm(problem).expect("No path found");
-    let node = Some(Box::new(res));
-    println!("[{}] Optimal x:", name);
-    //while node.is_some() {
-        println!("      {}", node.as_ref().unwrap().state);
-    //    node = node.unwrap().parent;
-    //}
+    let result = algorithm(problem);
+    println!("[{}] Best x after {} steps:", name, result.steps);
+    println!("      {} (value {:.4})", result.state, result.value);
 }
 
-fn try_genetic<P, S, A>(problem: &P, name: &str, algorithm: SearchAlgorithm<P, String, A>) 
-where
-    P: Problem<S, A>,
-    S: Clone + Display,
-    A: Clone
-{
-    let res = algorithm(problem).expect("No path found");
-    let node = Some(Box::new(res));
-    let mut dummy: u32 = 0;
-
-    println!("[{}] Optimal child DNA: {}", name, node.as_ref().unwrap().state);
-    //while node.is_some() {
-    println!("  Converted to choices:");
-    genetic_algorithm::iterate_over_dna(problem, node.as_ref().unwrap(), printfn, &mut dummy);
-    //    node = node.unwrap().parent;
-    //}
-}
-
-fn printfn<S, A>(node: Node<S, A>, _: &mut u32)
-where
-    S: Clone + Display,
-    A: Clone
-{
-    println!("      {}", node.state);
-}
-
-fn try_or_search<P, S, A>(problem: &P, name: &str, algorithm: fn(&P) -> Plan<A>)
-where
-    P: Problem<S, A>,
-    S: Clone,
-    A: Clone + Debug
-{
-    let plan = algorithm(problem).expect("No path found");
-    println!("[{}] Optimal plan:", name);
-    for a in plan {
-        println!("      {:?}", a);
-    //    node = node.unwrap().parent;
-    }
-}
-
-fn try_heuristic<P, S, A>(problem: &P, name: &str, algorithm: SearchAlgorithmHeuristic<P, S, A>, h: fn(&P, &Node<S, A>) -> f64)
-where
-    P: Problem<S, A>,
-    S: Clone + Display,
-    A: Clone
-{
-    let res = algorithm(problem, h).expect("No Path found");
-    let mut node = Some(Box::new(res));
-    println!("[{}] Optimal path from Arad to Bucharest:", name);
-    while node.is_some() {
-        println!("      {}", node.as_ref().unwrap().state);
-        node = node.unwrap().parent;
+fn try_genetic(n: usize, name: &str) {
+    let genetic = Genetic {
+        target_fitness: Some((n * (n - 1) / 2) as f64),
+        max_stagnation: Some(200),
+        ..Genetic::default()
+    };
+    let result = genetic_algorithm(&QueensOperators::new(n), |board: &QueensState| board.non_attacking_pairs() as f64, &genetic, &mut rand::thread_rng())
+        .expect("Invalid settings");
+    println!("[{}] Fittest board after {} generations ({} non-attacking pairs):", name, result.generations, result.fitness);
+    for line in result.best.to_string().lines() {
+        println!("      {}", line);
     }
 }
 
-fn h<P, S, A>(problem: &P, node: &Node<S, A>) -> f64
+fn try_or_search<P, S, A, C>(problem: &P, name: &str, algorithm: fn(&P) -> Plan<S, A>)
 where
-    P: Problem<S, A>,
-    S: Clone,
-    A: Clone
+    P: NondeterministicProblem<S, A, C>,
+    C: Cost,
+    S: Clone + Debug,
+    A: Clone + Debug
 {
-    problem.get_heuristic_cost(&node.state)
+    let plan = algorithm(problem).expect("No plan found");
+    println!("[{}] Conditional plan:", name);
+    println!("      {}", plan);
 }
 
-fn try_agent<T, P, S, A>(problem: &P, name: &str)
+fn try_agent<T, P, S, A, C>(problem: &P, name: &str)
 where
-    P: Problem<S, A>,
+    P: OnlineProblem<S, A, C>,
     S: Clone + Eq + Hash,
     A: Clone + Eq + Hash + Debug,
-    T: Agent<P, S, A>,
+    C: Cost,
+    T: Agent<P, S, A, C>,
 {
     let mut agent = T::new();
     let mut state = problem.get_initial_node().state.clone();
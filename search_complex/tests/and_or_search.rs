@@ -0,0 +1,106 @@
+use search_complex::{
+    and_or_search,
+    ConditionalPlan,
+    Node,
+    NondeterministicProblem,
+    Problem,
+    ARAD_TO_BUCHAREST_PROBLEM,
+};
+
+/* From Start, Risky may reach the goal or a trap with no way out, while Safe always reaches
+   Left or Right, from which Go reaches the goal */
+struct Fork;
+
+impl Problem<&'static str, &'static str> for Fork {
+    fn is_goal(&self, state: &&'static str) -> bool {
+        *state == "Goal"
+    }
+
+    fn actions(&self, state: &&'static str) -> Vec<&'static str> {
+        match *state {
+            "Start" => vec!["Risky", "Safe"],
+            "Left" | "Right" => vec!["Go"],
+            _ => vec![],
+        }
+    }
+
+    fn result(&self, state: &&'static str, action: &&'static str) -> &'static str {
+        self.results(state, action)[0]
+    }
+
+    fn action_cost(&self, _state: &&'static str, _action: &&'static str, _new_state: &&'static str) -> u32 {
+        1
+    }
+
+    fn get_initial_node(&self) -> Node<&'static str, &'static str> {
+        Node::new("Start", None, None, 0, 0)
+    }
+
+    fn get_goal_node(&self) -> Node<&'static str, &'static str> {
+        Node::new("Goal", None, None, 0, 0)
+    }
+
+    fn get_heuristic_cost(&self, _state: &&'static str) -> u32 {
+        0
+    }
+}
+
+impl NondeterministicProblem<&'static str, &'static str> for Fork {
+    fn results(&self, state: &&'static str, action: &&'static str) -> Vec<&'static str> {
+        match (*state, *action) {
+            ("Start", "Risky") => vec!["Goal", "Trap"],
+            ("Start", "Safe") => vec!["Left", "Right"],
+            (_, "Go") => vec!["Goal"],
+            _ => vec![*state],
+        }
+    }
+}
+
+fn go() -> ConditionalPlan<&'static str, &'static str> {
+    ConditionalPlan::Step { action: "Go", branches: vec![("Goal", ConditionalPlan::Empty)] }
+}
+
+#[test]
+fn the_plan_avoids_actions_with_a_failing_outcome() {
+    let plan = and_or_search(&Fork).unwrap();
+    let expected = ConditionalPlan::Step { action: "Safe", branches: vec![("Left", go()), ("Right", go())] };
+    assert_eq!(plan, expected);
+    assert_eq!(plan.depth(), 2);
+    assert_eq!(plan.check(&Fork, &"Start"), Ok(()));
+    assert_eq!(plan.to_string(), "[\"Safe\", if \"Left\" then [\"Go\"] else if \"Right\" then [\"Go\"]]");
+}
+
+#[test]
+fn the_checker_rejects_plans_that_may_fail() {
+    let risky = ConditionalPlan::Step { action: "Risky", branches: vec![("Goal", ConditionalPlan::Empty)] };
+    assert!(risky.check(&Fork, &"Start").is_err());
+    let trapped = ConditionalPlan::Step {
+        action: "Risky",
+        branches: vec![("Goal", ConditionalPlan::Empty), ("Trap", ConditionalPlan::Empty)],
+    };
+    assert!(trapped.check(&Fork, &"Start").is_err());
+    let impossible = ConditionalPlan::Step { action: "Go", branches: vec![("Goal", ConditionalPlan::Empty)] };
+    assert!(impossible.check(&Fork, &"Start").is_err());
+    assert!(ConditionalPlan::Empty.check(&Fork, &"Start").is_err());
+}
+
+#[test]
+fn executing_the_plan_follows_the_outcomes() {
+    let plan = and_or_search(&Fork).unwrap();
+    for outcome in ["Left", "Right"] {
+        let environment = |state: &&'static str, action: &&'static str| if *state == "Start" { outcome } else { Fork.result(state, action) };
+        assert_eq!(plan.execute("Start", environment), Ok((vec!["Safe", "Go"], "Goal")));
+    }
+    /* An outcome the plan did not expect */
+    assert!(plan.execute("Start", |_: &&'static str, _: &&'static str| "Trap").is_err());
+}
+
+#[test]
+fn deterministic_problems_get_a_sequence_of_actions() {
+    let problem = &ARAD_TO_BUCHAREST_PROBLEM;
+    let plan = and_or_search(problem).unwrap();
+    assert_eq!(plan.check(problem, &"Arad"), Ok(()));
+    let (actions, end) = plan.execute("Arad", |state, action| problem.result(state, action)).unwrap();
+    assert_eq!(end, "Bucharest");
+    assert_eq!(actions.len(), plan.depth());
+}
@@ -0,0 +1,162 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use search_complex::{
+    beam_search_with,
+    local_beam_search,
+    stochastic_beam_search,
+    Beam,
+    BeamSelection,
+    LocalSearchProblem,
+    Objective,
+    QueensProblem,
+    TourProblem,
+};
+
+/// Distance of an integer from a target, to be minimised, with no random states
+struct Valley {
+    start: i32,
+    target: i32,
+}
+
+impl LocalSearchProblem<i32> for Valley {
+    fn initial(&self) -> i32 {
+        self.start
+    }
+
+    fn neighbors(&self, state: &i32) -> Vec<i32> {
+        vec![state - 1, state + 1]
+    }
+
+    fn value(&self, state: &i32) -> f64 {
+        (state - self.target).abs() as f64
+    }
+
+    fn objective(&self) -> Objective {
+        Objective::Minimize
+    }
+
+    fn is_optimal(&self, state: &i32) -> bool {
+        *state == self.target
+    }
+}
+
+#[test]
+fn local_beam_search_walks_down_a_valley() {
+    let problem = Valley { start: 0, target: 12 };
+    let result = local_beam_search(&problem, 3, &mut StdRng::seed_from_u64(0)).unwrap();
+    assert_eq!(result.state, 12);
+    assert_eq!(result.value, 0.);
+    assert_eq!(result.steps, 12);
+    assert_eq!(result.trajectory, (0..=12).rev().map(f64::from).collect::<Vec<f64>>());
+}
+
+#[test]
+fn local_beam_search_solves_queens() {
+    let mut rng = StdRng::seed_from_u64(47);
+    let mut solved = 0;
+    for _ in 0..10 {
+        let problem = QueensProblem::random(8, &mut rng).unwrap();
+        let result = local_beam_search(&problem, 20, &mut rng).unwrap();
+        assert_eq!(result.trajectory.len(), result.steps + 1);
+        assert_eq!(result.value, problem.value(&result.state));
+        if problem.is_optimal(&result.state) {
+            solved += 1;
+        }
+    }
+    assert!(solved >= 5, "solved {} boards out of 10", solved);
+}
+
+#[test]
+fn stochastic_beam_search_solves_queens() {
+    let mut rng = StdRng::seed_from_u64(47);
+    let mut solved = 0;
+    for _ in 0..10 {
+        let problem = QueensProblem::random(8, &mut rng).unwrap();
+        let result = stochastic_beam_search(&problem, 20, 0.5, &mut rng).unwrap();
+        assert_eq!(result.trajectory.len(), result.steps + 1);
+        if problem.is_optimal(&result.state) {
+            solved += 1;
+        }
+    }
+    assert!(solved >= 5, "solved {} boards out of 10", solved);
+}
+
+#[test]
+fn beam_search_is_reproducible_with_a_seed() {
+    let problem = TourProblem::random(15, &mut StdRng::seed_from_u64(3)).unwrap();
+    for selection in [BeamSelection::Best, BeamSelection::Stochastic { temperature: 0.05 }] {
+        let beam = Beam { k: 5, selection, max_iterations: 50, ..Beam::default() };
+        let first = beam_search_with(&problem, &beam, &mut StdRng::seed_from_u64(9)).unwrap();
+        let second = beam_search_with(&problem, &beam, &mut StdRng::seed_from_u64(9)).unwrap();
+        assert_eq!(first.state, second.state);
+        assert_eq!(first.trajectory, second.trajectory);
+        assert!(first.value <= first.trajectory[0]);
+    }
+}
+
+#[test]
+fn stochastic_beam_search_stops_on_stagnation() {
+    let problem = TourProblem::random(10, &mut StdRng::seed_from_u64(4)).unwrap();
+    let beam = Beam {
+        k: 3,
+        selection: BeamSelection::Stochastic { temperature: 100. },
+        max_iterations: 10_000,
+        max_stagnation: 20,
+    };
+    let result = beam_search_with(&problem, &beam, &mut StdRng::seed_from_u64(0)).unwrap();
+    assert!(result.steps < 10_000);
+    assert!(result.value <= result.trajectory.iter().cloned().fold(f64::INFINITY, f64::min) + 1e-9);
+}
+
+#[test]
+fn beam_search_rejects_bad_settings() {
+    let problem = Valley { start: 0, target: 3 };
+    let mut rng = StdRng::seed_from_u64(0);
+    assert!(local_beam_search(&problem, 0, &mut rng).is_err());
+    assert!(stochastic_beam_search(&problem, 2, 0., &mut rng).is_err());
+    assert!(stochastic_beam_search(&problem, 2, f64::NAN, &mut rng).is_err());
+}
+
+/// Integers valued by how high they are, except that those beyond 2 have no finite value
+struct Plateau;
+
+impl LocalSearchProblem<i32> for Plateau {
+    fn initial(&self) -> i32 {
+        0
+    }
+
+    fn neighbors(&self, state: &i32) -> Vec<i32> {
+        vec![state - 1, state + 1]
+    }
+
+    fn value(&self, state: &i32) -> f64 {
+        match state {
+            3 => f64::NAN,
+            4.. => f64::INFINITY,
+            _ => *state as f64,
+        }
+    }
+}
+
+#[test]
+fn stochastic_beam_search_never_draws_a_score_that_is_not_finite() {
+    let result = stochastic_beam_search(&Plateau, 5, 1., &mut StdRng::seed_from_u64(47)).unwrap();
+    assert!(result.steps > 0);
+    assert!(result.trajectory.iter().all(|value| value.is_finite()));
+    assert_eq!(result.value, 2.);
+
+    /* Nothing to draw from */
+    struct Nowhere;
+    impl LocalSearchProblem<i32> for Nowhere {
+        fn initial(&self) -> i32 {
+            0
+        }
+        fn neighbors(&self, _: &i32) -> Vec<i32> {
+            vec![1]
+        }
+        fn value(&self, state: &i32) -> f64 {
+            if *state == 0 { 0. } else { f64::NAN }
+        }
+    }
+    assert_eq!(stochastic_beam_search(&Nowhere, 3, 1., &mut StdRng::seed_from_u64(47)).unwrap().steps, 0);
+}
@@ -0,0 +1,119 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use search_complex::{
+    empirical_gradient,
+    genetic_algorithm,
+    gradient_ascent,
+    random_restart_hill_climbing,
+    simulated_annealing_with,
+    Annealing,
+    Benchmark,
+    BenchmarkProblem,
+    ContinuousProblem,
+    Genetic,
+    GradientAscent,
+    HillClimbing,
+    LocalSearchProblem,
+    RealVector,
+    RealVectorOperators,
+    Schedule,
+    BENCHMARKS,
+};
+
+fn dimensions(benchmark: Benchmark) -> usize {
+    if benchmark == Benchmark::Himmelblau { 2 } else { 3 }
+}
+
+#[test]
+fn the_minima_have_the_lowest_value() {
+    let mut rng = StdRng::seed_from_u64(1);
+    for benchmark in BENCHMARKS {
+        let problem = BenchmarkProblem::random(benchmark, dimensions(benchmark), &mut rng).unwrap();
+        let minimum = problem.minimum();
+        assert!(minimum.abs() < 1e-4, "{:?} has minimum {}", benchmark, minimum);
+        for x in problem.minima() {
+            assert!((benchmark.value(&x) - minimum).abs() < 1e-4, "{:?} at {:?}", benchmark, x);
+            assert!(problem.is_optimal(&x), "{:?} at {:?}", benchmark, x);
+        }
+        for _ in 0..1000 {
+            let x = problem.random_state(&mut rng).unwrap();
+            assert!(benchmark.value(&x) >= minimum - 1e-9);
+        }
+    }
+}
+
+#[test]
+fn gradients_match_finite_differences() {
+    let mut rng = StdRng::seed_from_u64(2);
+    for benchmark in BENCHMARKS {
+        for _ in 0..100 {
+            let problem = BenchmarkProblem::random(benchmark, dimensions(benchmark), &mut rng).unwrap();
+            let x = ContinuousProblem::initial(&problem);
+            let analytic = problem.gradient(&x).unwrap();
+            let estimate = empirical_gradient(&problem, &x, 1e-6);
+            for (a, e) in analytic.iter().zip(&estimate) {
+                assert!((a - e).abs() <= 1e-4 * (1. + a.abs()), "{:?} at {:?}: {:?} but {:?}", benchmark, x, analytic, estimate);
+            }
+        }
+    }
+}
+
+#[test]
+fn invalid_problems_are_rejected() {
+    assert!(BenchmarkProblem::new(Benchmark::Himmelblau, vec![0.; 3]).is_err());
+    assert!(BenchmarkProblem::new(Benchmark::Rosenbrock, vec![0.]).is_err());
+    assert!(BenchmarkProblem::new(Benchmark::Rastrigin, vec![6., 0.]).is_err());
+    for step in [0., -0.1, f64::NAN, f64::INFINITY] {
+        assert!(BenchmarkProblem::new(Benchmark::Rastrigin, vec![1., 0.]).unwrap().with_step(step).is_err(), "{}", step);
+    }
+}
+
+#[test]
+fn gradient_descent_solves_the_smooth_functions() {
+    for (benchmark, start) in [(Benchmark::Rosenbrock, vec![-1.2, 1.]), (Benchmark::Himmelblau, vec![0., 0.])] {
+        let problem = BenchmarkProblem::new(benchmark, start).unwrap();
+        let settings = GradientAscent { max_iterations: 100_000, tolerance: 1e-9, ..GradientAscent::default() };
+        let result = gradient_ascent(&problem, &settings).unwrap();
+        assert!(result.value < 1e-6, "{:?} stopped at {:?}", benchmark, result.state);
+    }
+}
+
+#[test]
+fn hill_climbing_needs_restarts_on_rastrigin() {
+    /* Steps of 0.01 only reach a local minimum near the start */
+    let mut rng = StdRng::seed_from_u64(3);
+    let problem = BenchmarkProblem::new(Benchmark::Rastrigin, vec![3.3, -2.2]).unwrap().with_step(0.01).unwrap();
+    let once = random_restart_hill_climbing(&problem, HillClimbing::SteepestAscent, 0, &mut rng);
+    assert!(once.value > 5.);
+    let restarted = random_restart_hill_climbing(&problem, HillClimbing::SteepestAscent, 200, &mut rng);
+    assert!(restarted.value < 0.01, "{:?}", restarted.state);
+    assert!(restarted.restarts > 0);
+}
+
+#[test]
+fn annealing_escapes_the_local_minima_of_ackley() {
+    let mut rng = StdRng::seed_from_u64(4);
+    /* Hill climbing stops in the local minimum next to the start */
+    let problem = BenchmarkProblem::new(Benchmark::Ackley, vec![6., -7.]).unwrap().with_step(0.3).unwrap();
+    let once = random_restart_hill_climbing(&problem, HillClimbing::SteepestAscent, 0, &mut rng);
+    assert!(once.value > 10.);
+    let annealing = Annealing {
+        schedule: Schedule::Exponential { t0: 2., alpha: 0.9995 },
+        ..Annealing::default()
+    };
+    let result = simulated_annealing_with(&problem, &annealing, &mut rng);
+    assert!(result.value < 0.5, "stopped at {:?}", result.state);
+}
+
+#[test]
+fn the_genetic_algorithm_finds_the_minima() {
+    let mut rng = StdRng::seed_from_u64(5);
+    for benchmark in [Benchmark::Rastrigin, Benchmark::Ackley, Benchmark::Himmelblau, Benchmark::Schwefel] {
+        let problem = BenchmarkProblem::random(benchmark, 2, &mut rng).unwrap();
+        let context = RealVectorOperators::new(problem.bounds()).unwrap().with_sigma(0.01).unwrap();
+        let genetic = Genetic { max_generations: 300, mutation_rate: 0.3, elitism: 2, ..Genetic::default() };
+        let fitness = |x: &RealVector| -benchmark.value(&x.0);
+        let result = genetic_algorithm(&context, fitness, &genetic, &mut rng).unwrap();
+        assert!(-result.fitness < 0.01, "{:?} reached {} at {:?}", benchmark, -result.fitness, result.best.0);
+    }
+}
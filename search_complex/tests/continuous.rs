@@ -0,0 +1,183 @@
+use search_complex::{
+    empirical_gradient,
+    gradient_ascent,
+    newton_raphson,
+    ContinuousProblem,
+    Derivatives,
+    GradientAscent,
+    NewtonRaphson,
+    Objective,
+    StepSize,
+    GRAPH_PROBLEM,
+};
+
+/// (x - 1)^2 + 10 (y + 2)^2, without derivatives
+struct Bowl;
+
+impl ContinuousProblem for Bowl {
+    fn dimensions(&self) -> usize {
+        2
+    }
+
+    fn initial(&self) -> Vec<f64> {
+        vec![5., 5.]
+    }
+
+    fn value(&self, x: &[f64]) -> f64 {
+        (x[0] - 1.).powi(2) + 10. * (x[1] + 2.).powi(2)
+    }
+
+    fn objective(&self) -> Objective {
+        Objective::Minimize
+    }
+}
+
+/// The Rosenbrock function, with its gradient but not its Hessian
+struct Rosenbrock;
+
+impl ContinuousProblem for Rosenbrock {
+    fn dimensions(&self) -> usize {
+        2
+    }
+
+    fn initial(&self) -> Vec<f64> {
+        vec![-1.2, 1.]
+    }
+
+    fn value(&self, x: &[f64]) -> f64 {
+        (1. - x[0]).powi(2) + 100. * (x[1] - x[0] * x[0]).powi(2)
+    }
+
+    fn objective(&self) -> Objective {
+        Objective::Minimize
+    }
+
+    fn gradient(&self, x: &[f64]) -> Option<Vec<f64>> {
+        Some(vec![
+            -2. * (1. - x[0]) - 400. * x[0] * (x[1] - x[0] * x[0]),
+            200. * (x[1] - x[0] * x[0]),
+        ])
+    }
+}
+
+/// A plane, whose Hessian is 0
+struct Plane;
+
+impl ContinuousProblem for Plane {
+    fn dimensions(&self) -> usize {
+        2
+    }
+
+    fn initial(&self) -> Vec<f64> {
+        vec![0., 0.]
+    }
+
+    fn value(&self, x: &[f64]) -> f64 {
+        x[0] + 2. * x[1]
+    }
+}
+
+fn close(a: &[f64], b: &[f64], tolerance: f64) -> bool {
+    a.iter().zip(b).all(|(a, b)| (a - b).abs() <= tolerance)
+}
+
+#[test]
+fn gradient_ascent_climbs_the_curve() {
+    /* The peak left of 0 is at x = -10 */
+    let settings = [
+        GradientAscent::default(),
+        GradientAscent { step: StepSize::Fixed(0.5), ..GradientAscent::default() },
+        GradientAscent { derivatives: Derivatives::Empirical { h: 1e-6 }, ..GradientAscent::default() },
+    ];
+    for settings in settings {
+        let result = gradient_ascent(&GRAPH_PROBLEM, &settings).unwrap();
+        assert!(close(&result.state, &[-10.], 1e-4), "{:?} stopped at {:?}", settings, result.state);
+        assert!(result.steps < settings.max_iterations);
+        assert!(result.trajectory.windows(2).all(|w| w[0] <= w[1] + 1e-12));
+    }
+}
+
+#[test]
+fn newton_raphson_finds_a_stationary_point_whatever_the_objective() {
+    let result = newton_raphson(&GRAPH_PROBLEM, &NewtonRaphson::default()).unwrap();
+    let slope = GRAPH_PROBLEM.gradient(&result.state).unwrap()[0];
+    assert!(slope.abs() < 1e-9);
+    /* From 0 it goes to the minimum at 0.2x + 2 = pi, not the maximum at 0.2x + 2 = 0 */
+    assert!(close(&result.state, &[5. * std::f64::consts::PI - 10.], 1e-6), "{:?}", result.state);
+    assert!(result.value < -9.99);
+}
+
+#[test]
+fn empirical_derivatives_minimise_a_bowl() {
+    let descent = gradient_ascent(&Bowl, &GradientAscent::default()).unwrap();
+    assert!(close(&descent.state, &[1., -2.], 1e-4), "{:?}", descent.state);
+    assert!(descent.value < 1e-8);
+
+    /* A quadratic takes a single Newton step, up to the error of the estimated Hessian */
+    let newton = newton_raphson(&Bowl, &NewtonRaphson { tolerance: 1e-6, ..NewtonRaphson::default() }).unwrap();
+    assert!(close(&newton.state, &[1., -2.], 1e-6), "{:?}", newton.state);
+    assert!(newton.steps <= 3);
+
+    let estimate = empirical_gradient(&Bowl, &[2., 0.], 1e-5);
+    assert!(close(&estimate, &[2., 40.], 1e-6));
+}
+
+#[test]
+fn newton_raphson_solves_rosenbrock_much_faster_than_gradient_descent() {
+    let newton = newton_raphson(&Rosenbrock, &NewtonRaphson::default()).unwrap();
+    assert!(close(&newton.state, &[1., 1.], 1e-6), "{:?}", newton.state);
+
+    let settings = GradientAscent { max_iterations: 50_000, tolerance: 1e-8, ..GradientAscent::default() };
+    let descent = gradient_ascent(&Rosenbrock, &settings).unwrap();
+    assert!(close(&descent.state, &[1., 1.], 1e-3), "{:?}", descent.state);
+    assert!(newton.steps * 10 < descent.steps);
+}
+
+#[test]
+fn flat_curvature_and_wrong_dimensions_are_errors() {
+    assert!(newton_raphson(&Plane, &NewtonRaphson::default()).is_err());
+
+    struct Wrong;
+    impl ContinuousProblem for Wrong {
+        fn dimensions(&self) -> usize {
+            3
+        }
+        fn initial(&self) -> Vec<f64> {
+            vec![0.]
+        }
+        fn value(&self, x: &[f64]) -> f64 {
+            x[0]
+        }
+    }
+    assert!(gradient_ascent(&Wrong, &GradientAscent::default()).is_err());
+}
+
+#[test]
+fn line_searches_which_cannot_end_are_errors() {
+    for shrink in [0., 1., 1.5, -0.5, f64::NAN] {
+        let settings = GradientAscent { step: StepSize::LineSearch { initial: 1., shrink }, ..GradientAscent::default() };
+        assert!(gradient_ascent(&Bowl, &settings).is_err(), "shrink {}", shrink);
+    }
+    let settings = GradientAscent { step: StepSize::LineSearch { initial: 0., shrink: 0.5 }, ..GradientAscent::default() };
+    assert!(gradient_ascent(&Bowl, &settings).is_err());
+    assert!(gradient_ascent(&Bowl, &GradientAscent { tolerance: -1., ..GradientAscent::default() }).is_err());
+
+    /* The square root of |x|, whose slope is infinite at 0 */
+    struct Spike;
+    impl ContinuousProblem for Spike {
+        fn dimensions(&self) -> usize {
+            1
+        }
+        fn initial(&self) -> Vec<f64> {
+            vec![0.]
+        }
+        fn value(&self, x: &[f64]) -> f64 {
+            x[0].abs().sqrt()
+        }
+        fn gradient(&self, x: &[f64]) -> Option<Vec<f64>> {
+            Some(vec![x[0].signum() / (2. * x[0].abs().sqrt())])
+        }
+    }
+    assert!(gradient_ascent(&Spike, &GradientAscent::default()).is_err());
+    assert!(newton_raphson(&Spike, &NewtonRaphson::default()).is_err());
+}
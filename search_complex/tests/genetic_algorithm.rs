@@ -0,0 +1,65 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use search_complex::{
+    genetic_algorithm,
+    Genetic,
+    QueensOperators,
+    QueensState,
+    Selection,
+    Termination,
+};
+
+fn fitness(board: &QueensState) -> f64 {
+    board.non_attacking_pairs() as f64
+}
+
+#[test]
+fn every_selection_solves_8_queens() {
+    for selection in [Selection::Roulette, Selection::Tournament(3), Selection::Rank] {
+        let genetic = Genetic {
+            selection,
+            elitism: 2,
+            mutation_rate: 0.5,
+            max_generations: 5000,
+            target_fitness: Some(28.),
+            ..Genetic::default()
+        };
+        let result = genetic_algorithm(&QueensOperators::new(8), fitness, &genetic, &mut StdRng::seed_from_u64(8)).unwrap();
+        assert_eq!(result.termination, Termination::TargetFitness, "{:?}", selection);
+        assert_eq!(result.best.attacking_pairs(), 0);
+        assert_eq!(result.fitness, 28.);
+    }
+}
+
+#[test]
+fn elitism_never_loses_the_fittest() {
+    let genetic = Genetic { elitism: 1, max_generations: 50, ..Genetic::default() };
+    let result = genetic_algorithm(&QueensOperators::new(12), fitness, &genetic, &mut StdRng::seed_from_u64(1)).unwrap();
+    assert_eq!(result.termination, Termination::Generations);
+    assert_eq!(result.generations, 50);
+    assert_eq!(result.history.len(), 51);
+    assert!(result.history.windows(2).all(|w| w[0] <= w[1]));
+    assert_eq!(result.fitness, *result.history.last().unwrap());
+}
+
+#[test]
+fn a_flat_fitness_stagnates() {
+    let genetic = Genetic { max_stagnation: Some(10), ..Genetic::default() };
+    let result = genetic_algorithm(&QueensOperators::new(8), |_: &QueensState| 0., &genetic, &mut StdRng::seed_from_u64(2)).unwrap();
+    assert_eq!(result.termination, Termination::Stagnation);
+    assert_eq!(result.generations, 10);
+}
+
+#[test]
+fn invalid_settings_are_rejected() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let settings = [
+        Genetic { population_size: 0, ..Genetic::default() },
+        Genetic { elitism: 101, ..Genetic::default() },
+        Genetic { selection: Selection::Tournament(0), ..Genetic::default() },
+        Genetic { mutation_rate: 1.5, ..Genetic::default() },
+    ];
+    for genetic in settings {
+        assert!(genetic_algorithm(&QueensOperators::new(8), fitness, &genetic, &mut rng).is_err());
+    }
+}
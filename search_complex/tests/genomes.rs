@@ -0,0 +1,154 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use search_complex::{
+    algorithms::genomes::{cycle, ox, pmx},
+    genetic_algorithm,
+    BitCrossover,
+    BitString,
+    BitStringOperators,
+    Genetic,
+    Genome,
+    Permutation,
+    PermutationCrossover,
+    PermutationMutation,
+    PermutationOperators,
+    QueensState,
+    RealCrossover,
+    RealVector,
+    RealVectorOperators,
+    Termination,
+    TourProblem,
+};
+
+/* The parents of the usual textbook examples, counting from 0 */
+const PARENT1: [usize; 9] = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+const PARENT2: [usize; 9] = [3, 4, 1, 0, 7, 6, 5, 8, 2];
+
+fn is_permutation(genes: &[usize]) -> bool {
+    let mut sorted = genes.to_vec();
+    sorted.sort();
+    sorted.iter().enumerate().all(|(i, g)| i == *g)
+}
+
+#[test]
+fn permutation_crossovers_match_the_textbook() {
+    assert_eq!(pmx(&PARENT1, &PARENT2, 3, 7), [0, 7, 1, 3, 4, 5, 6, 8, 2]);
+    assert_eq!(ox(&PARENT1, &PARENT2, 3, 7), [1, 0, 7, 3, 4, 5, 6, 8, 2]);
+    assert_eq!(cycle(&[0, 1, 2, 3, 4, 5, 6, 7], &[7, 4, 1, 0, 2, 5, 3, 6]), [0, 4, 1, 3, 2, 5, 6, 7]);
+}
+
+#[test]
+fn permutation_operators_keep_permutations() {
+    let mut rng = StdRng::seed_from_u64(1);
+    for crossover in [PermutationCrossover::Pmx, PermutationCrossover::Ox, PermutationCrossover::Cycle] {
+        for mutation in [PermutationMutation::Swap, PermutationMutation::Inversion] {
+            let context = PermutationOperators::new(30).with_crossover(crossover).with_mutation(mutation);
+            assert_eq!((context.length(), context.crossover(), context.mutation()), (30, crossover, mutation));
+            for _ in 0..100 {
+                let parent1 = Permutation::random(&context, &mut rng);
+                let parent2 = Permutation::random(&context, &mut rng);
+                let mut child = parent1.crossover(&parent2, &context, &mut rng);
+                assert!(is_permutation(&child.0), "{:?}", crossover);
+                child.mutate(&context, &mut rng);
+                assert!(is_permutation(&child.0), "{:?}", mutation);
+            }
+        }
+    }
+}
+
+#[test]
+fn bit_crossovers_take_every_bit_from_a_parent() {
+    let mut rng = StdRng::seed_from_u64(2);
+    let zeros = BitString(vec![false; 64]);
+    let ones = BitString(vec![true; 64]);
+    for crossover in [BitCrossover::OnePoint, BitCrossover::TwoPoint, BitCrossover::Uniform] {
+        let context = BitStringOperators::new(64).with_crossover(crossover);
+        let child = zeros.crossover(&ones, &context, &mut rng);
+        let changes = child.0.windows(2).filter(|w| w[0] != w[1]).count();
+        match crossover {
+            BitCrossover::OnePoint => assert!(changes <= 1),
+            BitCrossover::TwoPoint => assert!(changes <= 2),
+            BitCrossover::Uniform => assert!(changes > 2),
+        }
+    }
+
+    let mut all = BitString(vec![false; 64]);
+    all.mutate(&BitStringOperators::new(64).with_flip_rate(1.).unwrap(), &mut rng);
+    assert_eq!(all, ones);
+}
+
+#[test]
+fn real_operators_stay_within_the_bounds() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let bounds = vec![(-1., 1.), (0., 10.), (5., 5.)];
+    for crossover in [RealCrossover::Blx { alpha: 0.5 }, RealCrossover::Sbx { eta: 2. }] {
+        let context = RealVectorOperators::new(bounds.clone()).unwrap().with_crossover(crossover).unwrap().with_sigma(0.5).unwrap();
+        for _ in 0..100 {
+            let parent1 = RealVector::random(&context, &mut rng);
+            let parent2 = RealVector::random(&context, &mut rng);
+            let mut child = parent1.crossover(&parent2, &context, &mut rng);
+            child.mutate(&context, &mut rng);
+            assert!(child.0.iter().zip(&bounds).all(|(x, (low, high))| low <= x && x <= high));
+        }
+    }
+}
+
+#[test]
+fn bit_strings_solve_one_max() {
+    let context = BitStringOperators::new(40).with_crossover(BitCrossover::Uniform);
+    let genetic = Genetic { target_fitness: Some(40.), mutation_rate: 0.5, ..Genetic::default() };
+    let ones = |bits: &BitString| bits.0.iter().filter(|b| **b).count() as f64;
+    let result = genetic_algorithm(&context, ones, &genetic, &mut StdRng::seed_from_u64(4)).unwrap();
+    assert_eq!(result.termination, Termination::TargetFitness);
+}
+
+#[test]
+fn permutations_solve_8_queens() {
+    /* With one queen on every row, only the diagonals can attack */
+    let context = PermutationOperators::new(8).with_crossover(PermutationCrossover::Pmx).with_mutation(PermutationMutation::Swap);
+    let genetic = Genetic { target_fitness: Some(28.), mutation_rate: 0.5, ..Genetic::default() };
+    let fitness = |p: &Permutation| QueensState::new(p.0.clone()).unwrap().non_attacking_pairs() as f64;
+    let result = genetic_algorithm(&context, fitness, &genetic, &mut StdRng::seed_from_u64(5)).unwrap();
+    assert_eq!(result.termination, Termination::TargetFitness);
+    assert_eq!(QueensState::new(result.best.0).unwrap().attacking_pairs(), 0);
+}
+
+#[test]
+fn permutations_shorten_tours() {
+    let mut rng = StdRng::seed_from_u64(6);
+    let problem = TourProblem::random(30, &mut rng).unwrap();
+    let context = PermutationOperators::new(30);
+    let genetic = Genetic { max_generations: 500, mutation_rate: 0.5, elitism: 2, ..Genetic::default() };
+    let result = genetic_algorithm(&context, |tour: &Permutation| -problem.tour_length(&tour.0), &genetic, &mut rng).unwrap();
+    /* The fitness is the negated length: the best of 100 random tours is much longer */
+    assert!(-result.fitness < 0.6 * -result.history[0], "from {} to {}", -result.history[0], -result.fitness);
+    assert!(is_permutation(&result.best.0));
+}
+
+#[test]
+fn real_vectors_minimise_the_sphere() {
+    let sphere = |x: &RealVector| -x.0.iter().map(|x| x * x).sum::<f64>();
+    for crossover in [RealCrossover::Blx { alpha: 0.5 }, RealCrossover::Sbx { eta: 2. }] {
+        let context = RealVectorOperators::new(vec![(-5., 5.); 5]).unwrap().with_crossover(crossover).unwrap().with_sigma(0.01).unwrap();
+        let genetic = Genetic { max_generations: 300, mutation_rate: 0.2, elitism: 2, ..Genetic::default() };
+        let result = genetic_algorithm(&context, sphere, &genetic, &mut StdRng::seed_from_u64(7)).unwrap();
+        assert!(result.fitness > -1e-2, "{:?} reached {}", crossover, -result.fitness);
+    }
+}
+
+#[test]
+fn operators_reject_rates_and_bounds_they_cannot_use() {
+    for flip_rate in [-0.1, 1.5, f64::NAN] {
+        assert!(BitStringOperators::new(8).with_flip_rate(flip_rate).is_err(), "{}", flip_rate);
+    }
+    assert_eq!(BitStringOperators::new(8).with_flip_rate(0.).unwrap().flip_rate(), 0.);
+
+    assert!(RealVectorOperators::new(vec![(0., 1.), (2., -2.)]).is_err());
+    assert!(RealVectorOperators::new(vec![(f64::NAN, 1.)]).is_err());
+    assert!(RealVectorOperators::new(vec![(0., f64::INFINITY)]).is_err());
+    let context = RealVectorOperators::new(vec![(0., 1.), (3., 3.)]).unwrap();
+    assert_eq!(context.bounds(), [(0., 1.), (3., 3.)]);
+    assert!(context.clone().with_sigma(-1.).is_err());
+    assert!(context.clone().with_crossover(RealCrossover::Sbx { eta: f64::NAN }).is_err());
+    assert!(context.with_crossover(RealCrossover::Blx { alpha: -0.5 }).is_err());
+}
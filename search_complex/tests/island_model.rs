@@ -0,0 +1,81 @@
+use search_complex::{
+    island_genetic_algorithm,
+    Genetic,
+    Islands,
+    QueensOperators,
+    QueensState,
+    Termination,
+    Topology,
+};
+
+fn fitness(board: &QueensState) -> f64 {
+    board.non_attacking_pairs() as f64
+}
+
+#[test]
+fn seeded_runs_are_reproducible() {
+    let genetic = Genetic { population_size: 30, max_generations: 40, ..Genetic::default() };
+    let islands = Islands { seed: Some(42), ..Islands::default() };
+    let first = island_genetic_algorithm(&QueensOperators::new(10), fitness, &genetic, &islands).unwrap();
+    let second = island_genetic_algorithm(&QueensOperators::new(10), fitness, &genetic, &islands).unwrap();
+    assert_eq!(first.best, second.best);
+    assert_eq!(first.island, second.island);
+    let histories = |result: &search_complex::IslandResult<QueensState>| -> Vec<Vec<f64>> {
+        result.islands.iter().map(|island| island.history.clone()).collect()
+    };
+    assert_eq!(histories(&first), histories(&second));
+}
+
+#[test]
+fn migrants_follow_the_topology() {
+    let genetic = Genetic { population_size: 20, max_generations: 20, ..Genetic::default() };
+    for (topology, immigrants) in [(Topology::Ring, 3 * 2), (Topology::FullyConnected, 3 * 3 * 2)] {
+        let islands = Islands { islands: 4, topology, migration_interval: 5, migrants: 2, seed: Some(1) };
+        let result = island_genetic_algorithm(&QueensOperators::new(10), fitness, &genetic, &islands).unwrap();
+        assert_eq!(result.termination, Termination::Generations);
+        assert_eq!(result.generations, 20);
+        assert_eq!(result.islands.len(), 4);
+        for island in &result.islands {
+            /* Three migrations, between the four epochs of five generations */
+            assert_eq!(island.immigrants, immigrants, "{:?}", topology);
+            assert_eq!(island.history.len(), 21);
+            assert!(island.fitness <= result.fitness);
+        }
+        assert_eq!(result.fitness, fitness(&result.best));
+    }
+}
+
+#[test]
+fn islands_solve_12_queens() {
+    let genetic = Genetic { population_size: 50, mutation_rate: 0.5, target_fitness: Some(66.), max_generations: 5000, ..Genetic::default() };
+    let islands = Islands { islands: 4, seed: Some(12), ..Islands::default() };
+    let result = island_genetic_algorithm(&QueensOperators::new(12), fitness, &genetic, &islands).unwrap();
+    assert_eq!(result.termination, Termination::TargetFitness);
+    assert_eq!(result.best.attacking_pairs(), 0);
+    assert_eq!(result.islands[result.island].fitness, 66.);
+}
+
+#[test]
+fn islands_stop_when_all_of_them_stagnate() {
+    let genetic = Genetic { max_stagnation: Some(12), ..Genetic::default() };
+    let islands = Islands { migration_interval: 5, seed: Some(3), ..Islands::default() };
+    let result = island_genetic_algorithm(&QueensOperators::new(8), |_: &QueensState| 1., &genetic, &islands).unwrap();
+    assert_eq!(result.termination, Termination::Stagnation);
+    assert_eq!(result.generations, 12);
+    /* Epochs of 5, 5 and 2 generations, with no migration after the last one */
+    for island in &result.islands {
+        assert_eq!(island.immigrants, 2 * 2);
+    }
+}
+
+#[test]
+fn invalid_islands_are_rejected() {
+    let genetic = Genetic::default();
+    for islands in [
+        Islands { islands: 0, ..Islands::default() },
+        Islands { migration_interval: 0, ..Islands::default() },
+        Islands { migrants: 101, ..Islands::default() },
+    ] {
+        assert!(island_genetic_algorithm(&QueensOperators::new(8), fitness, &genetic, &islands).is_err());
+    }
+}
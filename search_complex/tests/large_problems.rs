@@ -0,0 +1,137 @@
+//! Local search on 1000 queens and 500 cities. The tours take minutes without optimisations, so
+//! their tests only run in release builds:
+//!
+//!     cargo test --release -p search_complex --test large_problems
+//!
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use search_complex::{
+    first_choice_hill_climbing,
+    first_choice_hill_climbing_with_sideways_moves,
+    genetic_algorithm,
+    simulated_annealing_with,
+    Annealing,
+    Genetic,
+    LocalSearchProblem,
+    Permutation,
+    PermutationOperators,
+    QueensMove,
+    QueensOperators,
+    QueensProblem,
+    QueensState,
+    Schedule,
+    Selection,
+    Termination,
+    TourProblem,
+};
+
+const QUEENS: usize = 1000;
+const CITIES: usize = 500;
+/* Side of the square the cities are in */
+const SIDE: f64 = 1000.;
+
+fn queens(seed: u64) -> QueensProblem {
+    QueensProblem::random(QUEENS, &mut StdRng::seed_from_u64(seed)).unwrap()
+}
+
+/* Random cities with integer coordinates, written and read back in the TSPLIB format */
+fn cities(seed: u64) -> TourProblem {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut text = format!("NAME : random{}\nTYPE : TSP\nDIMENSION : {}\nEDGE_WEIGHT_TYPE : EUC_2D\nNODE_COORD_SECTION\n", CITIES, CITIES);
+    for city in 1..=CITIES {
+        text += &format!("{} {} {}\n", city, rng.gen_range(0..SIDE as u32), rng.gen_range(0..SIDE as u32));
+    }
+    text += "EOF\n";
+    TourProblem::from_tsplib(&text).unwrap()
+}
+
+/* The length of the shortest tour through n random cities of a square is close to
+   0.7124 sqrt(n * area) (Beardwood, Halton and Hammersley) */
+fn expected_optimum() -> f64 {
+    0.7124 * (CITIES as f64).sqrt() * SIDE
+}
+
+#[test]
+fn hill_climbing_with_sideways_moves_solves_1000_queens() {
+    /* Without sideways moves, the climb stops a few attacking pairs short of a solution */
+    let problem = queens(48).with_moves(QueensMove::MinConflicts);
+    let result = first_choice_hill_climbing_with_sideways_moves(&problem, 1000, 100, &mut StdRng::seed_from_u64(1));
+    assert!(problem.is_optimal(&result.state), "{} attacking pairs left", result.value);
+}
+
+#[test]
+fn annealing_solves_1000_queens() {
+    let problem = queens(48);
+    let annealing = Annealing {
+        schedule: Schedule::Exponential { t0: 0.5, alpha: 0.999 },
+        min_temperature: 0.,
+        max_steps: 200_000,
+        ..Annealing::default()
+    };
+    let result = simulated_annealing_with(&problem, &annealing, &mut StdRng::seed_from_u64(1));
+    assert!(problem.is_optimal(&result.state), "{} attacking pairs left", result.value);
+    assert!(result.steps < 200_000);
+}
+
+/* With every child mutated by a min-conflicts move, the population does min-conflicts local
+   search and the mutations, not the crossovers, solve the board. A genetic algorithm with
+   random mutations, as in AIMA, does not even solve 20 queens within thousands of generations. */
+#[test]
+fn min_conflicts_mutations_solve_1000_queens_in_a_small_population() {
+    let operators = QueensOperators::new(QUEENS).with_mutation(QueensMove::MinConflicts);
+    let genetic = Genetic {
+        population_size: 10,
+        mutation_rate: 1.,
+        max_generations: 5000,
+        target_fitness: Some((QUEENS * (QUEENS - 1) / 2) as f64),
+        ..Genetic::default()
+    };
+    let fitness = |board: &QueensState| board.non_attacking_pairs() as f64;
+    let result = genetic_algorithm(&operators, fitness, &genetic, &mut StdRng::seed_from_u64(48)).unwrap();
+    assert_eq!(result.termination, Termination::TargetFitness);
+    assert_eq!(result.best.attacking_pairs(), 0);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, ignore = "slow without optimisations")]
+fn hill_climbing_shortens_500_city_tours() {
+    let problem = cities(48);
+    let result = first_choice_hill_climbing(&problem, 20_000, &mut StdRng::seed_from_u64(1));
+    assert_eq!(result.value, problem.tour_length(&result.state));
+    assert!(result.value < 1.3 * expected_optimum(), "{} against {}", result.value, expected_optimum());
+}
+
+#[test]
+#[cfg_attr(debug_assertions, ignore = "slow without optimisations")]
+fn annealing_shortens_500_city_tours() {
+    let problem = cities(48);
+    let annealing = Annealing {
+        schedule: Schedule::Exponential { t0: 50., alpha: 0.99998 },
+        min_temperature: 1e-2,
+        max_steps: 500_000,
+        ..Annealing::default()
+    };
+    let result = simulated_annealing_with(&problem, &annealing, &mut StdRng::seed_from_u64(1));
+    assert!(result.value < 1.25 * expected_optimum(), "{} against {}", result.value, expected_optimum());
+}
+
+/* Crossovers of tours this long break most of what the parents got right, so a small population
+   mostly evolves by inversion mutations, which are 2-opt moves. It needs many more generations
+   than hill climbing needs moves, since every child is measured whole. */
+#[test]
+#[cfg_attr(debug_assertions, ignore = "slow without optimisations")]
+fn genetic_algorithm_shortens_500_city_tours() {
+    let problem = cities(48);
+    let genetic = Genetic {
+        population_size: 4,
+        selection: Selection::Tournament(2),
+        mutation_rate: 1.,
+        max_generations: 500_000,
+        ..Genetic::default()
+    };
+    let fitness = |tour: &Permutation| -problem.tour_length(&tour.0);
+    let result = genetic_algorithm(&PermutationOperators::new(CITIES), fitness, &genetic, &mut StdRng::seed_from_u64(48)).unwrap();
+    let length = -result.fitness;
+    assert!(length < 1.3 * expected_optimum(), "{} against {}", length, expected_optimum());
+}
@@ -0,0 +1,177 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use search_complex::{
+    first_choice_hill_climbing,
+    hill_climbing,
+    hill_climbing_with_sideways_moves,
+    random_restart_hill_climbing,
+    simulated_annealing,
+    stochastic_hill_climbing,
+    HillClimbing,
+    LocalSearchProblem,
+    Objective,
+    QueensProblem,
+    QueensState,
+    GRAPH_PROBLEM,
+};
+
+/// Distance of an integer from a target, to be minimised
+struct Valley {
+    start: i32,
+    target: i32,
+}
+
+impl LocalSearchProblem<i32> for Valley {
+    fn initial(&self) -> i32 {
+        self.start
+    }
+
+    fn neighbors(&self, state: &i32) -> Vec<i32> {
+        vec![state - 1, state + 1]
+    }
+
+    fn value(&self, state: &i32) -> f64 {
+        (state - self.target).abs() as f64
+    }
+
+    fn objective(&self) -> Objective {
+        Objective::Minimize
+    }
+}
+
+#[test]
+fn hill_climbing_reaches_the_top_of_the_curve() {
+    /* 10cos(0.2x + 2) falls to the right of 0, so the climb goes left to the peak at x = -10 */
+    let result = hill_climbing(&GRAPH_PROBLEM, &mut StdRng::seed_from_u64(0));
+    let peak = -10.;
+    assert!((result.state - peak).abs() <= 0.1, "stopped at {}", result.state);
+    assert!(result.value > 9.99);
+    assert_eq!(result.steps, 100);
+    assert_eq!(result.restarts, 0);
+}
+
+#[test]
+fn minimising_problems_descend() {
+    let problem = Valley { start: -7, target: 5 };
+    assert_eq!(problem.score(&3), -2.);
+    assert!(Objective::Minimize.is_better(1., 2.));
+    assert!(Objective::Maximize.is_better(2., 1.));
+    let result = hill_climbing(&problem, &mut StdRng::seed_from_u64(0));
+    assert_eq!((result.state, result.value, result.steps), (5, 0., 12));
+}
+
+/// A valley whose states past a cliff have no value
+struct Cliff {
+    valley: Valley,
+    edge: i32,
+}
+
+impl LocalSearchProblem<i32> for Cliff {
+    fn initial(&self) -> i32 {
+        self.valley.initial()
+    }
+
+    fn neighbors(&self, state: &i32) -> Vec<i32> {
+        self.valley.neighbors(state)
+    }
+
+    fn value(&self, state: &i32) -> f64 {
+        if *state > self.edge { f64::NAN } else { self.valley.value(state) }
+    }
+
+    fn objective(&self) -> Objective {
+        Objective::Minimize
+    }
+}
+
+#[test]
+fn successors_without_a_value_are_skipped() {
+    /* The NaN state past the edge comes last among the first neighbours, and must not stop the descent */
+    let problem = Cliff { valley: Valley { start: 12, target: 5 }, edge: 12 };
+    let result = hill_climbing(&problem, &mut StdRng::seed_from_u64(0));
+    assert_eq!((result.state, result.value, result.steps), (5, 0., 7));
+}
+
+#[test]
+fn simulated_annealing_descends_a_valley() {
+    /* Once the temperature has dropped, uphill moves are almost never taken */
+    let problem = Valley { start: 40, target: 0 };
+    let end = simulated_annealing(&problem, &mut StdRng::seed_from_u64(0));
+    assert!(end.value <= 1., "stopped at {}", end.state);
+}
+
+/* Share of random 8-queens boards on which the climb ends on a solution */
+fn solved(climb: impl Fn(&QueensProblem, &mut StdRng) -> QueensState) -> usize {
+    let mut rng = StdRng::seed_from_u64(8);
+    (0..100)
+        .filter(|_| {
+            let problem = QueensProblem::random(8, &mut rng).unwrap();
+            problem.is_optimal(&climb(&problem, &mut rng))
+        })
+        .count()
+}
+
+#[test]
+fn sideways_moves_solve_most_8_queens_boards() {
+    /* AIMA reports 14% for steepest ascent and 94% with up to 100 sideways moves */
+    let steepest = solved(|problem, rng| hill_climbing(problem, rng).state);
+    let sideways = solved(|problem, rng| hill_climbing_with_sideways_moves(problem, 100, rng).state);
+    assert!(steepest < 30, "steepest ascent solved {}%", steepest);
+    assert!(sideways > 80, "sideways moves solved {}%", sideways);
+}
+
+#[test]
+fn random_restarts_solve_8_queens() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let problem = QueensProblem::random(8, &mut rng).unwrap();
+    let first_choice_sideways = HillClimbing::FirstChoiceSideways { max_tries: 100, max_sideways: 20 };
+    for variant in [HillClimbing::SteepestAscent, HillClimbing::Sideways(100), HillClimbing::Stochastic, HillClimbing::FirstChoice(100), first_choice_sideways] {
+        let result = random_restart_hill_climbing(&problem, variant, 1000, &mut rng);
+        assert_eq!(result.value, 0., "{:?} failed", variant);
+        assert!(result.steps > 0);
+    }
+}
+
+#[test]
+fn random_restarts_stop_at_the_budget() {
+    /* Without restarts it is a single climb, which gets stuck on this board */
+    let problem = QueensProblem::new(QueensState::new(vec![0; 8]).unwrap()).unwrap();
+    let single = hill_climbing_with_sideways_moves(&problem, 0, &mut StdRng::seed_from_u64(2));
+    let result = random_restart_hill_climbing(&problem, HillClimbing::SteepestAscent, 0, &mut StdRng::seed_from_u64(2));
+    assert!(single.value > 0.);
+    assert_eq!((result.state, result.steps, result.restarts), (single.state, single.steps, 0));
+}
+
+#[test]
+fn stochastic_variants_only_move_uphill() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let problem = QueensProblem::random(8, &mut rng).unwrap();
+    let start = problem.value(&problem.initial());
+    let stochastic = stochastic_hill_climbing(&problem, &mut rng);
+    let first_choice = first_choice_hill_climbing(&problem, 200, &mut rng);
+    for result in [stochastic, first_choice] {
+        assert!(result.value <= start);
+        assert!(result.value + result.steps as f64 <= start, "each move removes an attacking pair");
+    }
+}
+
+#[test]
+fn queens_counts_follow_the_moves() {
+    let mut rng = StdRng::seed_from_u64(48);
+    let mut board = QueensProblem::random(30, &mut rng).unwrap().initial();
+    for _ in 0..500 {
+        let (column, row) = (rng.gen_range(0..30), rng.gen_range(0..30));
+        let delta = board.move_delta(column, row);
+        let before = board.attacking_pairs() as isize;
+        board.move_queen(column, row);
+        let recounted = QueensState::new(board.rows().to_vec()).unwrap();
+        assert_eq!(board.attacking_pairs(), recounted.attacking_pairs());
+        assert_eq!(board.attacking_pairs() as isize, before + delta);
+        for column in 0..30 {
+            assert_eq!(board.conflicts(column), recounted.conflicts(column));
+        }
+    }
+    assert!(QueensState::new(vec![0, 3, 1]).is_err());
+    assert_eq!(QueensState::new(vec![1, 3, 0, 2]).unwrap().attacking_pairs(), 0);
+    assert_eq!(QueensState::new(vec![0; 4]).unwrap().attacking_pairs(), 6);
+}
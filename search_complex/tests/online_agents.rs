@@ -0,0 +1,58 @@
+use std::{fmt::Debug, hash::Hash};
+
+use search_complex::{
+    Agent,
+    AradToBucharestProblem,
+    Cost,
+    LrtaAgent,
+    OnlineDFSAgent,
+    OnlineProblem,
+    ARAD_TO_BUCHAREST_PROBLEM,
+};
+
+/// Lets the agent act until it stops, returning the states it visited
+fn run_agent<T, P, S, A, C>(problem: &P, max_steps: usize) -> Vec<S>
+where
+    T: Agent<P, S, A, C>,
+    P: OnlineProblem<S, A, C>,
+    S: Clone + Eq + Hash,
+    A: Clone + Eq + Hash + Debug,
+    C: Cost,
+{
+    let stop = problem.stop();
+    let mut agent = T::new();
+    let mut state = problem.get_initial_node().state;
+    let mut visited = vec![state.clone()];
+    for _ in 0..max_steps {
+        let action = agent.step(problem, state.clone());
+        if action == stop {
+            return visited;
+        }
+        assert!(problem.actions(&state).contains(&action), "{:?} is not applicable", action);
+        state = problem.result(&state, &action);
+        visited.push(state.clone());
+    }
+    panic!("The agent did not stop within {} steps", max_steps);
+}
+
+#[test]
+fn online_dfs_agent_explores_until_the_goal() {
+    let problem = &ARAD_TO_BUCHAREST_PROBLEM;
+    let visited = run_agent::<OnlineDFSAgent<_, _>, _, _, _, _>(problem, 1000);
+    assert_eq!(visited.first(), Some(&"Arad"));
+    assert_eq!(visited.last(), Some(&"Bucharest"));
+}
+
+#[test]
+fn lrta_agent_reaches_the_goal() {
+    let problem = &ARAD_TO_BUCHAREST_PROBLEM;
+    let visited = run_agent::<LrtaAgent<_, _, _, _>, _, _, _, _>(problem, 1000);
+    assert_eq!(visited, ["Arad", "Sibiu", "Rimnicu Vilcea", "Pitesti", "Bucharest"]);
+}
+
+#[test]
+fn lrta_agent_takes_the_same_route_with_real_costs() {
+    let problem: AradToBucharestProblem<f64> = AradToBucharestProblem::arad_to_bucharest();
+    let visited = run_agent::<LrtaAgent<_, _, _, _>, _, _, _, _>(&problem, 1000);
+    assert_eq!(visited, ["Arad", "Sibiu", "Rimnicu Vilcea", "Pitesti", "Bucharest"]);
+}
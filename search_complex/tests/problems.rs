@@ -0,0 +1,55 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use search_complex::{
+    expand,
+    simulated_annealing,
+    NondeterministicProblem,
+    Problem,
+    ARAD_TO_BUCHAREST_PROBLEM,
+    GRAPH_PROBLEM,
+};
+
+#[test]
+fn results_match_the_deterministic_actions() {
+    let problem = &ARAD_TO_BUCHAREST_PROBLEM;
+    let root = problem.get_initial_node();
+    let children: Vec<_> = expand(problem, root.clone()).unwrap().into_iter().collect();
+    assert_eq!(children.len(), 3);
+    for child in &children {
+        let action = child.action.as_ref().unwrap();
+        assert_eq!(problem.results(&root.state, action), [child.state]);
+    }
+    let children: Vec<_> = children.into_iter().map(|n| n.state).collect();
+    for city in ["Sibiu", "Timisoara", "Zerind"] {
+        assert!(children.contains(&city));
+    }
+}
+
+#[test]
+fn expand_accumulates_the_path_cost() {
+    let problem = &ARAD_TO_BUCHAREST_PROBLEM;
+    let sibiu = expand(problem, problem.get_initial_node()).unwrap()
+        .into_iter()
+        .find(|n| n.state == "Sibiu")
+        .unwrap();
+    let fagaras = expand(problem, sibiu).unwrap().into_iter().find(|n| n.state == "Fagaras").unwrap();
+    assert_eq!(fagaras.path_cost, 140 + 99);
+}
+
+#[test]
+fn expand_links_children_to_their_parent() {
+    let problem = &ARAD_TO_BUCHAREST_PROBLEM;
+    for child in expand(problem, problem.get_initial_node()).unwrap() {
+        assert_eq!(child.parent.as_ref().map(|p| p.state), Some("Arad"));
+        let action = child.action.as_ref().unwrap();
+        assert_eq!(problem.result(&"Arad", action), child.state);
+    }
+}
+
+#[test]
+fn simulated_annealing_stays_on_the_grid() {
+    let x = simulated_annealing(&GRAPH_PROBLEM, &mut StdRng::seed_from_u64(0)).state;
+    /* Every move goes 0.1 from the origin */
+    let steps = x / 0.1;
+    assert!((steps - steps.round()).abs() < 1e-6);
+}
@@ -0,0 +1,123 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use search_complex::{
+    simulated_annealing,
+    simulated_annealing_with,
+    Annealing,
+    LocalSearchProblem,
+    QueensProblem,
+    Schedule,
+};
+
+fn queens(n: usize, seed: u64) -> QueensProblem {
+    QueensProblem::random(n, &mut StdRng::seed_from_u64(seed)).unwrap()
+}
+
+#[test]
+fn seeded_runs_are_reproducible() {
+    let problem = queens(8, 1);
+    let first = simulated_annealing(&problem, &mut StdRng::seed_from_u64(7));
+    let second = simulated_annealing(&problem, &mut StdRng::seed_from_u64(7));
+    assert_eq!(first.state, second.state);
+    assert_eq!(first.trajectory, second.trajectory);
+}
+
+#[test]
+fn every_schedule_improves_on_the_start() {
+    let schedules = [
+        Schedule::Exponential { t0: 2., alpha: 0.995 },
+        Schedule::Linear { t0: 2., rate: 0.001 },
+        Schedule::Logarithmic { c: 1. },
+        Schedule::Lam { t0: 2., steps: 5000 },
+    ];
+    for schedule in schedules {
+        let annealing = Annealing { schedule, max_steps: 5000, ..Annealing::default() };
+        let mut solved = 0;
+        for seed in 0..10 {
+            let problem = queens(8, seed);
+            let start = problem.value(&problem.initial());
+            let result = simulated_annealing_with(&problem, &annealing, &mut StdRng::seed_from_u64(seed));
+            assert!(result.value <= start, "{:?}", schedule);
+            /* The best state is never worse than any state of the trajectory */
+            assert!(result.trajectory.iter().all(|value| result.value <= *value));
+            assert_eq!(result.trajectory.len(), result.steps + 1);
+            if problem.is_optimal(&result.state) {
+                solved += 1;
+            }
+        }
+        assert!(solved >= 5, "{:?} solved {} of 10", schedule, solved);
+    }
+}
+
+#[test]
+fn the_search_stops_at_the_minimum_temperature() {
+    /* The temperature reaches 0.5 at step 50 */
+    let annealing = Annealing {
+        schedule: Schedule::Linear { t0: 1., rate: 0.01 },
+        min_temperature: 0.5,
+        ..Annealing::default()
+    };
+    let result = simulated_annealing_with(&queens(30, 2), &annealing, &mut StdRng::seed_from_u64(2));
+    assert_eq!(result.steps, 49);
+
+    let lam = Annealing { schedule: Schedule::Lam { t0: 1., steps: 300 }, ..Annealing::default() };
+    let result = simulated_annealing_with(&queens(30, 2), &lam, &mut StdRng::seed_from_u64(2));
+    assert_eq!(result.steps, 299);
+}
+
+#[test]
+fn reheating_starts_again_from_the_best_state() {
+    let annealing = Annealing {
+        schedule: Schedule::Exponential { t0: 0.5, alpha: 0.99 },
+        min_temperature: 0.,
+        max_steps: 3000,
+        reheat_after: Some(200),
+    };
+    let problem = queens(30, 3);
+    let result = simulated_annealing_with(&problem, &annealing, &mut StdRng::seed_from_u64(3));
+    assert!(result.restarts > 0);
+    /* Every reheat adds the value of the state it starts again from */
+    assert_eq!(result.trajectory.len(), result.steps + 1 + result.restarts);
+
+    let once = simulated_annealing_with(&problem, &Annealing { reheat_after: None, ..annealing }, &mut StdRng::seed_from_u64(3));
+    assert!(result.value <= once.value);
+}
+
+/// The squares 0 to 10 of a row, valued by how far right they are; square 1 has no value
+struct Row;
+
+impl LocalSearchProblem<i32> for Row {
+    fn initial(&self) -> i32 {
+        0
+    }
+
+    fn neighbors(&self, square: &i32) -> Vec<i32> {
+        [square - 1, square + 1].into_iter().filter(|s| (0..=10).contains(s)).collect()
+    }
+
+    fn value(&self, square: &i32) -> f64 {
+        if *square == 1 { f64::NAN } else { *square as f64 }
+    }
+}
+
+#[test]
+fn moves_to_a_nan_value_are_rejected() {
+    let annealing = Annealing { max_steps: 100, ..Annealing::default() };
+    let result = simulated_annealing_with(&Row, &annealing, &mut StdRng::seed_from_u64(40));
+    assert_eq!(result.steps, 100);
+    assert_eq!(result.state, 0);
+}
+
+#[test]
+fn the_search_stops_where_the_temperature_would_go_below_zero() {
+    for schedule in [
+        Schedule::Exponential { t0: -1., alpha: 0.99 },
+        Schedule::Logarithmic { c: -1. },
+        Schedule::Linear { t0: 1., rate: 0.01 },
+        Schedule::Exponential { t0: f64::NAN, alpha: 0.99 },
+    ] {
+        let annealing = Annealing { schedule, min_temperature: -10., ..Annealing::default() };
+        let result = simulated_annealing_with(&queens(8, 4), &annealing, &mut StdRng::seed_from_u64(4));
+        assert!(result.steps <= 100, "{:?}", schedule);
+    }
+}
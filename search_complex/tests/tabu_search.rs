@@ -0,0 +1,158 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use search_complex::{
+    hill_climbing,
+    late_acceptance_hill_climbing,
+    tabu_search,
+    tabu_search_with_attributes,
+    Aspiration,
+    LateAcceptance,
+    LocalSearchProblem,
+    QueensProblem,
+    QueensState,
+    Tabu,
+    TourProblem,
+};
+
+/* The queens of `to` which are on another row in `from`, as (column, row) */
+fn moved_queens(from: &QueensState, to: &QueensState) -> Vec<(usize, usize)> {
+    (0..to.size()).filter(|c| from.row(*c) != to.row(*c)).map(|c| (c, to.row(c))).collect()
+}
+
+#[test]
+fn tabu_search_solves_queens_with_attributes() {
+    let mut rng = StdRng::seed_from_u64(46);
+    let mut solved = 0;
+    for _ in 0..10 {
+        let problem = QueensProblem::random(20, &mut rng).unwrap();
+        let result = tabu_search_with_attributes(&problem, &Tabu::default(), moved_queens);
+        assert_eq!(result.value, problem.value(&result.state));
+        assert_eq!(result.trajectory.len(), result.steps + 1);
+        if problem.is_optimal(&result.state) {
+            solved += 1;
+        }
+    }
+    assert!(solved >= 9, "solved {} boards out of 10", solved);
+}
+
+#[test]
+fn tabu_search_escapes_where_hill_climbing_gets_stuck() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut stuck = 0;
+    for _ in 0..20 {
+        let problem = QueensProblem::random(8, &mut rng).unwrap();
+        if hill_climbing(&problem, &mut rng).value == 0. {
+            continue;
+        }
+        stuck += 1;
+        let tabu = tabu_search(&problem, &Tabu { max_iterations: 500, ..Tabu::default() });
+        assert_eq!(tabu.value, 0., "tabu search stopped at\n{}", tabu.state);
+    }
+    assert!(stuck >= 10, "hill climbing got stuck on {} boards out of 20", stuck);
+}
+
+#[test]
+fn tabu_search_goes_downhill_from_a_local_optimum() {
+    /* A local minimum which is not a solution: every neighbour is as bad or worse */
+    let stuck = hill_climbing(&QueensProblem::random(8, &mut StdRng::seed_from_u64(1)).unwrap(), &mut StdRng::seed_from_u64(1));
+    let problem = QueensProblem::new(stuck.state.clone()).unwrap();
+    assert!(stuck.value > 0.);
+    assert!(problem.neighbors(&stuck.state).iter().all(|board| problem.value(board) >= stuck.value));
+    let settings = Tabu { max_iterations: 20, max_stagnation: Some(20), ..Tabu::default() };
+    let result = tabu_search(&problem, &settings);
+    assert!(result.steps > 0);
+    assert!(result.value <= stuck.value);
+}
+
+#[test]
+fn tabu_search_stops_on_stagnation_and_when_everything_is_tabu() {
+    let problem = QueensProblem::random(6, &mut StdRng::seed_from_u64(1)).unwrap();
+    let settings = Tabu { max_iterations: 10_000, max_stagnation: Some(15), ..Tabu::default() };
+    let result = tabu_search(&problem, &settings);
+    assert!(result.steps < 10_000);
+
+    /* Three cities, whose only tour has no neighbours */
+    let tour = TourProblem::from_points(&[(0., 0.), (1., 0.), (1., 1.)]).unwrap();
+    let result = tabu_search(&tour, &Tabu::default());
+    assert_eq!(result.steps, 0);
+}
+
+#[test]
+fn tabu_tenure_and_aspiration_change_the_search() {
+    let mut rng = StdRng::seed_from_u64(11);
+    let problem = TourProblem::random(25, &mut rng).unwrap();
+    let attributes = |from: &Vec<usize>, to: &Vec<usize>| TourProblem::added_edges(from, to);
+    let settings = Tabu { max_iterations: 200, ..Tabu::default() };
+    let long = tabu_search_with_attributes(&problem, &Tabu { tenure: 40, ..settings }, attributes);
+    let never = tabu_search_with_attributes(&problem, &Tabu { tenure: 40, aspiration: Aspiration::Never, ..settings }, attributes);
+    assert!(long.value <= never.value + 1e-9);
+    let none = tabu_search_with_attributes(&problem, &Tabu { tenure: 0, ..settings }, attributes);
+    assert_ne!(none.trajectory, long.trajectory);
+}
+
+#[test]
+fn tabu_search_shortens_tours_below_hill_climbing() {
+    let mut rng = StdRng::seed_from_u64(46);
+    let problem = TourProblem::random(40, &mut rng).unwrap();
+    let climbed = hill_climbing(&problem, &mut rng);
+    let settings = Tabu { tenure: 15, max_iterations: 300, ..Tabu::default() };
+    let tabu = tabu_search_with_attributes(&problem, &settings, |from, to| TourProblem::added_edges(from, to));
+    assert!((tabu.value - problem.tour_length(&tabu.state)).abs() < 1e-9);
+    assert!(tabu.value <= climbed.value + 1e-9, "tabu {} against {}", tabu.value, climbed.value);
+    assert!(tabu.value < problem.tour_length(&problem.initial()));
+}
+
+#[test]
+fn added_edges_are_the_edges_a_two_opt_move_adds() {
+    let added = TourProblem::added_edges(&[0, 1, 2, 3, 4], &[0, 3, 2, 1, 4]);
+    assert_eq!(added, vec![(0, 3), (1, 4)]);
+    assert!(TourProblem::added_edges(&[0, 1, 2], &[0, 1, 2]).is_empty());
+}
+
+#[test]
+fn tours_have_two_opt_neighbours() {
+    let problem = TourProblem::random(6, &mut StdRng::seed_from_u64(0)).unwrap();
+    let tour = problem.initial();
+    let neighbors = problem.neighbors(&tour);
+    assert_eq!(neighbors.len(), 5 * 4 / 2 - 1);
+    for neighbor in &neighbors {
+        assert_eq!(neighbor[0], 0);
+        assert_eq!(TourProblem::added_edges(&tour, neighbor).len(), 2);
+    }
+    assert!(TourProblem::random(5, &mut StdRng::seed_from_u64(0)).unwrap().with_tour(vec![0, 1, 1, 3, 4]).is_err());
+}
+
+#[test]
+fn late_acceptance_solves_queens() {
+    let mut rng = StdRng::seed_from_u64(46);
+    let settings = LateAcceptance { history: 10, ..LateAcceptance::default() };
+    for _ in 0..5 {
+        let problem = QueensProblem::random(20, &mut rng).unwrap();
+        let result = late_acceptance_hill_climbing(&problem, &settings, &mut rng).unwrap();
+        assert!(problem.is_optimal(&result.state), "{} attacking pairs left", result.value);
+        assert_eq!(result.trajectory.len(), result.steps + 1);
+    }
+}
+
+#[test]
+fn late_acceptance_shortens_tours() {
+    let mut rng = StdRng::seed_from_u64(46);
+    let problem = TourProblem::random(40, &mut rng).unwrap();
+    let climbed = hill_climbing(&problem, &mut rng);
+    let settings = LateAcceptance { history: 100, max_iterations: 200_000, ..LateAcceptance::default() };
+    let late = late_acceptance_hill_climbing(&problem, &settings, &mut rng).unwrap();
+    assert!((late.value - problem.tour_length(&late.state)).abs() < 1e-9);
+    assert!(late.value <= climbed.value * 1.02, "late acceptance {} against {}", late.value, climbed.value);
+}
+
+#[test]
+fn late_acceptance_stops_and_validates() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let problem = QueensProblem::random(30, &mut rng).unwrap();
+    let settings = LateAcceptance { max_iterations: 100, ..LateAcceptance::default() };
+    assert_eq!(late_acceptance_hill_climbing(&problem, &settings, &mut rng).unwrap().steps, 100);
+    let settings = LateAcceptance { max_stagnation: Some(10), ..LateAcceptance::default() };
+    assert!(late_acceptance_hill_climbing(&problem, &settings, &mut rng).unwrap().steps < 100_000);
+    let settings = LateAcceptance { history: 0, ..LateAcceptance::default() };
+    assert!(late_acceptance_hill_climbing(&problem, &settings, &mut rng).is_err());
+}
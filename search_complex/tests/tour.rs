@@ -0,0 +1,151 @@
+use std::collections::HashSet;
+
+use rand::{rngs::StdRng, SeedableRng};
+
+use search_complex::{hill_climbing, LocalSearchProblem, Neighborhood, TourProblem};
+
+const SQUARE: &str = "NAME : square
+TYPE : TSP
+COMMENT : Four corners of a square
+DIMENSION : 4
+EDGE_WEIGHT_TYPE : EUC_2D
+NODE_COORD_SECTION
+1 0 0
+2 10 10
+3 0 10
+4 10 0
+EOF
+";
+
+/* 14 cities of Burma, whose shortest tour is 3323 long */
+const BURMA14: &str = "NAME: burma14
+TYPE: TSP
+COMMENT: 14-Staedte in Burma (Zaw Win)
+DIMENSION: 14
+EDGE_WEIGHT_TYPE: GEO
+EDGE_WEIGHT_FORMAT: FUNCTION
+DISPLAY_DATA_TYPE: COORD_DISPLAY
+NODE_COORD_SECTION
+   1  16.47       96.10
+   2  16.47       94.44
+   3  20.09       92.54
+   4  22.39       93.37
+   5  25.23       97.24
+   6  22.00       96.05
+   7  20.47       97.02
+   8  17.20       96.29
+   9  16.30       97.38
+  10  14.05       98.12
+  11  16.53       97.38
+  12  21.52       95.59
+  13  19.41       97.13
+  14  20.09       94.55
+EOF
+";
+
+#[test]
+fn tsplib_coordinates_give_rounded_distances() {
+    let problem = TourProblem::from_tsplib(SQUARE).unwrap();
+    assert_eq!(problem.cities(), 4);
+    assert_eq!(problem.distance(0, 1), 14.);
+    assert_eq!(problem.tour_length(&[0, 2, 1, 3]), 40.);
+    assert_eq!(problem.tour_length(&[0, 1, 2, 3]), 48.);
+
+    let ceiling = SQUARE.replace("EUC_2D", "CEIL_2D");
+    assert_eq!(TourProblem::from_tsplib(&ceiling).unwrap().distance(0, 1), 15.);
+    let att = SQUARE.replace("EUC_2D", "ATT");
+    assert_eq!(TourProblem::from_tsplib(&att).unwrap().distance(0, 1), 5.);
+}
+
+#[test]
+fn tsplib_geographical_distances_match_the_published_optimum() {
+    let problem = TourProblem::from_tsplib(BURMA14).unwrap();
+    assert_eq!(problem.cities(), 14);
+    let optimal = [1, 2, 14, 3, 4, 5, 6, 12, 7, 13, 8, 11, 9, 10].map(|city: usize| city - 1);
+    assert_eq!(problem.tour_length(&optimal), 3323.);
+
+    let problem = problem.with_neighborhood(Neighborhood::ThreeOpt);
+    let result = hill_climbing(&problem, &mut StdRng::seed_from_u64(0));
+    assert!(result.value <= 3323. * 1.05, "{}", result.value);
+}
+
+#[test]
+fn tsplib_explicit_formats_give_the_same_matrix() {
+    let full = TourProblem::from_tsplib(
+        "TYPE: TSP\nDIMENSION: 3\nEDGE_WEIGHT_TYPE: EXPLICIT\nEDGE_WEIGHT_FORMAT: FULL_MATRIX\nEDGE_WEIGHT_SECTION\n0 1 2\n1 0 3\n2 3 0\nEOF\n",
+    ).unwrap();
+    for (format, weights) in [
+        ("UPPER_ROW", "1 2\n3"),
+        ("LOWER_ROW", "1\n2 3"),
+        ("UPPER_DIAG_ROW", "0 1 2\n0 3\n0"),
+        ("LOWER_DIAG_ROW", "0\n1 0 2 3 0"),
+    ] {
+        let text = format!("TYPE: TSP\nDIMENSION: 3\nEDGE_WEIGHT_TYPE: EXPLICIT\nEDGE_WEIGHT_FORMAT: {}\nEDGE_WEIGHT_SECTION\n{}\nEOF\n", format, weights);
+        let problem = TourProblem::from_tsplib(&text).unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_eq!(problem.distance(i, j), full.distance(i, j), "{} from {} to {}", format, i, j);
+            }
+        }
+    }
+}
+
+#[test]
+fn tsplib_errors_are_reported() {
+    assert!(TourProblem::from_tsplib(&SQUARE.replace("TYPE : TSP", "TYPE : ATSP")).is_err());
+    assert!(TourProblem::from_tsplib(&SQUARE.replace("EUC_2D", "EUC_3D")).is_err());
+    assert!(TourProblem::from_tsplib(&SQUARE.replace("4 10 0\n", "")).is_err());
+    assert!(TourProblem::from_tsplib(&SQUARE.replace("4 10 0", "5 10 0")).is_err());
+    assert!(TourProblem::from_tsplib(&SQUARE.replace("DIMENSION : 4\n", "")).is_err());
+    assert!(TourProblem::read_tsplib("no/such/file.tsp").is_err());
+}
+
+fn is_tour(tour: &[usize], n: usize) -> bool {
+    let mut sorted = tour.to_vec();
+    sorted.sort_unstable();
+    tour[0] == 0 && sorted == (0..n).collect::<Vec<usize>>()
+}
+
+#[test]
+fn every_neighborhood_gives_tours_through_every_city() {
+    let mut rng = StdRng::seed_from_u64(1);
+    for neighborhood in [Neighborhood::TwoOpt, Neighborhood::ThreeOpt, Neighborhood::OrOpt] {
+        let problem = TourProblem::random(8, &mut StdRng::seed_from_u64(48)).unwrap().with_neighborhood(neighborhood);
+        let tour = problem.random_state(&mut rng).unwrap();
+        let neighbors = problem.neighbors(&tour);
+        assert!(neighbors.iter().all(|neighbor| is_tour(neighbor, 8) && *neighbor != tour), "{:?}", neighborhood);
+        let all: HashSet<Vec<usize>> = neighbors.into_iter().collect();
+        for _ in 0..200 {
+            let neighbor = problem.random_neighbor(&tour, &mut rng).unwrap();
+            assert!(all.contains(&neighbor), "{:?} {:?}", neighborhood, neighbor);
+        }
+    }
+    let problem = TourProblem::random(8, &mut rng).unwrap();
+    assert_eq!(problem.neighbors(&problem.initial()).len(), 7 * 6 / 2 - 1);
+}
+
+#[test]
+fn three_opt_and_or_opt_change_the_expected_edges() {
+    let tour: Vec<usize> = (0..8).collect();
+    let three = TourProblem::random(8, &mut StdRng::seed_from_u64(0)).unwrap().with_neighborhood(Neighborhood::ThreeOpt);
+    for neighbor in three.neighbors(&tour) {
+        assert_eq!(TourProblem::added_edges(&tour, &neighbor).len(), 3, "{:?}", neighbor);
+    }
+    let or = TourProblem::random(8, &mut StdRng::seed_from_u64(0)).unwrap().with_neighborhood(Neighborhood::OrOpt);
+    for neighbor in or.neighbors(&tour) {
+        let added = TourProblem::added_edges(&tour, &neighbor).len();
+        assert!(added == 2 || added == 3, "{:?}", neighbor);
+    }
+}
+
+#[test]
+fn hill_climbing_ends_on_a_local_optimum_of_every_neighborhood() {
+    let mut rng = StdRng::seed_from_u64(48);
+    for neighborhood in [Neighborhood::TwoOpt, Neighborhood::ThreeOpt, Neighborhood::OrOpt] {
+        let problem = TourProblem::random(15, &mut rng).unwrap().with_neighborhood(neighborhood);
+        let result = hill_climbing(&problem, &mut rng);
+        assert!(result.value < problem.tour_length(&problem.initial()));
+        let best_neighbor = problem.neighbors(&result.state).iter().map(|tour| problem.tour_length(tour)).fold(f64::INFINITY, f64::min);
+        assert!(best_neighbor >= result.value - 1e-9, "{:?}", neighborhood);
+    }
+}
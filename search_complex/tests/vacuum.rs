@@ -0,0 +1,174 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use search_complex::{
+    and_or_search,
+    cyclic_and_or_search,
+    ConditionalPlan,
+    NondeterministicProblem,
+    Problem,
+    SearchError,
+    VacuumAction::{self, Left, Right, Suck},
+    VacuumDynamics,
+    VacuumState,
+    VacuumWorld,
+};
+
+fn state(location: usize, dirty: &[bool]) -> VacuumState {
+    VacuumState::new(location, dirty.to_vec()).unwrap()
+}
+
+/* Every placement of the cleaner and of the dirt on a row */
+fn all_states(squares: usize) -> Vec<VacuumState> {
+    (0..squares)
+        .flat_map(|location| (0..1 << squares).map(move |dirt: usize| state(location, &(0..squares).map(|s| dirt >> s & 1 == 1).collect::<Vec<_>>())))
+        .collect()
+}
+
+#[test]
+fn erratic_suck_may_clean_a_neighbor_or_deposit_dirt() {
+    let world = VacuumWorld::erratic();
+    let both = state(0, &[true, true]);
+    assert_eq!(world.results(&both, &Suck), [state(0, &[false, true]), state(0, &[false, false])]);
+    assert_eq!(world.results(&state(1, &[false, true]), &Suck), [state(1, &[false, false])]);
+    assert_eq!(world.results(&state(0, &[false, true]), &Suck), [state(0, &[false, true]), state(0, &[true, true])]);
+    assert_eq!(world.results(&both, &Right), [state(1, &[true, true])]);
+
+    let middle = state(1, &[true, true, true]);
+    assert_eq!(world.results(&middle, &Suck).len(), 3);
+    assert!(VacuumState::new(3, vec![true; 3]).is_err());
+}
+
+#[test]
+fn slippery_moves_may_fail() {
+    let world = VacuumWorld::slippery();
+    let start = state(0, &[true, true]);
+    assert_eq!(world.results(&start, &Right), [state(1, &[true, true]), start.clone()]);
+    assert_eq!(world.results(&start, &Left), std::slice::from_ref(&start));
+    assert_eq!(world.results(&start, &Suck), [state(0, &[false, true])]);
+}
+
+#[test]
+fn the_erratic_world_has_an_acyclic_plan_from_every_state() {
+    assert_eq!(VacuumWorld::erratic().dynamics(), VacuumDynamics::Erratic);
+    for start in all_states(2).into_iter().chain(all_states(3)) {
+        let world = VacuumWorld::new(start.clone(), VacuumDynamics::Erratic);
+        let plan = and_or_search(&world).unwrap();
+        assert!(!plan.is_cyclic());
+        assert_eq!(plan.check(&world, &start), Ok(()), "{}", start);
+    }
+
+    /* The plan of AIMA: [Suck, if State5 then [Right, Suck] else []] */
+    let plan = and_or_search(&VacuumWorld::erratic()).unwrap();
+    let expected = ConditionalPlan::Step {
+        action: Suck,
+        branches: vec![
+            (state(0, &[false, true]), ConditionalPlan::Step {
+                action: Right,
+                branches: vec![(state(1, &[false, true]), ConditionalPlan::Step {
+                    action: Suck,
+                    branches: vec![(state(1, &[false, false]), ConditionalPlan::Empty)],
+                })],
+            }),
+            (state(0, &[false, false]), ConditionalPlan::Empty),
+        ],
+    };
+    assert_eq!(plan, expected);
+}
+
+#[test]
+fn the_slippery_world_needs_a_cyclic_plan() {
+    let world = VacuumWorld::slippery();
+    let start = world.get_initial_node().state;
+    assert_eq!(and_or_search(&world), Err(SearchError::Failure));
+
+    /* The plan of AIMA: [L1: Right, if State5 then L1 else [Suck]] */
+    let plan = cyclic_and_or_search(&world).unwrap();
+    assert!(plan.is_cyclic());
+    assert_eq!(plan.check(&world, &start), Ok(()));
+    assert!(plan.to_string().starts_with("[L1: Right, if "), "{}", plan);
+    assert!(plan.to_string().contains(" then L1"), "{}", plan);
+
+    /* The move fails three times, then works */
+    let mut failures = 3;
+    let environment = |state: &VacuumState, action: &VacuumAction| {
+        let results = world.results(state, action);
+        if failures > 0 && results.len() > 1 {
+            failures -= 1;
+            results[1].clone()
+        } else {
+            results[0].clone()
+        }
+    };
+    let (actions, end) = plan.execute(start.clone(), environment).unwrap();
+    assert_eq!(actions, [Right, Right, Right, Right, Suck]);
+    assert!(end.is_clean());
+
+    /* A move which never works */
+    assert!(plan.execute_within(start, 100, |state: &VacuumState, _: &VacuumAction| state.clone()).is_err());
+}
+
+#[test]
+fn cyclic_plans_are_found_and_followed_from_every_state() {
+    let mut rng = StdRng::seed_from_u64(48);
+    for dynamics in [VacuumDynamics::Erratic, VacuumDynamics::Slippery] {
+        for start in all_states(3) {
+            let world = VacuumWorld::new(start.clone(), dynamics);
+            let plan = cyclic_and_or_search(&world).unwrap();
+            assert_eq!(plan.check(&world, &start), Ok(()), "{:?} {}", dynamics, start);
+            let environment = |state: &VacuumState, action: &VacuumAction| world.results(state, action).choose(&mut rng).unwrap().clone();
+            let (_, end) = plan.execute_within(start, 1000, environment).unwrap();
+            assert!(end.is_clean());
+        }
+    }
+}
+
+#[test]
+fn the_checker_rejects_bad_loops() {
+    let world = VacuumWorld::slippery();
+    let start = state(0, &[false, true]);
+    let moved = state(1, &[false, true]);
+    let suck = ConditionalPlan::Step { action: Suck, branches: vec![(state(1, &[false, false]), ConditionalPlan::Empty)] };
+
+    /* Moving left from the left square never gets anywhere */
+    let stuck = ConditionalPlan::Labelled {
+        label: 1,
+        plan: Box::new(ConditionalPlan::Step { action: Left, branches: vec![(start.clone(), ConditionalPlan::Loop(1))] }),
+    };
+    assert!(stuck.check(&world, &start).is_err());
+
+    /* A loop must go back to an enclosing label, planned for the state it is in */
+    let unlabelled = ConditionalPlan::Step { action: Right, branches: vec![(moved.clone(), suck.clone()), (start.clone(), ConditionalPlan::Loop(1))] };
+    assert!(unlabelled.check(&world, &start).is_err());
+    let elsewhere = ConditionalPlan::Labelled {
+        label: 1,
+        plan: Box::new(ConditionalPlan::Step { action: Suck, branches: vec![(state(0, &[false, true]), unlabelled.clone())] }),
+    };
+    assert!(elsewhere.check(&world, &state(0, &[true, true])).is_err());
+
+    let retry = ConditionalPlan::Labelled { label: 1, plan: Box::new(unlabelled) };
+    assert_eq!(retry.check(&world, &start), Ok(()));
+    assert_eq!(retry.depth(), 2);
+}
+
+#[test]
+fn plans_clean_random_rows() {
+    let mut rng = StdRng::seed_from_u64(1);
+    for _ in 0..10 {
+        let dirty: Vec<bool> = (0..5).map(|_| rng.gen_bool(0.5)).collect();
+        let start = state(rng.gen_range(0..5), &dirty);
+        let world = VacuumWorld::new(start.clone(), VacuumDynamics::Erratic);
+        let plan = and_or_search(&world).unwrap();
+        assert_eq!(plan.check(&world, &start), Ok(()), "{}", start);
+        let (actions, end) = plan.execute(start, |state, action| world.result(state, action)).unwrap();
+        assert!(end.is_clean());
+        assert!(actions.len() <= plan.depth());
+    }
+}
+
+#[test]
+fn states_are_rows_with_the_cleaner_on_them() {
+    let start = state(1, &[true, false, true]);
+    assert_eq!((start.location(), start.squares()), (1, 3));
+    assert!(start.is_dirty(0) && !start.is_dirty(1));
+    assert!(VacuumState::new(0, vec![]).is_err());
+}
@@ -0,0 +1,168 @@
+//!
+//! Path costs. Any numeric type can be the cost of a problem, as long as costs can be added,
+//! compared and there is a largest value to stand in for infinity.
+//!
+//! Algorithms that keep their frontier sorted also need costs to be totally ordered (`Ord`).
+//! The primitive floats are not, so they are wrapped in `OrderedFloat` for those algorithms.
+//!
+
+/* Std library */
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/* External crates */
+use num::{Bounded, Float, Num, NumCast, One, ToPrimitive, Zero};
+
+pub trait Cost: Num + Copy + PartialOrd + Bounded + ToPrimitive {
+    /// `self + rhs`, or `None` if the sum can not be represented
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! integer_cost {
+    ($($t:ty)*) => {$(
+        impl Cost for $t {
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+        }
+    )*};
+}
+
+macro_rules! float_cost {
+    ($($t:ty)*) => {$(
+        impl Cost for $t {
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                finite_sum(self, rhs)
+            }
+        }
+    )*};
+}
+
+integer_cost!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+float_cost!(f32 f64);
+
+/* A sum of finite floats overflows when it becomes infinite */
+fn finite_sum<F: Float>(lhs: F, rhs: F) -> Option<F> {
+    let sum = lhs + rhs;
+    if sum.is_infinite() && lhs.is_finite() && rhs.is_finite() {
+        None
+    } else {
+        Some(sum)
+    }
+}
+
+/// A float with a total order: NaN is equal to itself and larger than every number.
+/// Its largest value is infinity.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OrderedFloat<F>(pub F);
+
+impl<F: Float> PartialEq for OrderedFloat<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<F: Float> Eq for OrderedFloat<F> {}
+
+impl<F: Float> PartialOrd for OrderedFloat<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F: Float> Ord for OrderedFloat<F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.partial_cmp(&other.0)
+            .unwrap_or_else(|| self.0.is_nan().cmp(&other.0.is_nan()))
+    }
+}
+
+impl<F: Display> Display for OrderedFloat<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+macro_rules! ordered_float_op {
+    ($($trait:ident $method:ident)*) => {$(
+        impl<F: Float> $trait for OrderedFloat<F> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                OrderedFloat(self.0.$method(rhs.0))
+            }
+        }
+    )*};
+}
+
+ordered_float_op!(Add add Sub sub Mul mul Div div Rem rem);
+
+impl<F: Float> Neg for OrderedFloat<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        OrderedFloat(-self.0)
+    }
+}
+
+impl<F: Float> Zero for OrderedFloat<F> {
+    fn zero() -> Self {
+        OrderedFloat(F::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<F: Float> One for OrderedFloat<F> {
+    fn one() -> Self {
+        OrderedFloat(F::one())
+    }
+}
+
+impl<F: Float> Num for OrderedFloat<F> {
+    type FromStrRadixErr = F::FromStrRadixErr;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        F::from_str_radix(s, radix).map(OrderedFloat)
+    }
+}
+
+impl<F: Float> Bounded for OrderedFloat<F> {
+    fn min_value() -> Self {
+        OrderedFloat(F::neg_infinity())
+    }
+
+    fn max_value() -> Self {
+        OrderedFloat(F::infinity())
+    }
+}
+
+impl<F: Float> ToPrimitive for OrderedFloat<F> {
+    fn to_i64(&self) -> Option<i64> {
+        self.0.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.0.to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.0.to_f64()
+    }
+}
+
+/// Lets problems with whole-number costs, like the Romania map, be used with float costs
+impl<F: Float> From<u32> for OrderedFloat<F> {
+    fn from(value: u32) -> Self {
+        OrderedFloat(<F as NumCast>::from(value).unwrap())
+    }
+}
+
+impl<F: Float> Cost for OrderedFloat<F> {
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        finite_sum(self.0, rhs.0).map(OrderedFloat)
+    }
+}
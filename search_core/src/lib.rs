@@ -0,0 +1,33 @@
+//!
+//! The problem formulation shared by the search crates: nodes, the `Problem` trait with its
+//! extension traits, path costs, the Romania route finding problem used throughout AIMA and a
+//! reader of TSPLIB files.
+//!
+
+pub mod cost;
+pub mod node;
+pub mod problem;
+pub mod romania;
+mod table;
+pub mod tsplib;
+
+/* Used by the problem! macro */
+#[doc(hidden)]
+pub use paste;
+
+pub use cost::{Cost, OrderedFloat};
+pub use node::Node;
+pub use problem::{
+    expand,
+    NondeterministicProblem,
+    OnlineProblem,
+    Problem,
+    SearchError,
+    SearchResult,
+};
+pub use romania::{
+    AradToBucharestAction,
+    AradToBucharestProblem,
+    ARAD_TO_BUCHAREST_PROBLEM,
+};
+pub use tsplib::Tsplib;
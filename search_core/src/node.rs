@@ -0,0 +1,66 @@
+//!
+//! Node structure for use in algorithms
+//!
+
+/* Internal crates */
+use crate::cost::Cost;
+
+#[derive(Clone, PartialOrd, PartialEq, Eq, Debug, Ord)]
+pub struct Node<S, A, C = u32> {
+    pub state: S,
+    pub parent: Option<Box<Node<S, A, C>>>,
+    pub action: Option<A>,
+    pub path_cost: C,
+    pub f: C
+}
+
+impl<S, A, C> std::fmt::Display for Node<S, A, C>
+where
+    S: std::fmt::Display,
+    A: std::fmt::Display,
+    C: std::fmt::Display
+{
+    fn fmt (&self, fmt: &mut std::fmt::Formatter) -> std::result::Result<(), std::fmt::Error> {
+        write!(fmt, "{{ State: {} Path-Cost: {} f_val: {} }}", self.state, self.path_cost, self.f)
+    }
+}
+
+impl<S, A, C> Node<S, A, C> {
+    pub const fn new(state: S, parent: Option<Box<Node<S, A, C>>>, action: Option<A>, path_cost: C, f: C) -> Self {
+        Self { state, parent, action, path_cost, f }
+    }
+}
+
+/// Number of actions on the path from the root to `start_node`, 0 for the root
+pub fn depth<S, A, C>(start_node: Node<S, A, C>) -> usize
+where
+    S: Clone,
+    A: Clone,
+    C: Cost
+{
+    let mut len: usize = 0;
+    let mut node = start_node.parent;
+    while let Some(n) = node {
+        len += 1;
+        node = n.parent;
+    }
+    len
+}
+
+/// Whether the state of `node` appears earlier on its own path
+pub fn is_cycle<S, A, C>(node: Node<S, A, C>) -> bool
+where
+    S: Clone + Eq,
+    A: Clone + Eq,
+    C: Cost
+{
+    let mut n = node.parent.as_deref();
+    while let Some(ancestor) = n {
+        if ancestor.state == node.state {
+            return true;
+        }
+        n = ancestor.parent.as_deref();
+    }
+
+    false
+}
@@ -0,0 +1,89 @@
+//!
+//! The problem formulation shared by all algorithms. A problem only has to implement
+//! `Problem`; the extension traits are needed by the algorithms that use them:
+//!     NondeterministicProblem     AND-OR search, where an action can have several outcomes
+//!     OnlineProblem               online agents, which need an action meaning "stop"
+//!
+
+/* Internal crates */
+use crate::{
+    cost::Cost,
+    node::Node,
+};
+
+pub type SearchResult<S, A, C = u32> = Result<Node<S, A, C>, SearchError>;
+
+pub trait Problem<S, A, C = u32>
+where
+    S: Clone,
+    A: Clone,
+    C: Cost
+{
+    fn is_goal(&self, state: &S) -> bool;
+    fn actions(&self, state: &S) -> Vec<A>;
+    fn result(&self, state: &S, action: &A) -> S;
+    fn action_cost(&self, state: &S, action: &A, new_state: &S) -> C;
+    fn get_initial_node(&self) -> Node<S, A, C>;
+    fn get_goal_node(&self) -> Node<S, A, C>;
+    fn get_heuristic_cost(&self, state: &S) -> C;
+
+    /// A node for every goal state, which searches that work backwards from the goal start
+    /// from. Problems whose `is_goal` holds for several states override this with all of them;
+    /// the default is the single goal node.
+    fn get_goal_nodes(&self) -> Vec<Node<S, A, C>> {
+        vec![self.get_goal_node()]
+    }
+}
+
+pub trait NondeterministicProblem<S, A, C = u32>: Problem<S, A, C>
+where
+    S: Clone,
+    A: Clone,
+    C: Cost
+{
+    /// The states that doing `action` in `state` can lead to. Deterministic problems can use
+    /// the default, which is the single state given by `result`.
+    fn results(&self, state: &S, action: &A) -> Vec<S> {
+        vec![self.result(state, action)]
+    }
+}
+
+pub trait OnlineProblem<S, A, C = u32>: Problem<S, A, C>
+where
+    S: Clone,
+    A: Clone,
+    C: Cost
+{
+    /// The action an online agent returns once it has reached the goal
+    fn stop(&self) -> A;
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord)]
+pub enum SearchError {
+    Failure,
+    CutOffReached,
+    /// A path cost became too large for the cost type
+    CostOverflow,
+}
+
+/// The children of `node`, or `CostOverflow` if the path cost of a child can not be represented
+pub fn expand<P, S, A, C>(problem: &P, node: Node<S, A, C>) -> Result<Vec<Node<S, A, C>>, SearchError>
+where
+    P: Problem<S, A, C>,
+    S: Clone,
+    A: Clone,
+    C: Cost
+{
+    let s = &node.state;
+    let mut nodes: Vec<Node<S, A, C>> = Vec::new();
+    for action in problem.actions(s) {
+        let s_star = problem.result(s, &action);
+        let cost = node.path_cost
+            .checked_add(problem.action_cost(s, &action, &s_star))
+            .ok_or(SearchError::CostOverflow)?;
+
+        nodes.push(Node::new(s_star, Some(Box::new(node.clone())), Some(action), cost, cost));
+    }
+
+    Ok(nodes)
+}
@@ -0,0 +1,74 @@
+//!
+//! Route finding on the road map of Romania (AIMA figure 3.1), with the straight-line
+//! distances to Bucharest as heuristic (AIMA figure 3.16).
+//!
+//! The map is defined with `problem!`, from the road distances of figure 3.1.
+//!
+//! The problem can be used with any cost type that distances in whole kilometres convert into,
+//! e.g. `AradToBucharestProblem<u32>` or `AradToBucharestProblem<f64>`.
+//!
+
+/* Internal crates */
+use crate::problem;
+
+pub type Action = AradToBucharestAction;
+pub type State = &'static str;
+
+problem! {
+    pub struct AradToBucharestProblem;
+    pub enum AradToBucharestAction { stop: Stop }
+
+    states {
+        Arad, Bucharest, Craiova, Drobeta, Eforie, Fagaras, Giurgiu, Hirsova, Iasi, Lugoj,
+        Mehadia, Neamt, Oradea, Pitesti, RimnicuVilcea = "Rimnicu Vilcea", Sibiu, Timisoara,
+        Urziceni, Vaslui, Zerind
+    }
+
+    roads {
+        Arad - Sibiu: 140,
+        Arad - Zerind: 75,
+        Arad - Timisoara: 118,
+        Zerind - Oradea: 71,
+        Timisoara - Lugoj: 111,
+        Lugoj - Mehadia: 70,
+        Mehadia - Drobeta: 75,
+        Drobeta - Craiova: 120,
+        Oradea - Sibiu: 151,
+        Sibiu - Fagaras: 99,
+        Sibiu - RimnicuVilcea: 80,
+        Craiova - RimnicuVilcea: 146,
+        Craiova - Pitesti: 138,
+        RimnicuVilcea - Pitesti: 97,
+        Fagaras - Bucharest: 211,
+        Pitesti - Bucharest: 101,
+        Bucharest - Giurgiu: 90,
+        Bucharest - Urziceni: 85,
+        Urziceni - Hirsova: 98,
+        Hirsova - Eforie: 86,
+        Urziceni - Vaslui: 142,
+        Vaslui - Iasi: 92,
+        Iasi - Neamt: 87,
+    }
+
+    /* Straight-line distances to Bucharest (figure 3.16) */
+    heuristic Bucharest {
+        Arad: 366, Bucharest: 0, Craiova: 160, Drobeta: 242, Eforie: 161, Fagaras: 176,
+        Giurgiu: 77, Hirsova: 151, Iasi: 226, Lugoj: 244, Mehadia: 241, Neamt: 234, Oradea: 380,
+        Pitesti: 100, RimnicuVilcea: 193, Sibiu: 253, Timisoara: 329, Urziceni: 80, Vaslui: 199,
+        Zerind: 374
+    }
+}
+
+pub const ARAD_TO_BUCHAREST_PROBLEM: AradToBucharestProblem = AradToBucharestProblem::arad_to_bucharest();
+
+impl<C> AradToBucharestProblem<C> {
+    /// The problem of AIMA chapter 3, from Arad to Bucharest
+    pub const fn arad_to_bucharest() -> Self {
+        Self { initial_state: "Arad", goal_state: "Bucharest", cost: std::marker::PhantomData }
+    }
+}
+
+/// All cities of the map, sorted by name
+pub fn cities() -> Vec<State> {
+    AradToBucharestProblem::<u32>::states()
+}
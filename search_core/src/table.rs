@@ -0,0 +1,230 @@
+//!
+//! The `problem!` macro, which defines a route-finding problem from its transition table:
+//!
+//!     problem! {
+//!         /// Doc comment of the problem
+//!         pub struct TriangleProblem;
+//!         pub enum TriangleAction { stop: Stop }
+//!         states { A, B, C = "City C" }
+//!         roads { A - B: 1, B - C: 2 }
+//!         arcs { C -> A: 4 }
+//!         heuristic C { A: 3, B: 2, C: 0 }
+//!     }
+//!
+//! States are `&'static str`, named after their identifier unless a name is given. There is an
+//! action To<State> for every state, plus the optional stop action of online agents; `roads` are
+//! travelled both ways and `arcs` one way. The heuristic holds when the goal is the given state,
+//! otherwise it is 0. `roads`, `arcs` and `heuristic` may be left out.
+//!
+//! Actions, results and costs are all generated from the same table, so they can not disagree.
+//! A table that is wrong in itself does not compile:
+//!     - a road, arc or heuristic value for a state that is not declared
+//!     - a road or arc given twice (also as A - B and B - A), or from a state to itself
+//!     - a heuristic that is missing a state, or gives one twice
+//!
+//! The problem has a cost type parameter like `AradToBucharestProblem<C = u32>`: the table holds
+//! u32 values, which are converted with `From<u32>`. Moving without a road costs `C::max_value()`.
+//!
+
+#[macro_export]
+macro_rules! problem {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident;
+        $(#[$action_meta:meta])*
+        $action_vis:vis enum $action:ident $({ stop: $stop:ident })?
+        states { $($state:ident $(= $label:literal)?),+ $(,)? }
+        $(roads { $($from:ident - $to:ident: $cost:literal),* $(,)? })?
+        $(arcs { $($arc_from:ident -> $arc_to:ident: $arc_cost:literal),* $(,)? })?
+        $(heuristic $goal:ident { $($h_state:ident: $h:literal),+ $(,)? })?
+    ) => { $crate::paste::paste! {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug)]
+        $vis struct $name<C = u32> {
+            initial_state: &'static str,
+            goal_state: &'static str,
+            cost: ::std::marker::PhantomData<C>
+        }
+
+        $(#[$action_meta])*
+        #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+        #[allow(clippy::enum_variant_names)]
+        $action_vis enum $action {
+            $([<To $state>],)+
+            $(
+                /// Only returned by online agents, once they are in the goal state
+                $stop,
+            )?
+        }
+
+        /* The states as an enum, so that the compiler checks the table */
+        #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        enum [<$name State>] {
+            $($state,)+
+        }
+
+        impl [<$name State>] {
+            const ALL: &'static [Self] = &[$(Self::$state),+];
+
+            fn name(self) -> &'static str {
+                match self {
+                    $(Self::$state => [$($label,)? stringify!($state)][0],)+
+                }
+            }
+
+            fn of(name: &str) -> Option<Self> {
+                Self::ALL.iter().copied().find(|s| s.name() == name)
+            }
+
+            fn distance(from: Self, to: Self) -> Option<u32> {
+                match (from, to) {
+                    $($((Self::$from, Self::$to) | (Self::$to, Self::$from) => Some($cost),)*)?
+                    $($((Self::$arc_from, Self::$arc_to) => Some($arc_cost),)*)?
+                    _ => None
+                }
+            }
+
+            /* The match must cover every state */
+            #[allow(unused_variables)]
+            fn heuristic(self, goal: Self) -> u32 {
+                $(
+                    if goal == Self::$goal {
+                        return match self {
+                            $(Self::$h_state => $h,)+
+                        };
+                    }
+                )?
+                0
+            }
+        }
+
+        /* Lints are not reported in macros of other crates, so repeated entries are found while compiling */
+        const _: () = {
+            let arcs: &[([<$name State>], [<$name State>])] = &[
+                $($(([<$name State>]::$from, [<$name State>]::$to), ([<$name State>]::$to, [<$name State>]::$from),)*)?
+                $($(([<$name State>]::$arc_from, [<$name State>]::$arc_to),)*)?
+            ];
+            let heuristic: &[[<$name State>]] = &[$($([<$name State>]::$h_state,)+)?];
+            let mut i = 0;
+            while i < arcs.len() {
+                assert!(arcs[i].0 as usize != arcs[i].1 as usize, "A road or arc leads from a state to itself");
+                let mut j = i + 1;
+                while j < arcs.len() {
+                    assert!(arcs[i].0 as usize != arcs[j].0 as usize || arcs[i].1 as usize != arcs[j].1 as usize,
+                        "A road or arc is given twice");
+                    j += 1;
+                }
+                i += 1;
+            }
+            let mut i = 0;
+            while i < heuristic.len() {
+                let mut j = i + 1;
+                while j < heuristic.len() {
+                    assert!(heuristic[i] as usize != heuristic[j] as usize, "A state has two heuristic values");
+                    j += 1;
+                }
+                i += 1;
+            }
+        };
+
+        impl<C> $name<C> {
+            pub fn new(initial: &str, goal: &str) -> Result<Self, String> {
+                Ok(Self {
+                    initial_state: Self::state(initial)?,
+                    goal_state: Self::state(goal)?,
+                    cost: ::std::marker::PhantomData
+                })
+            }
+
+            /// The state with the given name, ignoring case
+            pub fn state(name: &str) -> Result<&'static str, String> {
+                [<$name State>]::ALL.iter()
+                    .map(|s| s.name())
+                    .find(|s| s.eq_ignore_ascii_case(name))
+                    .ok_or_else(|| format!("Unknown state '{}', expected one of: {}", name, Self::states().join(", ")))
+            }
+
+            /// All states, sorted by name
+            pub fn states() -> Vec<&'static str> {
+                let mut states: Vec<&'static str> = [<$name State>]::ALL.iter().map(|s| s.name()).collect();
+                states.sort();
+                states
+            }
+        }
+
+        impl<C> $crate::problem::Problem<&'static str, $action, C> for $name<C>
+        where
+            C: $crate::cost::Cost + From<u32>
+        {
+            fn is_goal(&self, state: &&'static str) -> bool {
+                *state == self.goal_state
+            }
+
+            fn actions(&self, state: &&'static str) -> Vec<$action> {
+                let from = [<$name State>]::of(state);
+                let mut actions = Vec::new();
+                $($(
+                    if from == Some([<$name State>]::$from) {
+                        actions.push($action::[<To $to>]);
+                    }
+                    if from == Some([<$name State>]::$to) {
+                        actions.push($action::[<To $from>]);
+                    }
+                )*)?
+                $($(
+                    if from == Some([<$name State>]::$arc_from) {
+                        actions.push($action::[<To $arc_to>]);
+                    }
+                )*)?
+                actions
+            }
+
+            fn result(&self, state: &&'static str, action: &$action) -> &'static str {
+                let _ = state;
+                match action {
+                    $($action::[<To $state>] => [<$name State>]::$state.name(),)+
+                    $($action::$stop => *state,)?
+                }
+            }
+
+            fn action_cost(&self, state: &&'static str, _action: &$action, new_state: &&'static str) -> C {
+                match ([<$name State>]::of(state), [<$name State>]::of(new_state)) {
+                    (Some(from), Some(to)) => [<$name State>]::distance(from, to).map(C::from).unwrap_or_else(C::max_value),
+                    _ => C::max_value(),
+                }
+            }
+
+            fn get_initial_node(&self) -> $crate::node::Node<&'static str, $action, C> {
+                let h = self.get_heuristic_cost(&self.initial_state);
+                $crate::node::Node::new(self.initial_state, None, None, C::zero(), h)
+            }
+
+            fn get_goal_node(&self) -> $crate::node::Node<&'static str, $action, C> {
+                $crate::node::Node::new(self.goal_state, None, None, C::zero(), C::zero())
+            }
+
+            fn get_heuristic_cost(&self, state: &&'static str) -> C {
+                match ([<$name State>]::of(state), [<$name State>]::of(self.goal_state)) {
+                    (Some(state), Some(goal)) => C::from(state.heuristic(goal)),
+                    _ => C::zero(),
+                }
+            }
+        }
+
+        impl<C> $crate::problem::NondeterministicProblem<&'static str, $action, C> for $name<C>
+        where
+            C: $crate::cost::Cost + From<u32>
+        {}
+
+        $(
+            impl<C> $crate::problem::OnlineProblem<&'static str, $action, C> for $name<C>
+            where
+                C: $crate::cost::Cost + From<u32>
+            {
+                fn stop(&self) -> $action {
+                    $action::$stop
+                }
+            }
+        )?
+    }};
+}
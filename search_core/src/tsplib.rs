@@ -0,0 +1,195 @@
+//!
+//! Reads symmetric travelling salesperson problems in the TSPLIB format (Reinelt, 1991): a header
+//! of `KEYWORD : value` lines, then the coordinates of the cities or the distances between them.
+//!
+//!     NAME : square
+//!     TYPE : TSP
+//!     DIMENSION : 4
+//!     EDGE_WEIGHT_TYPE : EUC_2D
+//!     NODE_COORD_SECTION
+//!     1 0 0
+//!     2 0 10
+//!     3 10 10
+//!     4 10 0
+//!     EOF
+//!
+//! The distances are computed as TSPLIB specifies, rounded to integers, so that tour lengths
+//! match the published optima. Supported edge weight types are EUC_2D, CEIL_2D, ATT (pseudo-
+//! Euclidean), GEO (on the Earth, coordinates in degrees and minutes) and EXPLICIT, with the
+//! FULL_MATRIX (the default), UPPER_ROW, LOWER_ROW, UPPER_DIAG_ROW and LOWER_DIAG_ROW formats.
+//! The cities are numbered from 1 in the file, and from 0 in the distances.
+//!
+
+/* Std library */
+use std::{fs, path::Path};
+
+/// The name and the full distance matrix of a problem
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tsplib {
+    pub name: String,
+    pub distances: Vec<Vec<f64>>,
+}
+
+impl Tsplib {
+    /// Reads the TSPLIB file at the path
+    pub fn read<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        let text = fs::read_to_string(&path).map_err(|e| format!("Cannot read {}: {}", path.as_ref().display(), e))?;
+        Self::parse(&text)
+    }
+
+    /// Parses a problem in the TSPLIB format
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut name = String::new();
+        let mut dimension: Option<usize> = None;
+        let mut weight_type: Option<String> = None;
+        let mut weight_format: Option<String> = None;
+        let mut coordinates: Option<Vec<(f64, f64)>> = None;
+        let mut weights: Option<Vec<f64>> = None;
+
+        let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty()).peekable();
+        while let Some(line) = lines.next() {
+            let (keyword, value) = match line.split_once(':') {
+                Some((keyword, value)) => (keyword.trim(), value.trim()),
+                None => (line, ""),
+            };
+            match keyword {
+                "EOF" => break,
+                "NAME" => name = value.to_string(),
+                "TYPE" if value != "TSP" => return Err(format!("Problems of type {} are not supported", value)),
+                "DIMENSION" => {
+                    dimension = Some(value.parse().map_err(|_| format!("Invalid dimension {}", value))?);
+                },
+                "EDGE_WEIGHT_TYPE" => weight_type = Some(value.to_string()),
+                "EDGE_WEIGHT_FORMAT" => weight_format = Some(value.to_string()),
+                "NODE_COORD_SECTION" => {
+                    let n = dimension.ok_or("The dimension must come before the coordinates")?;
+                    let mut points = vec![None; n];
+                    for _ in 0..n {
+                        let line = lines.next().ok_or("The coordinates end too early")?;
+                        let numbers = numbers(line)?;
+                        let [city, x, y] = numbers[..] else {
+                            return Err(format!("Expected a city and two coordinates, found {}", line));
+                        };
+                        let index = city as usize;
+                        if city.fract() != 0. || index < 1 || index > n {
+                            return Err(format!("There is no city {} among {}", city, n));
+                        }
+                        points[index - 1] = Some((x, y));
+                    }
+                    coordinates = Some(points.into_iter().collect::<Option<_>>().ok_or("A city has no coordinates")?);
+                },
+                "EDGE_WEIGHT_SECTION" => {
+                    let n = dimension.ok_or("The dimension must come before the distances")?;
+                    let format = weight_format.as_deref().unwrap_or("FULL_MATRIX");
+                    let count = match format {
+                        "FULL_MATRIX" => n * n,
+                        "UPPER_ROW" | "LOWER_ROW" => n * n.saturating_sub(1) / 2,
+                        "UPPER_DIAG_ROW" | "LOWER_DIAG_ROW" => n * (n + 1) / 2,
+                        _ => return Err(format!("The edge weight format {} is not supported", format)),
+                    };
+                    let mut values = Vec::with_capacity(count);
+                    while values.len() < count {
+                        let line = lines.next().ok_or("The distances end too early")?;
+                        values.extend(numbers(line)?);
+                    }
+                    if values.len() > count {
+                        return Err(format!("Expected {} distances, found {}", count, values.len()));
+                    }
+                    weights = Some(values);
+                },
+                /* Sections that are not needed, like DISPLAY_DATA_SECTION, are skipped */
+                _ if keyword.ends_with("_SECTION") => {
+                    while lines.next_if(|line| numbers(line).is_ok()).is_some() {}
+                },
+                /* COMMENT, DISPLAY_DATA_TYPE and the like do not change the distances */
+                _ => {},
+            }
+        }
+
+        let n = dimension.ok_or("The problem has no dimension")?;
+        let weight_type = weight_type.ok_or("The problem has no edge weight type")?;
+        let distances = if weight_type == "EXPLICIT" {
+            let weights = weights.ok_or("The problem has no distances")?;
+            matrix(n, weight_format.as_deref().unwrap_or("FULL_MATRIX"), &weights)
+        } else {
+            let points = coordinates.ok_or("The problem has no coordinates")?;
+            let distance: fn((f64, f64), (f64, f64)) -> f64 = match weight_type.as_str() {
+                "EUC_2D" => euclidean,
+                "CEIL_2D" => ceiling,
+                "ATT" => pseudo_euclidean,
+                "GEO" => geographical,
+                _ => return Err(format!("The edge weight type {} is not supported", weight_type)),
+            };
+            points.iter().map(|a| points.iter().map(|b| distance(*a, *b)).collect()).collect()
+        };
+        Ok(Self { name, distances })
+    }
+}
+
+fn numbers(line: &str) -> Result<Vec<f64>, String> {
+    line.split_whitespace()
+        .map(|word| word.parse().map_err(|_| format!("{} is not a number", word)))
+        .collect()
+}
+
+/* The full matrix from the listed part of it, row by row */
+fn matrix(n: usize, format: &str, weights: &[f64]) -> Vec<Vec<f64>> {
+    if format == "FULL_MATRIX" {
+        return weights.chunks(n).map(<[f64]>::to_vec).collect();
+    }
+    let columns = |i: usize| match format {
+        "UPPER_ROW" => i + 1..n,
+        "UPPER_DIAG_ROW" => i..n,
+        "LOWER_ROW" => 0..i,
+        _ => 0..i + 1,
+    };
+    let mut distances = vec![vec![0.; n]; n];
+    let cells = (0..n).flat_map(|i| columns(i).map(move |j| (i, j)));
+    for ((i, j), value) in cells.zip(weights) {
+        distances[i][j] = *value;
+        distances[j][i] = *value;
+    }
+    distances
+}
+
+/* TSPLIB rounds to the nearest integer with (int) (x + 0.5) */
+fn nint(x: f64) -> f64 {
+    (x + 0.5).floor()
+}
+
+fn euclidean((x1, y1): (f64, f64), (x2, y2): (f64, f64)) -> f64 {
+    nint((x1 - x2).hypot(y1 - y2))
+}
+
+fn ceiling((x1, y1): (f64, f64), (x2, y2): (f64, f64)) -> f64 {
+    (x1 - x2).hypot(y1 - y2).ceil()
+}
+
+fn pseudo_euclidean((x1, y1): (f64, f64), (x2, y2): (f64, f64)) -> f64 {
+    let r = (((x1 - x2).powi(2) + (y1 - y2).powi(2)) / 10.).sqrt();
+    let t = nint(r);
+    if t < r { t + 1. } else { t }
+}
+
+/* TSPLIB computes geographical distances with this value of pi, and so must we to match its optima */
+#[allow(clippy::approx_constant)]
+const GEO_PI: f64 = 3.141_592;
+
+/* Degrees and minutes (DDD.MM) to radians */
+fn radians(x: f64) -> f64 {
+    let degrees = x.trunc();
+    let minutes = x - degrees;
+    GEO_PI * (degrees + 5. * minutes / 3.) / 180.
+}
+
+fn geographical((x1, y1): (f64, f64), (x2, y2): (f64, f64)) -> f64 {
+    const RADIUS: f64 = 6378.388;
+    if (x1, y1) == (x2, y2) {
+        return 0.;
+    }
+    let (latitude1, longitude1, latitude2, longitude2) = (radians(x1), radians(y1), radians(x2), radians(y2));
+    let q1 = (longitude1 - longitude2).cos();
+    let q2 = (latitude1 - latitude2).cos();
+    let q3 = (latitude1 + latitude2).cos();
+    (RADIUS * (0.5 * ((1. + q1) * q2 - (1. - q1) * q3)).acos() + 1.).trunc()
+}
@@ -0,0 +1,90 @@
+use search_core::{
+    problem,
+    romania::cities,
+    AradToBucharestProblem,
+    OnlineProblem,
+    Problem,
+    ARAD_TO_BUCHAREST_PROBLEM,
+};
+
+problem! {
+    /// A triangle with one one-way street
+    pub struct TriangleProblem;
+    pub enum TriangleAction { stop: Stop }
+    states { A, B, C = "City C" }
+    roads { A - B: 1, B - C: 2 }
+    arcs { C -> A: 4 }
+    heuristic C { A: 3, B: 2, C: 0 }
+}
+
+problem! {
+    struct LineProblem;
+    enum LineAction
+    states { Start, Middle, End }
+    roads { Start - Middle: 5, Middle - End: 5 }
+}
+
+#[test]
+fn actions_follow_roads_and_arcs() {
+    let problem: TriangleProblem = TriangleProblem::new("A", "City C").unwrap();
+    assert_eq!(problem.actions(&"A"), [TriangleAction::ToB]);
+    assert_eq!(problem.actions(&"B"), [TriangleAction::ToA, TriangleAction::ToC]);
+    assert_eq!(problem.actions(&"City C"), [TriangleAction::ToB, TriangleAction::ToA]);
+}
+
+#[test]
+fn results_and_costs_come_from_the_table() {
+    let problem: TriangleProblem = TriangleProblem::new("A", "City C").unwrap();
+    for state in TriangleProblem::<u32>::states() {
+        for action in problem.actions(&state) {
+            let next = problem.result(&state, &action);
+            assert!(problem.action_cost(&state, &action, &next) <= 4);
+        }
+    }
+    assert_eq!(problem.result(&"B", &TriangleAction::ToC), "City C");
+    assert_eq!(problem.action_cost(&"City C", &TriangleAction::ToA, &"A"), 4);
+    /* The arc only goes one way */
+    assert_eq!(problem.action_cost(&"A", &TriangleAction::ToC, &"City C"), u32::MAX);
+}
+
+#[test]
+fn heuristic_only_holds_towards_its_goal() {
+    let to_c: TriangleProblem = TriangleProblem::new("A", "City C").unwrap();
+    let to_b: TriangleProblem = TriangleProblem::new("A", "B").unwrap();
+    assert_eq!(to_c.get_heuristic_cost(&"A"), 3);
+    assert_eq!(to_b.get_heuristic_cost(&"A"), 0);
+    let line: LineProblem<f64> = LineProblem::new("Start", "End").unwrap();
+    assert_eq!(line.get_heuristic_cost(&"Start"), 0.);
+}
+
+#[test]
+fn states_are_looked_up_by_name() {
+    assert_eq!(TriangleProblem::<u32>::state("city c"), Ok("City C"));
+    assert_eq!(TriangleProblem::<u32>::states(), ["A", "B", "City C"]);
+    assert!(TriangleProblem::<u32>::new("A", "D").is_err());
+    assert_eq!(TriangleProblem::<u32>::new("A", "B").unwrap().stop(), TriangleAction::Stop);
+}
+
+#[test]
+fn romania_roads_are_symmetric() {
+    let problem = ARAD_TO_BUCHAREST_PROBLEM;
+    let cities = cities();
+    assert_eq!(cities.len(), 20);
+    for city in cities {
+        let actions = problem.actions(&city);
+        assert!(!actions.is_empty(), "{} has no roads", city);
+        for action in actions {
+            let next = problem.result(&city, &action);
+            let back = problem.actions(&next).into_iter().find(|a| problem.result(&next, a) == city).unwrap();
+            assert_eq!(problem.action_cost(&city, &action, &next), problem.action_cost(&next, &back, &city));
+        }
+    }
+    let problem: AradToBucharestProblem = AradToBucharestProblem::new("Arad", "Bucharest").unwrap();
+    assert_eq!(problem.get_heuristic_cost(&"Rimnicu Vilcea"), 193);
+}
+
+#[test]
+fn inconsistent_tables_do_not_compile() {
+    let tests = trybuild::TestCases::new();
+    tests.compile_fail("tests/ui/*.rs");
+}
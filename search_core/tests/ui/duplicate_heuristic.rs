@@ -0,0 +1,9 @@
+search_core::problem! {
+    struct DuplicateHeuristic;
+    enum Action
+    states { A, B }
+    roads { A - B: 1 }
+    heuristic B { A: 1, B: 0, A: 2 }
+}
+
+fn main() {}
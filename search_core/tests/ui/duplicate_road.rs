@@ -0,0 +1,8 @@
+search_core::problem! {
+    struct DuplicateRoad;
+    enum Action
+    states { A, B }
+    roads { A - B: 1, B - A: 2 }
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+search_core::problem! {
+    struct MissingHeuristic;
+    enum Action
+    states { A, B, C }
+    roads { A - B: 1, B - C: 1 }
+    heuristic C { A: 2, C: 0 }
+}
+
+fn main() {}
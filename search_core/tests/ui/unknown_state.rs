@@ -0,0 +1,8 @@
+search_core::problem! {
+    struct UnknownState;
+    enum Action
+    states { A, B }
+    roads { A - B: 1, B - C: 1 }
+}
+
+fn main() {}